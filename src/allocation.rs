@@ -0,0 +1,425 @@
+//! Distribution of workloads across days of the week.
+//! 
+//! The main type provided is the [`Allocator`] struct, which divides a
+//! quantity, or a list of weighted items, across a set of days, taking into
+//! account the relative weight and the capacity of each day.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/allocation.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{Weekdays, map::WeekdaysMap};
+use core::{
+	cmp::Reverse,
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+};
+
+
+
+//		Enums
+
+//		AllocationError															
+/// The errors that can occur when allocating work to days.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AllocationError {
+	/// The quantity is more than the total capacity of the days.
+	InsufficientCapacity {
+		/// The quantity to allocate.
+		required:  u64,
+		
+		/// The total capacity of the days.
+		available: u64,
+	},
+	
+	/// There are no days with a weight above zero to allocate to.
+	NoDays,
+	
+	/// An item does not fit into the remaining capacity of any day.
+	Unplaceable {
+		/// The position of the item in the list given.
+		index:  usize,
+		
+		/// The weight of the item.
+		weight: u64,
+	},
+}
+
+//󰭅		Display																	
+impl Display for AllocationError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::InsufficientCapacity { required, available } => write!(f, "Cannot allocate {required} with a capacity of {available}"),
+			Self::NoDays                                       => write!(f, "No days to allocate to"),
+			Self::Unplaceable { index, weight }                => write!(f, "Item {index} with weight {weight} does not fit on any day"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for AllocationError {}
+
+
+
+//		Structs
+
+//		Allocator																
+/// Divides work across a set of days of the week.
+/// 
+/// Each day has a weight, which defaults to `1`, and the work is divided in
+/// proportion to the weights, so a day with a weight of `1` receives half as
+/// much as a day with a weight of `2`. A day with a weight of `0` receives
+/// nothing. Each day can also have a capacity, which caps the amount it can
+/// receive, in which case the excess is shared among the other days, again in
+/// proportion to their weights.
+/// 
+/// Quantities are divided using the largest remainder method, so the parts
+/// always add up to the whole. Any units left over after rounding down go to
+/// the days with the largest fractional shares, and ties go to the earliest
+/// day in the week, so the result is always the same for the same inputs.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, allocation::Allocator};
+/// 
+/// // Friday is at half capacity
+/// let allocator = Allocator::new(Weekdays::WEEKDAYS)
+///     .with_weight(Weekdays::parse_const("mon-thu"), 2)
+///     .with_weight(Weekdays::FRIDAY, 1);
+/// let waves     = allocator.allocate(20).unwrap();
+/// 
+/// assert_eq!(waves.values().copied().collect::<Vec<_>>(), vec![5, 5, 4, 4, 2]);
+/// assert_eq!(waves.values().sum::<u64>(), 20);
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Allocator {
+	/// The capacity of each day, from Monday to Sunday, if capped.
+	capacities: [Option<u64>; 7],
+	
+	/// The days to allocate to.
+	days:       Weekdays,
+	
+	/// The weight of each day, from Monday to Sunday.
+	weights:    [u32; 7],
+}
+
+//󰭅		Allocator																
+impl Allocator {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Allocator`] for the given days, with equal weights and
+	/// no capacity limits.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to allocate to.
+	/// 
+	#[must_use]
+	pub const fn new(days: Weekdays) -> Self {
+		Self {
+			capacities: [None; 7],
+			days,
+			weights:    [1; 7],
+		}
+	}
+	
+	//		with_capacity														
+	/// Sets the capacity of the given days.
+	/// 
+	/// When allocating a quantity, this is the most that each of the days can
+	/// receive. When allocating items, this is the most total weight of items
+	/// that each of the days can receive.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`     - The days to set the capacity of.
+	/// * `capacity` - The capacity of each of the days.
+	/// 
+	#[must_use]
+	pub fn with_capacity(mut self, days: Weekdays, capacity: u64) -> Self {
+		for (day, slot) in Weekdays::ALL_DAYS.iter().zip(self.capacities.iter_mut()) {
+			if days.contains(day) {
+				*slot = Some(capacity);
+			}
+		}
+		self
+	}
+	
+	//		with_weight															
+	/// Sets the weight of the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`   - The days to set the weight of.
+	/// * `weight` - The weight of each of the days.
+	/// 
+	#[must_use]
+	pub fn with_weight(mut self, days: Weekdays, weight: u32) -> Self {
+		for (day, slot) in Weekdays::ALL_DAYS.iter().zip(self.weights.iter_mut()) {
+			if days.contains(day) {
+				*slot = weight;
+			}
+		}
+		self
+	}
+	
+	//		Public methods														
+	
+	//		allocate															
+	/// Divides a quantity across the days.
+	/// 
+	/// The result has an entry for every day being allocated to, in week
+	/// order, including days that receive nothing, such as those with a weight
+	/// of zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `quantity` - The quantity to divide.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`AllocationError::NoDays`] if no days have a weight above
+	/// zero, or [`AllocationError::InsufficientCapacity`] if the quantity is
+	/// more than the total capacity of those days.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Shares never exceed the quantity, and products are widened")]
+	#[expect(clippy::cast_possible_truncation, reason = "Shares never exceed the quantity")]
+	#[expect(clippy::integer_division,         reason = "Remainders are handled separately")]
+	pub fn allocate(&self, quantity: u64) -> Result<WeekdaysMap<u64>, AllocationError> {
+		let active = self.active();
+		if active.is_empty() {
+			return Err(AllocationError::NoDays);
+		}
+		let total_capacity = active.iter().try_fold(0_u64, |total, &index| self.capacity_at(index).and_then(|capacity| total.checked_add(capacity)));
+		if let Some(available) = total_capacity.filter(|&capacity| capacity < quantity) {
+			return Err(AllocationError::InsufficientCapacity { required: quantity, available });
+		}
+		let mut shares    = [0_u64; 7];
+		let mut remaining = quantity;
+		let mut open      = active;
+		while remaining > 0 && !open.is_empty() {
+			let total     = open.iter().map(|&index| u128::from(self.weight_at(index))).sum::<u128>();
+			let proposed  = open.iter().map(|&index| {
+				let scaled = u128::from(remaining) * u128::from(self.weight_at(index));
+				(index, (scaled / total) as u64, scaled % total)
+			}).collect::<Vec<_>>();
+			//	Days that would go over capacity are filled, and the rest is shared again
+			let full      = proposed.iter()
+				.filter(|&&(index, share, _)| self.room(&shares, index).is_some_and(|room| share > room))
+				.map(|&(index, _, _)| index)
+				.collect::<Vec<_>>();
+			if !full.is_empty() {
+				for &index in &full {
+					let room = self.room(&shares, index).unwrap_or(0);
+					if let Some(share) = shares.get_mut(index) {
+						*share += room;
+					}
+					remaining -= room;
+				}
+				open.retain(|index| !full.contains(index));
+				continue;
+			}
+			for &(index, share, _) in &proposed {
+				if let Some(slot) = shares.get_mut(index) {
+					*slot += share;
+				}
+				remaining -= share;
+			}
+			let mut order = proposed;
+			order.sort_by_key(|&(index, _, remainder)| (Reverse(remainder), index));
+			while remaining > 0 {
+				let mut placed = false;
+				for &(index, _, _) in &order {
+					if remaining > 0 && self.room(&shares, index).map_or(true, |room| room > 0) {
+						if let Some(slot) = shares.get_mut(index) {
+							*slot += 1;
+						}
+						remaining -= 1;
+						placed     = true;
+					}
+				}
+				if !placed {
+					break;
+				}
+			}
+			break;
+		}
+		Ok(self.days.iter().map(|day| (day, day.first_index().and_then(|index| shares.get(index)).copied().unwrap_or(0))).collect())
+	}
+	
+	//		allocate_items														
+	/// Divides a list of weighted items across the days.
+	/// 
+	/// Items are placed one at a time, heaviest first, on the day where the
+	/// total weight of its items relative to the day's own weight would be
+	/// lowest, without going over the day's capacity. Ties go to the earliest
+	/// day in the week, and items of equal weight are placed in the order
+	/// given, so the result is always the same for the same inputs.
+	/// 
+	/// The result has an entry for every day being allocated to, in week
+	/// order, including days that receive nothing. The items for each day are
+	/// in the order given.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `items` - The items to divide, each with its weight.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`AllocationError::NoDays`] if no days have a weight above
+	/// zero, or [`AllocationError::Unplaceable`] if an item does not fit on any
+	/// day.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, allocation::Allocator};
+	/// 
+	/// let allocator = Allocator::new(Weekdays::parse_const("mon-wed"));
+	/// let tasks     = allocator.allocate_items([("audit", 5), ("restock", 3), ("clean", 2), ("count", 2)]).unwrap();
+	/// 
+	/// assert_eq!(tasks.get(Weekdays::MONDAY),    Some(&vec!["audit"]));
+	/// assert_eq!(tasks.get(Weekdays::TUESDAY),   Some(&vec!["restock"]));
+	/// assert_eq!(tasks.get(Weekdays::WEDNESDAY), Some(&vec!["clean", "count"]));
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Loads are bounded by capacity checks, and products are widened")]
+	pub fn allocate_items<T, I>(&self, items: I) -> Result<WeekdaysMap<Vec<T>>, AllocationError>
+		where I: IntoIterator<Item = (T, u64)>,
+	{
+		let active = self.active();
+		if active.is_empty() {
+			return Err(AllocationError::NoDays);
+		}
+		let list       = items.into_iter().collect::<Vec<_>>();
+		let mut order  = (0..list.len()).collect::<Vec<_>>();
+		order.sort_by_key(|&index| Reverse(list.get(index).map_or(0, |&(_, weight)| weight)));
+		let mut loads  = [0_u64; 7];
+		let mut placed = vec![0; list.len()];
+		for index in order {
+			let weight = list.get(index).map_or(0, |&(_, item_weight)| item_weight);
+			let best   = active.iter()
+				.copied()
+				.filter(|&day| self.room(&loads, day).map_or(true, |room| weight <= room))
+				.min_by(|&a, &b| {
+					//	Compares (load + weight) / day weight without division
+					let load_a = u128::from(loads.get(a).copied().unwrap_or(0)) + u128::from(weight);
+					let load_b = u128::from(loads.get(b).copied().unwrap_or(0)) + u128::from(weight);
+					(load_a * u128::from(self.weight_at(b))).cmp(&(load_b * u128::from(self.weight_at(a)))).then(a.cmp(&b))
+				})
+				.ok_or(AllocationError::Unplaceable { index, weight })?;
+			if let Some(load) = loads.get_mut(best) {
+				*load = load.saturating_add(weight);
+			}
+			if let Some(slot) = placed.get_mut(index) {
+				*slot = best;
+			}
+		}
+		let mut result = self.days.iter().map(|day| (day, ())).collect::<WeekdaysMap<()>>().map(|()| Vec::new());
+		for ((item, _), index) in list.into_iter().zip(placed) {
+			if let Some(day_items) = result.get_mut(Weekdays::from_index(index)) {
+				day_items.push(item);
+			}
+		}
+		Ok(result)
+	}
+	
+	//		capacity															
+	/// Returns the capacity of the given day, if it is capped.
+	/// 
+	/// If more than one day is given, the earliest day in the week is used.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn capacity(&self, day: Weekdays) -> Option<u64> {
+		day.first_index().and_then(|index| self.capacity_at(index))
+	}
+	
+	//		days																
+	/// Returns the days being allocated to.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		weight																
+	/// Returns the weight of the given day.
+	/// 
+	/// If more than one day is given, the earliest day in the week is used. If
+	/// no days are given, or the day is not being allocated to, the weight is
+	/// zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn weight(&self, day: Weekdays) -> u32 {
+		day.first_index()
+			.filter(|&index| self.days.contains(Weekdays::from_index(index)))
+			.map_or(0, |index| self.weight_at(index))
+	}
+	
+	//		Private methods														
+	
+	//		active																
+	/// Returns the positions of the days that can receive work, from zero for
+	/// Monday to six for Sunday.
+	fn active(&self) -> Vec<usize> {
+		(0..7)
+			.filter(|&index| self.days.contains(Weekdays::from_index(index)) && self.weight_at(index) > 0)
+			.collect()
+	}
+	
+	//		capacity_at															
+	/// Returns the capacity of the day at the given position, if it is capped.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index` - The position of the day.
+	/// 
+	fn capacity_at(&self, index: usize) -> Option<u64> {
+		self.capacities.get(index).copied().flatten()
+	}
+	
+	//		room																
+	/// Returns the capacity remaining on the day at the given position, if it
+	/// is capped.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `loads` - The amount already given to each day.
+	/// * `index` - The position of the day.
+	/// 
+	fn room(&self, loads: &[u64; 7], index: usize) -> Option<u64> {
+		self.capacity_at(index).map(|capacity| capacity.saturating_sub(loads.get(index).copied().unwrap_or(0)))
+	}
+	
+	//		weight_at															
+	/// Returns the weight of the day at the given position.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index` - The position of the day.
+	/// 
+	fn weight_at(&self, index: usize) -> u32 {
+		self.weights.get(index).copied().unwrap_or(0)
+	}
+}
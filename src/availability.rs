@@ -0,0 +1,357 @@
+//! Common availability across many participants.
+//! 
+//! The main type provided is the [`Availability`] struct, which summarises the
+//! days on which a group of participants are available.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/availability.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::cmp::Reverse;
+
+
+
+//		Structs
+
+//		Availability															
+/// A summary of when a group of participants are available.
+/// 
+/// This is built from a slice of [`Weekdays`], one per participant, and keeps a
+/// count of how many participants are available on each day of the week. It
+/// can then be used to find the days that enough participants can attend, to
+/// rank the days by attendance, and to pick the best combination of days to
+/// reach as many participants as possible.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, availability::Availability};
+/// 
+/// let participants = [
+///     Weekdays::parse_const("mon-wed"),
+///     Weekdays::parse_const("tue-thu"),
+///     Weekdays::parse_const("wed-fri"),
+/// ];
+/// let availability = Availability::new(&participants);
+/// 
+/// assert_eq!(availability.attendance(Weekdays::WEDNESDAY), 3);
+/// assert_eq!(availability.at_least(2), Weekdays::parse_const("tue-thu"));
+/// assert_eq!(availability.best(2),     Weekdays::parse_const("tue-wed"));
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Availability {
+	/// The number of participants available on each day, from Monday to
+	/// Sunday.
+	counts:       [usize; 7],
+	
+	/// The number of participants with each of the possible sets of days,
+	/// indexed by the bit-mapped value.
+	patterns:     [usize; 128],
+	
+	/// The total number of participants.
+	participants: usize,
+}
+
+//󰭅		Availability															
+impl Availability {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Availability`] summary from the given participants.
+	/// 
+	/// Each entry in the slice is the set of days on which one participant is
+	/// available.
+	/// 
+	/// The participants are first bucketed by their set of days, of which
+	/// there are only 128 possibilities, so that the per-day counts are
+	/// derived from at most 128 entries.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `participants` - The days each participant is available.
+	/// 
+	#[must_use]
+	pub fn new(participants: &[Weekdays]) -> Self {
+		let mut availability = Self {
+			counts:       [0; 7],
+			patterns:     [0; 128],
+			participants: participants.len(),
+		};
+		for days in participants {
+			availability.add_pattern(days.0, 1);
+		}
+		let patterns = availability.patterns;
+		for (pattern, &count) in (0_u8..).zip(patterns.iter()) {
+			availability.add_counts(Weekdays(pattern), count);
+		}
+		availability
+	}
+	
+	//		Public methods														
+	
+	//		all																	
+	/// Returns the days on which every participant is available.
+	/// 
+	/// This is the intersection of all the participants' days. If there are no
+	/// participants, all days are returned.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, availability::Availability};
+	/// 
+	/// let availability = Availability::new(&[Weekdays::WEEKDAYS, Weekdays::parse_const("thu-sun")]);
+	/// assert_eq!(availability.all(), Weekdays::parse_const("thu-fri"));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn all(&self) -> Weekdays {
+		self.present().fold(Weekdays::ALL_DAYS, |all, days| all & days)
+	}
+	
+	//		any																	
+	/// Returns the days on which at least one participant is available.
+	/// 
+	/// This is the union of all the participants' days.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, availability::Availability};
+	/// 
+	/// let availability = Availability::new(&[Weekdays::MONDAY, Weekdays::FRIDAY]);
+	/// assert_eq!(availability.any(), Weekdays::parse_const("mon, fri"));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn any(&self) -> Weekdays {
+		self.present().fold(Weekdays::NONE, |any, days| any | days)
+	}
+	
+	//		at_least															
+	/// Returns the days on which at least the given number of participants are
+	/// available.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `threshold` - The minimum number of participants.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, availability::Availability};
+	/// 
+	/// let availability = Availability::new(&[
+	///     Weekdays::parse_const("mon-wed"),
+	///     Weekdays::parse_const("tue-thu"),
+	///     Weekdays::parse_const("wed-fri"),
+	/// ]);
+	/// assert_eq!(availability.at_least(3), Weekdays::WEDNESDAY);
+	/// assert_eq!(availability.at_least(2), Weekdays::parse_const("tue-thu"));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn at_least(&self, threshold: usize) -> Weekdays {
+		if threshold == 0 {
+			Weekdays::ALL_DAYS
+		} else if threshold == 1 {
+			self.any()
+		} else if threshold == self.participants {
+			self.all()
+		} else {
+			Weekdays::ALL_DAYS
+				.iter()
+				.zip(self.counts)
+				.filter(|&(_, count)| count >= threshold)
+				.fold(Weekdays::NONE, |days, (day, _)| days | day)
+		}
+	}
+	
+	//		attendance															
+	/// Returns the number of participants available on the given days.
+	/// 
+	/// If more than one day is given, the result is the number of participants
+	/// available on all of them.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The day or days to check.
+	/// 
+	#[must_use]
+	pub fn attendance(&self, days: Weekdays) -> usize {
+		(0_u8..)
+			.zip(self.patterns.iter())
+			.filter(|&(pattern, _)| Weekdays(pattern).contains(days))
+			.map(|(_, count)| count)
+			.sum()
+	}
+	
+	//		best																
+	/// Picks the combination of days that reaches the most participants.
+	/// 
+	/// The result contains at most `count` days, chosen so that as many
+	/// participants as possible are available on at least one of them. Where
+	/// several combinations reach the same number of participants, the one
+	/// with the highest total attendance is chosen, and after that the one with
+	/// the earliest days in the week.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `count` - The number of days to pick.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, availability::Availability};
+	/// 
+	/// let availability = Availability::new(&[
+	///     Weekdays::MONDAY,
+	///     Weekdays::MONDAY,
+	///     Weekdays::parse_const("mon, fri"),
+	///     Weekdays::SATURDAY,
+	/// ]);
+	/// assert_eq!(availability.best(1), Weekdays::MONDAY);
+	/// assert_eq!(availability.best(2), Weekdays::parse_const("mon, sat"));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn best(&self, count: usize) -> Weekdays {
+		//	There are only 128 combinations, so they can all be checked
+		(0_u8..128)
+			.map(Weekdays)
+			.filter(|days| usize::from(days.days()) == count.min(7))
+			.max_by_key(|&days| (self.coverage(days), self.total(days), days))
+			.unwrap_or(Weekdays::NONE)
+	}
+	
+	//		counts																
+	/// Returns the number of participants available on each day of the week.
+	/// 
+	/// The counts are in week order, from Monday to Sunday.
+	/// 
+	#[must_use]
+	pub const fn counts(&self) -> [usize; 7] {
+		self.counts
+	}
+	
+	//		coverage															
+	/// Returns the number of participants available on at least one of the
+	/// given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to check.
+	/// 
+	#[must_use]
+	pub fn coverage(&self, days: Weekdays) -> usize {
+		(0_u8..)
+			.zip(self.patterns.iter())
+			.filter(|&(pattern, _)| pattern & days.0 != 0)
+			.map(|(_, count)| count)
+			.sum()
+	}
+	
+	//		participants														
+	/// Returns the number of participants.
+	#[must_use]
+	pub const fn participants(&self) -> usize {
+		self.participants
+	}
+	
+	//		ranked																
+	/// Returns the days of the week ranked by attendance.
+	/// 
+	/// Each day is paired with the number of participants available on it, with
+	/// the best attended days first. Days with equal attendance are in week
+	/// order.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, availability::Availability};
+	/// 
+	/// let availability = Availability::new(&[Weekdays::WEEKENDS, Weekdays::SUNDAY]);
+	/// let ranked       = availability.ranked();
+	/// 
+	/// assert_eq!(ranked[0], (Weekdays::SUNDAY,   2));
+	/// assert_eq!(ranked[1], (Weekdays::SATURDAY, 1));
+	/// assert_eq!(ranked[2], (Weekdays::MONDAY,   0));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn ranked(&self) -> Vec<(Weekdays, usize)> {
+		let mut ranked = Weekdays::ALL_DAYS.iter().zip(self.counts).collect::<Vec<_>>();
+		//	The sort is stable, so equal days stay in week order
+		ranked.sort_by_key(|&(_, count)| Reverse(count));
+		ranked
+	}
+	
+	//		Private methods														
+	
+	//		add_counts															
+	/// Adds to the per-day counts for the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to count.
+	/// * `count` - The number of participants to add.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Counts cannot exceed the slice length")]
+	fn add_counts(&mut self, days: Weekdays, count: usize) {
+		for (day, total) in Weekdays::ALL_DAYS.iter().zip(self.counts.iter_mut()) {
+			if days.contains(day) {
+				*total += count;
+			}
+		}
+	}
+	
+	//		add_pattern															
+	/// Adds to the count of participants with the given set of days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `pattern` - The bit-mapped set of days.
+	/// * `count`   - The number of participants to add.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Counts cannot exceed the slice length")]
+	#[expect(clippy::indexing_slicing,        reason = "Patterns are always 7-bit values")]
+	fn add_pattern(&mut self, pattern: u8, count: usize) {
+		self.patterns[usize::from(pattern)] += count;
+	}
+	
+	//		present																
+	/// Returns an iterator over the sets of days that at least one participant
+	/// has.
+	fn present(&self) -> impl Iterator<Item = Weekdays> + '_ {
+		(0_u8..)
+			.zip(self.patterns.iter())
+			.filter(|&(_, &count)| count > 0)
+			.map(|(pattern, _)| Weekdays(pattern))
+	}
+	
+	//		total																
+	/// Returns the total attendance across the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to total.
+	/// 
+	fn total(&self, days: Weekdays) -> usize {
+		Weekdays::ALL_DAYS
+			.iter()
+			.zip(self.counts)
+			.filter(|&(day, _)| days.contains(day))
+			.map(|(_, count)| count)
+			.sum()
+	}
+}
@@ -0,0 +1,1073 @@
+//! Companion bitsets for days of the month and months of the year.
+//! 
+//! The [`MonthDays`] and [`Months`] structs work in the same way as
+//! [`Weekdays`], but represent days of the month and months of the year
+//! respectively. The [`CalendarMask`] struct combines all three, to decide
+//! whether a date matches.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/calendar.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	fmt::{Debug, Display, Formatter},
+	fmt,
+	ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+};
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Month, NaiveDate};
+#[cfg(feature = "postgres")]
+use ::{
+	bytes::BytesMut,
+	core::error::Error,
+	std::io::{Error as IoError, ErrorKind as IoErrorKind},
+	tokio_postgres::types::{FromSql, IsNull, ToSql, Type, to_sql_checked},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+
+
+//		Structs
+
+//		CalendarMask															
+/// A combined mask of days of the week, days of the month, and months.
+/// 
+/// A date matches the mask if its day of the week, day of the month, and month
+/// are all included. Use [`CalendarMask::ANY`] or the `ALL_*` constants of the
+/// individual types for any part that should not restrict the match.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, calendar::{CalendarMask, MonthDays, Months}};
+/// 
+/// //  The 1st and 15th of each quarter's first month, if a weekday
+/// let mask = CalendarMask::new(
+///     Weekdays::WEEKDAYS,
+///     MonthDays::FIRST | MonthDays::from_day(15).unwrap(),
+///     Months::JANUARY | Months::APRIL | Months::JULY | Months::OCTOBER,
+/// );
+/// 
+/// assert_eq!(mask.months().months(), 4);
+/// ```
+/// 
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CalendarMask {
+	/// The days of the week to match.
+	weekdays:   Weekdays,
+	
+	/// The days of the month to match.
+	month_days: MonthDays,
+	
+	/// The months to match.
+	months:     Months,
+}
+
+//󰭅		CalendarMask															
+impl CalendarMask {
+	//		Public constants													
+	/// A mask that matches every date.
+	pub const ANY: Self = Self::new(Weekdays::ALL_DAYS, MonthDays::ALL_DAYS, Months::ALL_MONTHS);
+	
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`CalendarMask`] from its parts.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `weekdays`   - The days of the week to match.
+	/// * `month_days` - The days of the month to match.
+	/// * `months`     - The months to match.
+	/// 
+	#[must_use]
+	pub const fn new(weekdays: Weekdays, month_days: MonthDays, months: Months) -> Self {
+		Self { weekdays, month_days, months }
+	}
+	
+	//		Public methods														
+	
+	//		matches																
+	/// Checks if the given date matches all parts of the mask.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::{Weekdays, calendar::{CalendarMask, MonthDays, Months}};
+	/// 
+	/// let mask = CalendarMask::new(
+	///     Weekdays::WEEKDAYS,
+	///     MonthDays::FIRST | MonthDays::from_day(15).unwrap(),
+	///     Months::JANUARY | Months::APRIL | Months::JULY | Months::OCTOBER,
+	/// );
+	/// 
+	/// assert!( mask.matches(NaiveDate::from_ymd_opt(2024, 4, 15).unwrap()));
+	/// assert!(!mask.matches(NaiveDate::from_ymd_opt(2024, 5, 15).unwrap()));
+	/// assert!(!mask.matches(NaiveDate::from_ymd_opt(2024, 7, 16).unwrap()));
+	/// assert!(!mask.matches(NaiveDate::from_ymd_opt(2024, 9,  1).unwrap()));
+	/// ```
+	/// 
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	pub fn matches(&self, date: NaiveDate) -> bool {
+		self.weekdays.contains(Weekdays::from(date.weekday()))
+			&& self.month_days.contains_date(date)
+			&& self.months.contains_date(date)
+	}
+	
+	//		month_days															
+	/// Returns the days of the month to match.
+	#[must_use]
+	pub const fn month_days(&self) -> MonthDays {
+		self.month_days
+	}
+	
+	//		months																
+	/// Returns the months to match.
+	#[must_use]
+	pub const fn months(&self) -> Months {
+		self.months
+	}
+	
+	//		weekdays															
+	/// Returns the days of the week to match.
+	#[must_use]
+	pub const fn weekdays(&self) -> Weekdays {
+		self.weekdays
+	}
+}
+
+//		MonthDays																
+/// A bit-mapped representation of the days of the month.
+/// 
+/// This struct uses 32 bits, where each of the 31 most significant bits
+/// represents a day of the month, starting from the 1st, and the least
+/// significant bit is a flag for the last day of the month, whichever day
+/// number that falls on. This allows "the last day" to be expressed regardless
+/// of the length of the month.
+/// 
+/// ```text
+/// 1st
+/// | 2nd              31st
+/// | |  ...           | Last day
+/// 1 1 ... 1 1 1 1 1 1 1
+/// ```
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::calendar::MonthDays;
+/// 
+/// let days = MonthDays::FIRST | MonthDays::from_day(15).unwrap();
+/// assert_eq!(days.days(), 2);
+/// assert!(days.contains(MonthDays::FIRST));
+/// ```
+/// 
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MonthDays(u32);
+
+//󰭅		MonthDays																
+impl MonthDays {
+	//		Public constants													
+	/// The first day of the month.
+	pub const FIRST:    Self = Self(0b1000_0000_0000_0000_0000_0000_0000_0000);
+	
+	/// The last day of the month, whichever day number that is.
+	pub const LAST:     Self = Self(0b0000_0000_0000_0000_0000_0000_0000_0001);
+	
+	/// All days of the month, from the 1st to the 31st.
+	pub const ALL_DAYS: Self = Self(0b1111_1111_1111_1111_1111_1111_1111_1110);
+	
+	/// No days of the month (empty).
+	pub const NONE:     Self = Self(0b0000_0000_0000_0000_0000_0000_0000_0000);
+	
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`MonthDays`] struct from the given days.
+	/// 
+	/// The days are expected to be a 32-bit number, where each of the 31 most
+	/// significant bits represents a day of the month, from the 1st to the
+	/// 31st, and the least significant bit represents the last day of the
+	/// month.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to represent, as a bit-mapped value.
+	/// 
+	#[must_use]
+	pub const fn new(days: u32) -> Self {
+		Self(days)
+	}
+	
+	//		from_day															
+	/// Creates a new [`MonthDays`] struct for a single day of the month.
+	/// 
+	/// Returns [`None`] if the day is not between 1 and 31.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day of the month, from 1 to 31.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::calendar::MonthDays;
+	/// 
+	/// assert_eq!(MonthDays::from_day(1),  Some(MonthDays::FIRST));
+	/// assert_eq!(MonthDays::from_day(31), Some(MonthDays::new(0b10)));
+	/// assert_eq!(MonthDays::from_day(32), None);
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Day is checked to be between 1 and 31")]
+	#[must_use]
+	pub const fn from_day(day: u8) -> Option<Self> {
+		if day >= 1 && day <= 31 {
+			Some(Self(Self::FIRST.0 >> (day - 1)))
+		} else {
+			None
+		}
+	}
+	
+	//		Public methods														
+	
+	//		contains															
+	/// Checks if the set of days contains the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to check for.
+	/// 
+	#[must_use]
+	pub const fn contains(&self, days: Self) -> bool {
+		self.0 & days.0 == days.0
+	}
+	
+	//		contains_date														
+	/// Checks if the set of days includes the day of the month of the given
+	/// date.
+	/// 
+	/// A date matches if its day number is set, or if it is the last day of
+	/// its month and the [`LAST`](Self::LAST) flag is set.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::calendar::MonthDays;
+	/// 
+	/// assert!( MonthDays::LAST.contains_date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+	/// assert!(!MonthDays::LAST.contains_date(NaiveDate::from_ymd_opt(2024, 3, 30).unwrap()));
+	/// ```
+	/// 
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	pub fn contains_date(&self, date: NaiveDate) -> bool {
+		let is_last = date.succ_opt().map_or(true, |next| next.month() != date.month());
+		self.0 & (Self::FIRST.0 >> date.day0()) != 0 || is_last && self.contains(Self::LAST)
+	}
+	
+	//		days																
+	/// Returns the number of days set.
+	/// 
+	/// The [`LAST`](Self::LAST) flag counts as a day.
+	/// 
+	#[expect(clippy::cast_possible_truncation, reason = "Value is guaranteed to be <= 32")]
+	#[must_use]
+	pub const fn days(&self) -> u8 {
+		self.0.count_ones() as u8
+	}
+	
+	//		is_empty															
+	/// Checks if the set of days is empty.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.0 == 0
+	}
+	
+	//		iter																
+	/// Returns an iterator over the days of the month that are set.
+	/// 
+	/// Days are yielded in order from the 1st, with the [`LAST`](Self::LAST)
+	/// flag at the end.
+	/// 
+	#[must_use]
+	pub const fn iter(&self) -> MonthDaysIter {
+		MonthDaysIter {
+			remaining: self.0,
+			position:  0,
+		}
+	}
+	
+	//		to_vec																
+	/// Converts the set of days to a [`Vec`] of days.
+	#[must_use]
+	pub fn to_vec(&self) -> Vec<Self> {
+		self.iter().collect()
+	}
+	
+	//		union																
+	/// Combines the set of days with another set of days.
+	/// 
+	/// This is the same as using the `|` operator, but is available in `const`
+	/// contexts.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to add.
+	/// 
+	#[must_use]
+	pub const fn union(&self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+}
+
+//󰭅		Add																		
+impl Add for MonthDays {
+	type Output = Self;
+	
+	//		add																	
+	#[expect(clippy::suspicious_arithmetic_impl, reason = "Bitwise OR is the correct operation")]
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(self.0 | rhs.0)
+	}
+}
+
+//󰭅		AddAssign																
+impl AddAssign for MonthDays {
+	//		add_assign															
+	#[expect(clippy::suspicious_op_assign_impl, reason = "Bitwise OR is the correct operation")]
+	fn add_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+//󰭅		BitAnd																	
+impl BitAnd for MonthDays {
+	type Output = Self;
+	
+	//		bitand																
+	fn bitand(self, rhs: Self) -> Self::Output {
+		Self(self.0 & rhs.0)
+	}
+}
+
+//󰭅		BitAndAssign															
+impl BitAndAssign for MonthDays {
+	//		bitand_assign														
+	fn bitand_assign(&mut self, rhs: Self) {
+		self.0 &= rhs.0;
+	}
+}
+
+//󰭅		BitOr																	
+impl BitOr for MonthDays {
+	type Output = Self;
+	
+	//		bitor																
+	fn bitor(self, rhs: Self) -> Self::Output {
+		Self(self.0 | rhs.0)
+	}
+}
+
+//󰭅		BitOrAssign																
+impl BitOrAssign for MonthDays {
+	//		bitor_assign														
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+//󰭅		BitXor																	
+impl BitXor for MonthDays {
+	type Output = Self;
+	
+	//		bitxor																
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		Self(self.0 ^ rhs.0)
+	}
+}
+
+//󰭅		BitXorAssign															
+impl BitXorAssign for MonthDays {
+	//		bitxor_assign														
+	fn bitxor_assign(&mut self, rhs: Self) {
+		self.0 ^= rhs.0;
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for MonthDays {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "MonthDays({self})")
+	}
+}
+
+//󰭅		Deserialize																
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MonthDays {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		u32::deserialize(deserializer).map(Self::new)
+	}
+}
+
+//󰭅		Display																	
+impl Display for MonthDays {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		//	Grouped into weeks of seven days, then the last-day flag
+		write!(
+			f,
+			"{:07b}_{:07b}_{:07b}_{:07b}_{:03b}_{:01b}",
+			self.0 >> 25,
+			self.0 >> 18 & 0b111_1111,
+			self.0 >> 11 & 0b111_1111,
+			self.0 >>  4 & 0b111_1111,
+			self.0 >>  1 & 0b111,
+			self.0       & 0b1,
+		)
+	}
+}
+
+//󰭅		FromSql																	
+#[cfg(feature = "postgres")]
+impl FromSql<'_> for MonthDays {
+	//		from_sql															
+	fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		match (ty, <[u8; 4]>::try_from(raw)) {
+			(&Type::BIT, Ok(bytes)) => Ok(Self(u32::from_be_bytes(bytes))),
+			(&Type::BIT, Err(_))    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid length for MonthDays: {}", raw.len()),
+			))),
+			(unknown, _)            => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for MonthDays: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+}
+
+//󰭅		IntoIterator															
+impl IntoIterator for MonthDays {
+	type Item     = Self;
+	type IntoIter = MonthDaysIter;
+	
+	//		into_iter															
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+//󰭅		IntoIterator															
+impl IntoIterator for &MonthDays {
+	type Item     = MonthDays;
+	type IntoIter = MonthDaysIter;
+	
+	//		into_iter															
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+//󰭅		Not																		
+impl Not for MonthDays {
+	type Output = Self;
+	
+	//		not																	
+	fn not(self) -> Self::Output {
+		Self(!self.0)
+	}
+}
+
+//󰭅		Serialize																
+#[cfg(feature = "serde")]
+impl Serialize for MonthDays {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u32(self.0)
+	}
+}
+
+//󰭅		Sub																		
+impl Sub for MonthDays {
+	type Output = Self;
+	
+	//		sub																	
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self(self.0 & !rhs.0)
+	}
+}
+
+//󰭅		SubAssign																
+impl SubAssign for MonthDays {
+	//		sub_assign															
+	fn sub_assign(&mut self, rhs: Self) {
+		self.0 &= !rhs.0;
+	}
+}
+
+//󰭅		ToSql																	
+#[cfg(feature = "postgres")]
+impl ToSql for MonthDays {
+	//		to_sql																
+	fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		match ty {
+			&Type::BIT => {
+				//	PostgreSQL expects 32 bits in the same format
+				out.extend_from_slice(&self.0.to_be_bytes());
+				Ok(IsNull::No)
+			}
+			unknown    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for MonthDays: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+	
+	to_sql_checked!();
+}
+
+//		MonthDaysIter															
+/// An iterator over the days of the month.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MonthDaysIter {
+	/// The remaining days to iterate over.
+	remaining: u32,
+	
+	/// The current position in the iteration.
+	position:  u8,
+}
+
+//󰭅		Iterator																
+impl Iterator for MonthDaysIter {
+	type Item = MonthDays;
+	
+	//		next																
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.position < 32 {
+			let current      = MonthDays::FIRST.0 >> self.position;
+			#[expect(clippy::arithmetic_side_effects, reason = "This is checked by the while loop")]
+			{ self.position += 1; }
+			
+			if self.remaining & current != 0 {
+				return Some(MonthDays(current));
+			}
+		}
+		None
+	}
+}
+
+//		Months																	
+/// A bit-mapped representation of the months of the year.
+/// 
+/// This struct uses 12 bits, ordered from most significant to least
+/// significant, starting from January, with the least significant bit
+/// representing December.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::calendar::Months;
+/// 
+/// let quarters = Months::JANUARY | Months::APRIL | Months::JULY | Months::OCTOBER;
+/// assert_eq!(quarters, Months::new(0b1001_0010_0100));
+/// assert_eq!(quarters.months(), 4);
+/// ```
+/// 
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Months(u16);
+
+//󰭅		Months																	
+impl Months {
+	//		Public constants													
+	/// January.
+	pub const JANUARY:    Self = Self(0b1000_0000_0000);
+	
+	/// February.
+	pub const FEBRUARY:   Self = Self(0b0100_0000_0000);
+	
+	/// March.
+	pub const MARCH:      Self = Self(0b0010_0000_0000);
+	
+	/// April.
+	pub const APRIL:      Self = Self(0b0001_0000_0000);
+	
+	/// May.
+	pub const MAY:        Self = Self(0b0000_1000_0000);
+	
+	/// June.
+	pub const JUNE:       Self = Self(0b0000_0100_0000);
+	
+	/// July.
+	pub const JULY:       Self = Self(0b0000_0010_0000);
+	
+	/// August.
+	pub const AUGUST:     Self = Self(0b0000_0001_0000);
+	
+	/// September.
+	pub const SEPTEMBER:  Self = Self(0b0000_0000_1000);
+	
+	/// October.
+	pub const OCTOBER:    Self = Self(0b0000_0000_0100);
+	
+	/// November.
+	pub const NOVEMBER:   Self = Self(0b0000_0000_0010);
+	
+	/// December.
+	pub const DECEMBER:   Self = Self(0b0000_0000_0001);
+	
+	/// All months of the year.
+	pub const ALL_MONTHS: Self = Self(0b1111_1111_1111);
+	
+	/// No months of the year (empty).
+	pub const NONE:       Self = Self(0b0000_0000_0000);
+	
+	//		Private constants													
+	/// The mask for all months of the year.
+	const ALL_MONTHS_MASK: u16 = 0b1111_1111_1111;
+	
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Months`] struct from the given months.
+	/// 
+	/// The months are expected to be a 12-bit number, where each bit
+	/// represents a month of the year, from January in the most significant
+	/// bit to December in the least significant bit.
+	/// 
+	/// **IMPORTANT**: Note that if the incoming value is larger than 12 bits,
+	/// the extra bits will be silently ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `months` - The months to represent, as a bit-mapped value.
+	/// 
+	#[must_use]
+	pub const fn new(months: u16) -> Self {
+		//	Ensure only 12 bits are used
+		Self(months & Self::ALL_MONTHS_MASK)
+	}
+	
+	//		from_month															
+	/// Creates a new [`Months`] struct for a single month.
+	/// 
+	/// Returns [`None`] if the month is not between 1 and 12.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `month` - The month number, from 1 for January to 12 for December.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Month is checked to be between 1 and 12")]
+	#[must_use]
+	pub const fn from_month(month: u8) -> Option<Self> {
+		if month >= 1 && month <= 12 {
+			Some(Self(Self::JANUARY.0 >> (month - 1)))
+		} else {
+			None
+		}
+	}
+	
+	//		Public methods														
+	
+	//		contains															
+	/// Checks if the set of months contains the given months.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `months` - The months to check for.
+	/// 
+	#[must_use]
+	pub const fn contains(&self, months: Self) -> bool {
+		self.0 & months.0 == months.0
+	}
+	
+	//		contains_date														
+	/// Checks if the set of months includes the month of the given date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	pub fn contains_date(&self, date: NaiveDate) -> bool {
+		self.0 & (Self::JANUARY.0 >> date.month0()) != 0
+	}
+	
+	//		is_empty															
+	/// Checks if the set of months is empty.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.0 == 0
+	}
+	
+	//		iter																
+	/// Returns an iterator over the months that are set, from January.
+	#[must_use]
+	pub const fn iter(&self) -> MonthsIter {
+		MonthsIter {
+			remaining: self.0,
+			position:  0,
+		}
+	}
+	
+	//		months																
+	/// Returns the number of months set.
+	#[expect(clippy::cast_possible_truncation, reason = "Value is guaranteed to be <= 12")]
+	#[must_use]
+	pub const fn months(&self) -> u8 {
+		self.0.count_ones() as u8
+	}
+	
+	//		to_chrono_vec														
+	/// Converts the set of months to a [`Vec`] of Chrono [`Month`]s.
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	pub fn to_chrono_vec(&self) -> Vec<Month> {
+		[
+			Month::January,
+			Month::February,
+			Month::March,
+			Month::April,
+			Month::May,
+			Month::June,
+			Month::July,
+			Month::August,
+			Month::September,
+			Month::October,
+			Month::November,
+			Month::December,
+		]
+			.into_iter()
+			.filter(|&month| self.contains(Self::from(month)))
+			.collect()
+	}
+	
+	//		to_vec																
+	/// Converts the set of months to a [`Vec`] of months.
+	#[must_use]
+	pub fn to_vec(&self) -> Vec<Self> {
+		self.iter().collect()
+	}
+	
+	//		union																
+	/// Combines the set of months with another set of months.
+	/// 
+	/// This is the same as using the `|` operator, but is available in `const`
+	/// contexts.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The months to add.
+	/// 
+	#[must_use]
+	pub const fn union(&self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+}
+
+//󰭅		Add																		
+impl Add for Months {
+	type Output = Self;
+	
+	//		add																	
+	#[expect(clippy::suspicious_arithmetic_impl, reason = "Bitwise OR is the correct operation")]
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(self.0 | rhs.0)
+	}
+}
+
+//󰭅		AddAssign																
+impl AddAssign for Months {
+	//		add_assign															
+	#[expect(clippy::suspicious_op_assign_impl, reason = "Bitwise OR is the correct operation")]
+	fn add_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+//󰭅		BitAnd																	
+impl BitAnd for Months {
+	type Output = Self;
+	
+	//		bitand																
+	fn bitand(self, rhs: Self) -> Self::Output {
+		Self(self.0 & rhs.0)
+	}
+}
+
+//󰭅		BitAndAssign															
+impl BitAndAssign for Months {
+	//		bitand_assign														
+	fn bitand_assign(&mut self, rhs: Self) {
+		self.0 &= rhs.0;
+	}
+}
+
+//󰭅		BitOr																	
+impl BitOr for Months {
+	type Output = Self;
+	
+	//		bitor																
+	fn bitor(self, rhs: Self) -> Self::Output {
+		Self(self.0 | rhs.0)
+	}
+}
+
+//󰭅		BitOrAssign																
+impl BitOrAssign for Months {
+	//		bitor_assign														
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+//󰭅		BitXor																	
+impl BitXor for Months {
+	type Output = Self;
+	
+	//		bitxor																
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		Self(self.0 ^ rhs.0)
+	}
+}
+
+//󰭅		BitXorAssign															
+impl BitXorAssign for Months {
+	//		bitxor_assign														
+	fn bitxor_assign(&mut self, rhs: Self) {
+		self.0 ^= rhs.0;
+	}
+}
+
+//󰭅		Debug																	
+impl Debug for Months {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "Months({self})")
+	}
+}
+
+//󰭅		Deserialize																
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Months {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		u16::deserialize(deserializer).map(Self::new)
+	}
+}
+
+//󰭅		Display																	
+impl Display for Months {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		//	Grouped into quarters
+		write!(
+			f,
+			"{:03b}_{:03b}_{:03b}_{:03b}",
+			self.0 >> 9,
+			self.0 >> 6 & 0b111,
+			self.0 >> 3 & 0b111,
+			self.0      & 0b111,
+		)
+	}
+}
+
+//󰭅		From: Month -> Months													
+#[cfg(feature = "chrono")]
+impl From<Month> for Months {
+	//		from																
+	fn from(month: Month) -> Self {
+		match month {
+			Month::January   => Self::JANUARY,
+			Month::February  => Self::FEBRUARY,
+			Month::March     => Self::MARCH,
+			Month::April     => Self::APRIL,
+			Month::May       => Self::MAY,
+			Month::June      => Self::JUNE,
+			Month::July      => Self::JULY,
+			Month::August    => Self::AUGUST,
+			Month::September => Self::SEPTEMBER,
+			Month::October   => Self::OCTOBER,
+			Month::November  => Self::NOVEMBER,
+			Month::December  => Self::DECEMBER,
+		}
+	}
+}
+
+//󰭅		FromSql																	
+#[cfg(feature = "postgres")]
+impl FromSql<'_> for Months {
+	//		from_sql															
+	fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		match (ty, <[u8; 2]>::try_from(raw)) {
+			(&Type::BIT, Ok(bytes)) => Ok(Self::new(u16::from_be_bytes(bytes))),
+			(&Type::BIT, Err(_))    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid length for Months: {}", raw.len()),
+			))),
+			(unknown, _)            => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for Months: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+}
+
+//󰭅		IntoIterator															
+impl IntoIterator for Months {
+	type Item     = Self;
+	type IntoIter = MonthsIter;
+	
+	//		into_iter															
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+//󰭅		IntoIterator															
+impl IntoIterator for &Months {
+	type Item     = Months;
+	type IntoIter = MonthsIter;
+	
+	//		into_iter															
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+//󰭅		Not																		
+impl Not for Months {
+	type Output = Self;
+	
+	//		not																	
+	fn not(self) -> Self::Output {
+		Self(!self.0 & Self::ALL_MONTHS_MASK)
+	}
+}
+
+//󰭅		Serialize																
+#[cfg(feature = "serde")]
+impl Serialize for Months {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u16(self.0)
+	}
+}
+
+//󰭅		Sub																		
+impl Sub for Months {
+	type Output = Self;
+	
+	//		sub																	
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self(self.0 & !rhs.0)
+	}
+}
+
+//󰭅		SubAssign																
+impl SubAssign for Months {
+	//		sub_assign															
+	fn sub_assign(&mut self, rhs: Self) {
+		self.0 &= !rhs.0;
+	}
+}
+
+//󰭅		ToSql																	
+#[cfg(feature = "postgres")]
+impl ToSql for Months {
+	//		to_sql																
+	fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		match ty {
+			&Type::BIT => {
+				//	PostgreSQL expects 12 bits in the same format
+				out.extend_from_slice(&self.0.to_be_bytes());
+				Ok(IsNull::No)
+			}
+			unknown    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for Months: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+	
+	to_sql_checked!();
+}
+
+//		MonthsIter																
+/// An iterator over the months of the year.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MonthsIter {
+	/// The remaining months to iterate over.
+	remaining: u16,
+	
+	/// The current position in the iteration.
+	position:  u8,
+}
+
+//󰭅		Iterator																
+impl Iterator for MonthsIter {
+	type Item = Months;
+	
+	//		next																
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.position < 12 {
+			let current      = Months::JANUARY.0 >> self.position;
+			#[expect(clippy::arithmetic_side_effects, reason = "This is checked by the while loop")]
+			{ self.position += 1; }
+			
+			if self.remaining & current != 0 {
+				return Some(Months(current));
+			}
+		}
+		None
+	}
+}
@@ -0,0 +1,498 @@
+//! Conflict detection across large collections of recurring reservations.
+//! 
+//! The main type provided is the [`ConflictIndex`] struct, which holds many
+//! [`Reservation`]s, each a set of days with a time window, and quickly finds
+//! the ones that clash with a new reservation.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/conflict.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{MAX_MINUTE, MINUTES_PER_DAY, Weekdays};
+use core::{array, ops::Range};
+use std::collections::{BTreeMap, BTreeSet};
+
+
+
+//		Constants
+
+/// The number of leaves in the tree of latest ends held for each day, which is
+/// the number of minutes in a day rounded up to a power of two, so that every
+/// start time has a leaf.
+const LEAVES: usize = 2_048;
+
+
+
+//		Structs
+
+//		ConflictIndex															
+/// An index of recurring reservations for fast conflict detection.
+/// 
+/// Each reservation is identified by a key, such as a booking ID, and is
+/// split into the parts that fall on each day of the week, which are held in
+/// order of start time. Finding conflicts for a new reservation only needs to
+/// look at the parts on the days it covers that start before it ends and end
+/// after it starts, which are found by way of a tree of the latest end of the
+/// parts for each range of start times. The time taken grows with the number
+/// of parts found rather than with the size of the collection, so it stays
+/// fast however long any of the reservations are.
+/// 
+/// Reservations can be added and removed at any time, and adding a
+/// reservation with a key that is already in the index replaces it.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, conflict::{ConflictIndex, Reservation}};
+/// 
+/// let mut index = ConflictIndex::new();
+/// index.insert("yoga",    Reservation::new(Weekdays::parse_const("mon, wed"), 1080..1140).unwrap());
+/// index.insert("pilates", Reservation::new(Weekdays::parse_const("tue, thu"), 1080..1140).unwrap());
+/// index.insert("spin",    Reservation::new(Weekdays::WEEKDAYS,               420..480).unwrap());
+/// 
+/// let request = Reservation::new(Weekdays::parse_const("wed-thu"), 1110..1200).unwrap();
+/// assert_eq!(index.conflicts(&request), vec![
+///     ("pilates", Weekdays::THURSDAY),
+///     ("yoga",    Weekdays::WEDNESDAY),
+/// ]);
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConflictIndex<K> {
+	/// The reservations, by key.
+	entries: BTreeMap<K, Reservation>,
+	
+	/// The parts of the reservations on each day, from Monday to Sunday.
+	days:    [DaySegments<K>; 7],
+}
+
+//󰭅		ConflictIndex															
+impl<K> ConflictIndex<K>
+	where K: Clone + Ord,
+{
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new, empty [`ConflictIndex`].
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			entries: BTreeMap::new(),
+			days:    array::from_fn(|_| DaySegments::new()),
+		}
+	}
+	
+	//		Public methods														
+	
+	//		conflicts															
+	/// Finds the reservations that clash with the given reservation.
+	/// 
+	/// Each conflict is given as the key of the clashing reservation, along
+	/// with the days on which the clash happens, as described for
+	/// [`Reservation::conflicts()`]. The conflicts are in key order.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `reservation` - The reservation to check.
+	/// 
+	#[must_use]
+	pub fn conflicts(&self, reservation: &Reservation) -> Vec<(K, Weekdays)> {
+		let candidates = reservation.segments()
+			.into_iter()
+			.filter_map(|(day, window)| Some(self.days.get(day)?.overlapping(&window)))
+			.flatten()
+			.collect::<BTreeSet<_>>();
+		candidates.into_iter()
+			.filter_map(|key| {
+				let days = self.entries.get(key)?.conflicts(reservation);
+				(!days.is_empty()).then(|| (key.clone(), days))
+			})
+			.collect()
+	}
+	
+	//		contains_key														
+	/// Checks if there is a reservation with the given key.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key` - The key to look up.
+	/// 
+	#[must_use]
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.entries.contains_key(key)
+	}
+	
+	//		get																	
+	/// Returns the reservation with the given key, if there is one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key` - The key to look up.
+	/// 
+	#[must_use]
+	pub fn get(&self, key: &K) -> Option<&Reservation> {
+		self.entries.get(key)
+	}
+	
+	//		insert																
+	/// Adds a reservation to the index.
+	/// 
+	/// If there was already a reservation with the same key, it is replaced,
+	/// and returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key`         - The key identifying the reservation.
+	/// * `reservation` - The reservation.
+	/// 
+	pub fn insert(&mut self, key: K, reservation: Reservation) -> Option<Reservation> {
+		let previous = self.remove(&key);
+		for (day, window) in reservation.segments() {
+			if let Some(segments) = self.days.get_mut(day) {
+				segments.insert(key.clone(), &window);
+			}
+		}
+		_ = self.entries.insert(key, reservation);
+		previous
+	}
+	
+	//		is_empty															
+	/// Checks if the index has no reservations.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+	
+	//		iter																
+	/// Returns an iterator over the reservations, in key order.
+	pub fn iter(&self) -> impl Iterator<Item = (&K, &Reservation)> {
+		self.entries.iter()
+	}
+	
+	//		len																	
+	/// Returns the number of reservations.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+	
+	//		remove																
+	/// Removes the reservation with the given key from the index, returning it
+	/// if there was one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key` - The key of the reservation.
+	/// 
+	pub fn remove(&mut self, key: &K) -> Option<Reservation> {
+		let reservation = self.entries.remove(key)?;
+		for (day, window) in reservation.segments() {
+			if let Some(segments) = self.days.get_mut(day) {
+				segments.remove(key, &window);
+			}
+		}
+		Some(reservation)
+	}
+}
+
+//󰭅		Default																	
+impl<K> Default for ConflictIndex<K>
+	where K: Clone + Ord,
+{
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//󰭅		Extend																	
+impl<K> Extend<(K, Reservation)> for ConflictIndex<K>
+	where K: Clone + Ord,
+{
+	//		extend																
+	fn extend<I: IntoIterator<Item = (K, Reservation)>>(&mut self, iter: I) {
+		for (key, reservation) in iter {
+			_ = self.insert(key, reservation);
+		}
+	}
+}
+
+//󰭅		FromIterator															
+impl<K> FromIterator<(K, Reservation)> for ConflictIndex<K>
+	where K: Clone + Ord,
+{
+	//		from_iter															
+	fn from_iter<I: IntoIterator<Item = (K, Reservation)>>(iter: I) -> Self {
+		let mut index = Self::new();
+		index.extend(iter);
+		index
+	}
+}
+
+//		DaySegments																
+/// The parts of the reservations that fall on a single day, by start time.
+/// 
+/// A part overlaps a time window if it starts before the window ends, and ends
+/// after the window starts. Alongside the parts, a segment tree holds the
+/// latest end of the parts starting in each range of start times, halving the
+/// range at each level down to a single minute, so that only the ranges with
+/// parts ending after the window starts need to be visited. This takes time in
+/// proportion to the number of parts found, times the depth of the tree.
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct DaySegments<K> {
+	/// The keys of the reservations, by the start and then the end of each
+	/// part.
+	starts: BTreeMap<u16, BTreeMap<u16, BTreeSet<K>>>,
+	
+	/// The latest end of the parts starting in each range of start times, as
+	/// a segment tree, with the root at position one, the children of each
+	/// node at twice its position and the next, and one leaf for each minute.
+	/// This is empty when there are no parts.
+	latest: Vec<u16>,
+}
+
+//󰭅		DaySegments																
+impl<K: Ord> DaySegments<K> {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new, empty [`DaySegments`].
+	const fn new() -> Self {
+		Self {
+			starts: BTreeMap::new(),
+			latest: Vec::new(),
+		}
+	}
+	
+	//		Public methods														
+	
+	//		insert																
+	/// Adds a part of a reservation.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key`    - The key of the reservation.
+	/// * `window` - The time window of the part, within the day.
+	/// 
+	fn insert(&mut self, key: K, window: &Range<u16>) {
+		_ = self.starts.entry(window.start)
+			.or_default()
+			.entry(window.end)
+			.or_default()
+			.insert(key);
+		self.update(window.start);
+	}
+	
+	//		overlapping															
+	/// Returns the keys of the reservations with parts that overlap a time
+	/// window, in order of start time.
+	/// 
+	/// A key can be given more than once.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `window` - The time window, within the day.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions are bounded by the size of the tree")]
+	#[expect(clippy::integer_division,        reason = "Ranges of start times are powers of two")]
+	fn overlapping(&self, window: &Range<u16>) -> impl Iterator<Item = &K> {
+		let start      = window.start;
+		let mut starts = Vec::new();
+		let mut nodes  = vec![(1_usize, 0_usize, LEAVES)];
+		while let Some((node, first, width)) = nodes.pop() {
+			if first >= usize::from(window.end) || !self.latest.get(node).is_some_and(|&end| end > start) {
+				continue;
+			}
+			if width == 1 {
+				starts.extend(u16::try_from(first).ok());
+				continue;
+			}
+			let half = width / 2;
+			nodes.push((node * 2 + 1, first + half, half));
+			nodes.push((node * 2,     first,        half));
+		}
+		starts.into_iter()
+			.filter_map(|first| self.starts.get(&first))
+			.flat_map(move |ends| ends.range(start.saturating_add(1)..))
+			.flat_map(|(_, keys)| keys)
+	}
+	
+	//		remove																
+	/// Removes a part of a reservation.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `key`    - The key of the reservation.
+	/// * `window` - The time window of the part, as it was added.
+	/// 
+	fn remove(&mut self, key: &K, window: &Range<u16>) {
+		let Some(ends) = self.starts.get_mut(&window.start) else {
+			return;
+		};
+		if let Some(keys) = ends.get_mut(&window.end) {
+			_ = keys.remove(key);
+			if keys.is_empty() {
+				drop(ends.remove(&window.end));
+			}
+		}
+		if ends.is_empty() {
+			drop(self.starts.remove(&window.start));
+		}
+		self.update(window.start);
+	}
+	
+	//		Private methods														
+	
+	//		update																
+	/// Refreshes the latest ends held in the tree for a start time, after the
+	/// parts starting then have changed.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `start` - The start time of the parts that have changed.
+	/// 
+	fn update(&mut self, start: u16) {
+		if self.starts.is_empty() {
+			self.latest = Vec::new();
+			return;
+		}
+		if self.latest.is_empty() {
+			self.latest = vec![0; LEAVES * 2];
+		}
+		let mut node = LEAVES + usize::from(start);
+		let mut end  = self.starts.get(&start)
+			.and_then(|ends| ends.last_key_value())
+			.map_or(0, |(&end, _)| end);
+		while node > 0 {
+			if let Some(latest) = self.latest.get_mut(node) {
+				*latest = end;
+			}
+			end  = end.max(self.latest.get(node ^ 1).copied().unwrap_or(0));
+			node /= 2;
+		}
+	}
+}
+
+//		Reservation																
+/// A time window that recurs on a set of days of the week.
+/// 
+/// The window is given in minutes from midnight, and can run past midnight
+/// into the following day, up to 48:00, so that a reservation from 22:00 to
+/// 02:00 on Fridays is held as `1320..1560`, and also takes up the start of
+/// each Saturday. A window running past midnight on Sunday carries on into
+/// Monday.
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Reservation {
+	/// The days on which the window starts.
+	days:   Weekdays,
+	
+	/// The time window, in minutes from midnight.
+	window: Range<u16>,
+}
+
+//󰭅		Reservation																
+impl Reservation {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Reservation`] for the given days and time window.
+	/// 
+	/// Returns [`None`] if the window is empty, starts at or after midnight at
+	/// the end of the day, or ends after midnight at the end of the following
+	/// day.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`   - The days on which the window starts.
+	/// * `window` - The time window, in minutes from midnight.
+	/// 
+	#[must_use]
+	pub fn new(days: Weekdays, window: Range<u16>) -> Option<Self> {
+		if window.is_empty() || window.start >= MINUTES_PER_DAY || window.end > MAX_MINUTE {
+			return None;
+		}
+		Some(Self { days, window })
+	}
+	
+	//		Public methods														
+	
+	//		conflicts															
+	/// Returns the days on which this reservation clashes with another.
+	/// 
+	/// Two reservations clash on a day if their time windows overlap on that
+	/// day. Windows that only touch, such as one ending at 10:00 and another
+	/// starting at 10:00, do not clash. For windows running past midnight, the
+	/// clash can be on the day after the one the reservation starts on.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The other reservation.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, conflict::Reservation};
+	/// 
+	/// let late_shift  = Reservation::new(Weekdays::FRIDAY,   1320..1560).unwrap();
+	/// let early_shift = Reservation::new(Weekdays::SATURDAY, 60..480).unwrap();
+	/// 
+	/// assert_eq!(late_shift.conflicts(&early_shift), Weekdays::SATURDAY);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn conflicts(&self, other: &Self) -> Weekdays {
+		let theirs = other.segments();
+		self.segments()
+			.into_iter()
+			.filter(|&(day, ref window)| {
+				theirs.iter().any(|&(other_day, ref other_window)| {
+					day == other_day && window.start.max(other_window.start) < window.end.min(other_window.end)
+				})
+			})
+			.fold(Weekdays::NONE, |days, (day, _)| days | Weekdays::from_index(day))
+	}
+	
+	//		days																
+	/// Returns the days on which the window starts.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		window																
+	/// Returns the time window, in minutes from midnight.
+	#[must_use]
+	pub fn window(&self) -> Range<u16> {
+		self.window.clone()
+	}
+	
+	//		Private methods														
+	
+	//		segments															
+	/// Splits the reservation into the parts that fall on each day, by day
+	/// position, from zero for Monday to six for Sunday.
+	#[expect(clippy::arithmetic_side_effects, reason = "Windows are checked to fit within two days")]
+	fn segments(&self) -> Vec<(usize, Range<u16>)> {
+		let mut segments = Vec::new();
+		for (index, day) in Weekdays::ALL_DAYS.iter().enumerate() {
+			if self.days.contains(day) {
+				segments.push((index, self.window.start..self.window.end.min(MINUTES_PER_DAY)));
+				if self.window.end > MINUTES_PER_DAY {
+					segments.push(((index + 1) % 7, 0..self.window.end - MINUTES_PER_DAY));
+				}
+			}
+		}
+		segments
+	}
+}
+
@@ -0,0 +1,340 @@
+//! Conversion to and from the day-of-week field of cron expressions.
+//! 
+//! The [`parse_dow()`] and [`to_dow()`] functions convert [`Weekdays`] to and
+//! from the day-of-week field, in either of the numbering schemes given by
+//! [`CronDialect`]. Numbers, three-letter names, ranges, lists, steps, `*`, and
+//! `?` are all understood, and output is always the shortest equivalent form.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/cron.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	iter,
+};
+
+
+
+//		Constants
+
+/// The three-letter day names, from Sunday to Saturday.
+const NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+
+
+//		Enums
+
+//		CronDialect																
+/// The numbering schemes used for the day-of-week field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CronDialect {
+	/// Quartz numbering, as used by Quartz and Spring, where 1 is Sunday and 7
+	/// is Saturday. Ranges can wrap around the end of the week, such as
+	/// `FRI-MON`.
+	Quartz,
+	
+	/// Vixie numbering, as used by most Unix cron implementations, where 0 is
+	/// Sunday, 6 is Saturday, and 7 is also Sunday. Ranges cannot wrap around,
+	/// but can end on 7, such as `5-7`.
+	Vixie,
+}
+
+//󰭅		CronDialect																
+impl CronDialect {
+	//		Private methods														
+	
+	//		day																	
+	/// Returns the day for the given number, if it is in range.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The number of the day.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Values are always less than 8")]
+	fn day(self, value: u8) -> Option<Weekdays> {
+		let (min, max) = self.limits();
+		if value < min || value > max {
+			return None;
+		}
+		let sunday_first = match self {
+			Self::Quartz => value.checked_sub(1)?,
+			Self::Vixie  => value % 7,
+		};
+		//	Weekdays are ordered from Monday, so Sunday moves to the end
+		Weekdays::ALL_DAYS.iter().nth(usize::from((sunday_first + 6) % 7))
+	}
+	
+	//		limits																
+	/// Returns the lowest and highest numbers allowed.
+	const fn limits(self) -> (u8, u8) {
+		match self {
+			Self::Quartz => (1, 7),
+			Self::Vixie  => (0, 7),
+		}
+	}
+	
+	//		number																
+	/// Returns the number for the given name, if it is valid.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The three-letter name of the day.
+	/// 
+	fn number(self, name: &str) -> Option<u8> {
+		let index = NAMES.iter().position(|candidate| candidate.eq_ignore_ascii_case(name))?;
+		let value = u8::try_from(index).ok()?;
+		match self {
+			Self::Quartz => value.checked_add(1),
+			Self::Vixie  => Some(value),
+		}
+	}
+	
+	//		value																
+	/// Parses a single value, which can be a number or a name.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `text` - The text to parse.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the value is not a valid number or name, or uses
+	/// Quartz syntax that has no equivalent as a set of days.
+	/// 
+	fn value(self, text: &str) -> Result<u8, CronError> {
+		let (min, max) = self.limits();
+		text.parse::<u8>()
+			.ok()
+			.filter(|&value| value >= min && value <= max)
+			.or_else(|| self.number(text))
+			.ok_or_else(|| {
+				if text.contains('#') || text.ends_with(['L', 'l', 'W', 'w']) {
+					CronError::UnsupportedSyntax(text.to_owned())
+				} else {
+					CronError::InvalidValue(text.to_owned())
+				}
+			})
+	}
+}
+
+//		CronError																
+/// The errors that can occur when parsing a day-of-week field.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CronError {
+	/// The field, or an entry in a list, was empty.
+	Empty,
+	
+	/// The range ended before it started, which is only allowed in Quartz
+	/// numbering.
+	InvalidRange(String),
+	
+	/// The step was not a positive number.
+	InvalidStep(String),
+	
+	/// The value was not a valid number or name for the dialect.
+	InvalidValue(String),
+	
+	/// The entry used Quartz syntax such as `6L` or `2#1`, which selects a
+	/// particular week of the month rather than a set of days.
+	UnsupportedSyntax(String),
+}
+
+//󰭅		Display																	
+impl Display for CronError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Empty                        => write!(f, "Empty day-of-week entry"),
+			Self::InvalidRange(ref range)      => write!(f, "Invalid day-of-week range: {range}"),
+			Self::InvalidStep(ref step)        => write!(f, "Invalid day-of-week step: {step}"),
+			Self::InvalidValue(ref value)      => write!(f, "Invalid day-of-week value: {value}"),
+			Self::UnsupportedSyntax(ref entry) => write!(f, "Unsupported day-of-week syntax: {entry}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for CronError {}
+
+
+
+//		Functions
+
+//		parse_dow																
+/// Parses a day-of-week field into a set of days.
+/// 
+/// The field is a comma-separated list of entries, each of which can be:
+/// 
+///   - A number or a three-letter name, such as `1` or `MON`.
+///   - A range, such as `1-5` or `MON-FRI`.
+///   - `*` or `?`, meaning every day.
+///   - Any of the above followed by a step, such as `*/2` or `1-5/2`. A single
+///     value with a step, such as `1/2`, runs to the end of the week.
+/// 
+/// Names are matched without regard to case. In Vixie numbering, a range that
+/// ends on `SUN` is taken to end on 7, so `FRI-SUN` works as expected.
+/// 
+/// # Parameters
+/// 
+/// * `field`   - The day-of-week field.
+/// * `dialect` - The numbering scheme to use.
+/// 
+/// # Errors
+/// 
+/// Returns a [`CronError`] describing the first entry that could not be
+/// parsed.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, cron::{CronDialect, parse_dow}};
+/// 
+/// assert_eq!(parse_dow("1-5",     CronDialect::Vixie),  Ok(Weekdays::WEEKDAYS));
+/// assert_eq!(parse_dow("SAT,0",   CronDialect::Vixie),  Ok(Weekdays::WEEKENDS));
+/// assert_eq!(parse_dow("2-6",     CronDialect::Quartz), Ok(Weekdays::WEEKDAYS));
+/// assert_eq!(parse_dow("FRI-MON", CronDialect::Quartz), Ok(Weekdays::parse_const("fri-mon")));
+/// ```
+/// 
+pub fn parse_dow(field: &str, dialect: CronDialect) -> Result<Weekdays, CronError> {
+	field.split(',').try_fold(Weekdays::NONE, |days, entry| Ok(days | parse_entry(entry, dialect)?))
+}
+
+//		to_dow																	
+/// Formats a set of days as a day-of-week field.
+/// 
+/// The shortest equivalent form is chosen, using numbers rather than names.
+/// Lists and ranges are preferred over steps where they are the same length.
+/// If no days are set, there is no equivalent field, and [`None`] is returned.
+/// 
+/// # Parameters
+/// 
+/// * `days`    - The days to format.
+/// * `dialect` - The numbering scheme to use.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, cron::{CronDialect, to_dow}};
+/// 
+/// assert_eq!(to_dow(Weekdays::WEEKDAYS, CronDialect::Vixie).as_deref(),  Some("1-5"));
+/// assert_eq!(to_dow(Weekdays::WEEKENDS, CronDialect::Vixie).as_deref(),  Some("0,6"));
+/// assert_eq!(to_dow(Weekdays::WEEKDAYS, CronDialect::Quartz).as_deref(), Some("2-6"));
+/// assert_eq!(to_dow(Weekdays::ALL_DAYS, CronDialect::Quartz).as_deref(), Some("*"));
+/// assert_eq!(to_dow(Weekdays::NONE,     CronDialect::Vixie),             None);
+/// ```
+/// 
+#[must_use]
+pub fn to_dow(days: Weekdays, dialect: CronDialect) -> Option<String> {
+	if days.is_empty() {
+		return None;
+	}
+	if days == Weekdays::ALL_DAYS {
+		return Some("*".to_owned());
+	}
+	let (min, max) = dialect.limits();
+	let selected   = |value: &u8| dialect.day(*value).is_some_and(|day| days.contains(day));
+	let lists      = match dialect {
+		//	Quartz ranges can wrap, so the list can start from any day
+		CronDialect::Quartz => (1..=7_u8).map(|first| format_runs((first..=7).chain(1..first).filter(selected))).collect(),
+		CronDialect::Vixie  => vec![format_runs((0..7).filter(selected)), format_runs((1..=7).filter(selected))],
+	};
+	let steps      = (2..=6_u8).flat_map(|step| {
+		iter::once(format!("*/{step}")).chain((min..=max).flat_map(move |start| {
+			iter::once(format!("{start}/{step}")).chain((min..=max).map(move |end| format!("{start}-{end}/{step}")))
+		}))
+	});
+	//	Lists are tried first, so that they win ties
+	lists.into_iter()
+		.chain(steps)
+		.filter(|candidate| parse_dow(candidate, dialect) == Ok(days))
+		.min_by_key(String::len)
+}
+
+//		format_runs																
+/// Formats numbers as a list, using ranges for runs of two or more.
+/// 
+/// A run continues from 7 to 1, to allow for Quartz ranges that wrap around.
+/// 
+/// # Parameters
+/// 
+/// * `numbers` - The numbers to format, in order.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Values are always less than 8")]
+fn format_runs<I>(numbers: I) -> String
+	where I: Iterator<Item = u8>,
+{
+	let mut runs = Vec::<(u8, u8)>::new();
+	for number in numbers {
+		match runs.last_mut() {
+			Some(&mut (_, ref mut end)) if *end % 7 + 1 == number => *end = number,
+			_                                                    => runs.push((number, number)),
+		}
+	}
+	runs.iter()
+		.map(|&(start, end)| if start == end { start.to_string() } else { format!("{start}-{end}") })
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+//		parse_entry																
+/// Parses a single entry of a day-of-week field.
+/// 
+/// # Parameters
+/// 
+/// * `entry`   - The entry to parse.
+/// * `dialect` - The numbering scheme to use.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the entry could not be parsed.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Values are always between the limits")]
+fn parse_entry(entry: &str, dialect: CronDialect) -> Result<Weekdays, CronError> {
+	let (min, max)   = dialect.limits();
+	let (base, step) = match entry.split_once('/') {
+		Some((base, text)) => {
+			let step = text.parse::<u8>().ok().filter(|&n| n > 0).ok_or_else(|| CronError::InvalidStep(text.to_owned()))?;
+			(base, Some(step))
+		}
+		None               => (entry, None),
+	};
+	let (start, end) = match (base, base.split_once('-')) {
+		("", _)               => return Err(CronError::Empty),
+		("*" | "?", _)        => (min, max),
+		(_, Some((from, to))) => {
+			let first = dialect.value(from)?;
+			let value = dialect.value(to)?;
+			//	Allow ranges such as FRI-SUN to end on 7
+			let last  = if dialect == CronDialect::Vixie && value == 0 && first > 0 { 7 } else { value };
+			if last < first && dialect == CronDialect::Vixie {
+				return Err(CronError::InvalidRange(base.to_owned()));
+			}
+			(first, last)
+		}
+		(_, None)             => {
+			let value = dialect.value(base)?;
+			(value, if step.is_some() { max } else { value })
+		}
+	};
+	//	Quartz ranges can wrap around, so the numbers are walked in sequence
+	let span    = if end >= start { end - start } else { end + 7 - start };
+	let numbers = (0..=span).map(|offset| (start + offset - min) % 7 + min);
+	Ok(numbers
+		.step_by(usize::from(step.unwrap_or(1)))
+		.filter_map(|value| dialect.day(value))
+		.fold(Weekdays::NONE, |days, day| days | day))
+}
@@ -0,0 +1,871 @@
+//! Boolean expressions over sets of days.
+//! 
+//! Rules can be written as expressions such as `(weekdays | sat) - wed` or
+//! `!weekends & (mon | fri)`, which combine named sets of days using the same
+//! operators as [`Weekdays`] itself:
+//! 
+//!   - `a | b` gives the days in either set, as for [`BitOr`](core::ops::BitOr).
+//!   - `a & b` gives the days in both sets, as for [`BitAnd`](core::ops::BitAnd).
+//!   - `a ^ b` gives the days in one set but not both, as for
+//!     [`BitXor`](core::ops::BitXor).
+//!   - `a - b` gives the days in the first set but not the second, as for
+//!     [`Sub`](core::ops::Sub).
+//!   - `!a` gives the days not in the set, as for [`Not`](core::ops::Not).
+//! 
+//! Operators follow the same precedence as in Rust, from `!` binding most
+//! tightly, through `-`, `&`, and `^`, to `|` binding least tightly, and
+//! parentheses can be used to group terms.
+//! 
+//! Names are matched without regard to case. The day names, such as `mon` or
+//! `monday`, and the sets `weekdays`, `weekends`, `all`, and `none` are always
+//! available, and other sets, such as `gym_days`, can be added using
+//! [`NamedSets`].
+//! 
+//! An [`Expression`] is parsed once and can then be evaluated many times. It
+//! is written back by [`Display`] in a canonical form, with names in lowercase,
+//! single spaces around operators, and only the parentheses that are needed.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/expression.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	hash::{Hash, Hasher},
+	mem,
+	ops::Range,
+	str::FromStr,
+};
+use std::collections::BTreeMap;
+
+
+
+//		Constants
+
+/// The names that are always available, and the days they refer to.
+const BUILT_IN_SETS: [(&str, Weekdays); 18] = [
+	("mon",       Weekdays::MONDAY),
+	("monday",    Weekdays::MONDAY),
+	("tue",       Weekdays::TUESDAY),
+	("tuesday",   Weekdays::TUESDAY),
+	("wed",       Weekdays::WEDNESDAY),
+	("wednesday", Weekdays::WEDNESDAY),
+	("thu",       Weekdays::THURSDAY),
+	("thursday",  Weekdays::THURSDAY),
+	("fri",       Weekdays::FRIDAY),
+	("friday",    Weekdays::FRIDAY),
+	("sat",       Weekdays::SATURDAY),
+	("saturday",  Weekdays::SATURDAY),
+	("sun",       Weekdays::SUNDAY),
+	("sunday",    Weekdays::SUNDAY),
+	("weekdays",  Weekdays::WEEKDAYS),
+	("weekends",  Weekdays::WEEKENDS),
+	("all",       Weekdays::ALL_DAYS),
+	("none",      Weekdays::NONE),
+];
+
+/// The deepest that an expression can be nested, counting each complement,
+/// parenthesis, and binary operator, so that parsing and evaluating cannot
+/// overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+
+
+//		Enums
+
+//		ExpressionError															
+/// The errors that can occur when parsing or evaluating an expression.
+/// 
+/// Each error gives the span of the source text at fault, as a range of byte
+/// offsets, which can be used to point at the problem.
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ExpressionError {
+	/// The expression contained a character that is not part of the language.
+	InvalidCharacter {
+		/// The character.
+		character: char,
+		
+		/// The span of the character.
+		span:      Range<usize>,
+	},
+	
+	/// The expression was nested too deeply, counting each complement,
+	/// parenthesis, and binary operator. Long chains of operators count too,
+	/// as each operator nests the part of the expression before it.
+	TooDeep {
+		/// The span of the token at which the limit was passed.
+		span: Range<usize>,
+	},
+	
+	/// An opening parenthesis was not closed.
+	UnclosedParenthesis {
+		/// The span of the opening parenthesis.
+		span: Range<usize>,
+	},
+	
+	/// The expression ended where a name or an opening parenthesis was
+	/// expected, including when it was empty.
+	UnexpectedEnd {
+		/// The empty span at the end of the expression.
+		span: Range<usize>,
+	},
+	
+	/// A token was found where it is not allowed, such as two names in a row,
+	/// or an operator with nothing before it.
+	UnexpectedToken {
+		/// The token, with names in lowercase.
+		token: String,
+		
+		/// The span of the token.
+		span:  Range<usize>,
+	},
+	
+	/// A name did not refer to a known set of days.
+	UnknownName {
+		/// The name, in lowercase.
+		name: String,
+		
+		/// The span of the name.
+		span: Range<usize>,
+	},
+}
+
+//󰭅		ExpressionError															
+impl ExpressionError {
+	//		Public methods														
+	
+	//		span																
+	/// Returns the span of the source text at fault, as a range of byte
+	/// offsets.
+	#[must_use]
+	pub fn span(&self) -> Range<usize> {
+		match *self {
+			Self::InvalidCharacter { ref span, .. }   |
+			Self::TooDeep { ref span }                |
+			Self::UnclosedParenthesis { ref span }    |
+			Self::UnexpectedEnd { ref span }          |
+			Self::UnexpectedToken { ref span, .. }    |
+			Self::UnknownName { ref span, .. }        => span.clone(),
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for ExpressionError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let Range { start, end } = self.span();
+		match *self {
+			Self::InvalidCharacter { character, .. } => write!(f, "Invalid character at {start}..{end}: {character}"),
+			Self::TooDeep { .. }                     => write!(f, "Expression nested more than {MAX_DEPTH} levels deep at {start}..{end}"),
+			Self::UnclosedParenthesis { .. }         => write!(f, "Unclosed parenthesis at {start}..{end}"),
+			Self::UnexpectedEnd { .. }               => write!(f, "Unexpected end of expression at {start}"),
+			Self::UnexpectedToken { ref token, .. }  => write!(f, "Unexpected token at {start}..{end}: {token}"),
+			Self::UnknownName { ref name, .. }       => write!(f, "Unknown name at {start}..{end}: {name}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for ExpressionError {}
+
+//		Node																	
+/// A node in a parsed expression.
+/// 
+/// Nodes are compared and hashed without regard to the spans of names, so
+/// that expressions that differ only in spacing are equal.
+/// 
+#[derive(Clone, Debug)]
+enum Node {
+	/// A binary operation.
+	Binary {
+		/// The operator.
+		operator: Operator,
+		
+		/// The left operand.
+		left:     Box<Self>,
+		
+		/// The right operand.
+		right:    Box<Self>,
+	},
+	
+	/// A named set of days.
+	Name {
+		/// The name, in lowercase.
+		name: String,
+		
+		/// The span of the name in the source text.
+		span: Range<usize>,
+	},
+	
+	/// The complement of a set of days.
+	Not(Box<Self>),
+}
+
+//󰭅		Node																	
+impl Node {
+	//		Public methods														
+	
+	//		evaluate															
+	/// Works out the days described by the node.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `sets` - The named sets of days.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`ExpressionError::UnknownName`] if a name is not known.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Subtraction of day sets cannot overflow")]
+	fn evaluate(&self, sets: &NamedSets) -> Result<Weekdays, ExpressionError> {
+		match *self {
+			Self::Binary { operator, ref left, ref right } => {
+				let (lhs, rhs) = (left.evaluate(sets)?, right.evaluate(sets)?);
+				Ok(match operator {
+					Operator::And => lhs & rhs,
+					Operator::Or  => lhs | rhs,
+					Operator::Sub => lhs - rhs,
+					Operator::Xor => lhs ^ rhs,
+				})
+			}
+			Self::Name { ref name, ref span }              => sets.get(name).ok_or_else(|| ExpressionError::UnknownName {
+				name: name.clone(),
+				span: span.clone(),
+			}),
+			Self::Not(ref operand)                         => Ok(!operand.evaluate(sets)?),
+		}
+	}
+	
+	//		names																
+	/// Adds the names used by the node to the given list, in order.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `names` - The list of names to add to.
+	/// 
+	fn names<'a>(&'a self, names: &mut Vec<&'a str>) {
+		match *self {
+			Self::Binary { ref left, ref right, .. } => {
+				left.names(names);
+				right.names(names);
+			}
+			Self::Name { ref name, .. }              => {
+				if !names.contains(&name.as_str()) {
+					names.push(name);
+				}
+			}
+			Self::Not(ref operand)                   => operand.names(names),
+		}
+	}
+	
+	//		Private methods														
+	
+	//		precedence															
+	/// Returns how tightly the node binds, where names and complements bind
+	/// more tightly than any binary operator.
+	const fn precedence(&self) -> u8 {
+		match *self {
+			Self::Binary { operator, .. }  => operator.precedence(),
+			Self::Name { .. } | Self::Not(_) => u8::MAX,
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Node {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Binary { operator, ref left, ref right } => {
+				//	Operators are left-associative, so a right operand at the same
+				//	level needs parentheses to keep its meaning
+				let needs_left  = left.precedence() < operator.precedence();
+				let needs_right = right.precedence() <= operator.precedence();
+				write_operand(f, left, needs_left)?;
+				write!(f, " {operator} ")?;
+				write_operand(f, right, needs_right)
+			}
+			Self::Name { ref name, .. }                    => write!(f, "{name}"),
+			Self::Not(ref operand)                         => {
+				write!(f, "!")?;
+				write_operand(f, operand, matches!(**operand, Self::Binary { .. }))
+			}
+		}
+	}
+}
+
+//󰭅		Eq																		
+impl Eq for Node {}
+
+//󰭅		Hash																	
+impl Hash for Node {
+	//		hash																
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		mem::discriminant(self).hash(state);
+		match *self {
+			Self::Binary { operator, ref left, ref right } => {
+				operator.hash(state);
+				left.hash(state);
+				right.hash(state);
+			}
+			Self::Name { ref name, .. }                    => name.hash(state),
+			Self::Not(ref operand)                         => operand.hash(state),
+		}
+	}
+}
+
+//󰭅		PartialEq																
+impl PartialEq for Node {
+	//		eq																	
+	fn eq(&self, other: &Self) -> bool {
+		match *self {
+			Self::Binary { operator, ref left, ref right } => matches!(
+				*other,
+				Self::Binary { operator: other_operator, left: ref other_left, right: ref other_right }
+					if operator == other_operator && left == other_left && right == other_right
+			),
+			Self::Name { ref name, .. }                    => matches!(*other, Self::Name { name: ref other_name, .. } if name == other_name),
+			Self::Not(ref operand)                         => matches!(*other, Self::Not(ref other_operand) if operand == other_operand),
+		}
+	}
+}
+
+//		Operator																
+/// The binary operators.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Operator {
+	/// Intersection, written `&`.
+	And,
+	
+	/// Union, written `|`.
+	Or,
+	
+	/// Difference, written `-`.
+	Sub,
+	
+	/// Symmetric difference, written `^`.
+	Xor,
+}
+
+//󰭅		Operator																
+impl Operator {
+	//		Public methods														
+	
+	//		precedence															
+	/// Returns how tightly the operator binds, where higher values bind more
+	/// tightly.
+	const fn precedence(self) -> u8 {
+		match self {
+			Self::Or  => 1,
+			Self::Xor => 2,
+			Self::And => 3,
+			Self::Sub => 4,
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Operator {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let symbol = match *self {
+			Self::And => "&",
+			Self::Or  => "|",
+			Self::Sub => "-",
+			Self::Xor => "^",
+		};
+		write!(f, "{symbol}")
+	}
+}
+
+//		TokenKind																
+/// The kinds of token in an expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum TokenKind {
+	/// A closing parenthesis.
+	Close,
+	
+	/// A name, in lowercase.
+	Name(String),
+	
+	/// The complement operator, `!`.
+	Not,
+	
+	/// An opening parenthesis.
+	Open,
+	
+	/// A binary operator.
+	Operator(Operator),
+}
+
+//󰭅		Display																	
+impl Display for TokenKind {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Close                  => write!(f, ")"),
+			Self::Name(ref name)         => write!(f, "{name}"),
+			Self::Not                    => write!(f, "!"),
+			Self::Open                   => write!(f, "("),
+			Self::Operator(operator)     => write!(f, "{operator}"),
+		}
+	}
+}
+
+
+
+//		Structs
+
+//		Expression																
+/// A parsed expression over sets of days.
+/// 
+/// Expressions are parsed using [`FromStr`], and evaluated against a set of
+/// names using [`evaluate()`](Self::evaluate()). Names are only looked up when
+/// evaluating, so an expression can be parsed before all of its names are
+/// known.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, expression::{Expression, NamedSets}};
+/// 
+/// let expression: Expression = "!Weekends & (mon|fri)".parse().unwrap();
+/// assert_eq!(expression.to_string(), "!weekends & (mon | fri)");
+/// assert_eq!(expression.evaluate(&NamedSets::new()), Ok(Weekdays::parse_const("mon, fri")));
+/// 
+/// let sets       = NamedSets::new().with_set("gym_days", Weekdays::parse_const("mon, wed, sat")).unwrap();
+/// let expression = "gym_days - weekends".parse::<Expression>().unwrap();
+/// assert_eq!(expression.evaluate(&sets), Ok(Weekdays::parse_const("mon, wed")));
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Expression {
+	/// The root of the parsed expression.
+	root: Node,
+}
+
+//󰭅		Expression																
+impl Expression {
+	//		Public methods														
+	
+	//		evaluate															
+	/// Works out the days described by the expression.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `sets` - The named sets of days.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`ExpressionError::UnknownName`] for the first name that is not
+	/// known, with its span in the source text.
+	/// 
+	pub fn evaluate(&self, sets: &NamedSets) -> Result<Weekdays, ExpressionError> {
+		self.root.evaluate(sets)
+	}
+	
+	//		names																
+	/// Returns the names used by the expression, in lowercase, in the order
+	/// they first appear.
+	/// 
+	/// This can be used to check that every name is known before evaluating.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::expression::Expression;
+	/// 
+	/// let expression: Expression = "(gym_days | sat) - gym_days".parse().unwrap();
+	/// assert_eq!(expression.names(), vec!["gym_days", "sat"]);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn names(&self) -> Vec<&str> {
+		let mut names = Vec::new();
+		self.root.names(&mut names);
+		names
+	}
+}
+
+//󰭅		Display																	
+impl Display for Expression {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.root)
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for Expression {
+	type Err = ExpressionError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parser = Parser { depth: 0, end: s.len(), position: 0, tokens: tokenize(s)? };
+		let root       = parser.parse_binary(1)?;
+		parser.peek().map_or(Ok(Self { root }), |token| Err(token.unexpected()))
+	}
+}
+
+//		NamedSets																
+/// A collection of named sets of days, for evaluating expressions.
+/// 
+/// The day names, such as `mon` or `monday`, and the sets `weekdays`,
+/// `weekends`, `all`, and `none` are always available. Other sets can be added,
+/// and names are matched without regard to case.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, expression::NamedSets};
+/// 
+/// let sets = NamedSets::new().with_set("Gym_Days", Weekdays::parse_const("mon, wed")).unwrap();
+/// assert_eq!(sets.get("gym_days"), Some(Weekdays::parse_const("mon, wed")));
+/// assert_eq!(sets.get("weekends"), Some(Weekdays::WEEKENDS));
+/// assert_eq!(sets.get("unknown"),  None);
+/// ```
+/// 
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NamedSets {
+	/// The sets added by the user, keyed by lowercase name.
+	sets: BTreeMap<String, Weekdays>,
+}
+
+//󰭅		NamedSets																
+impl NamedSets {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`NamedSets`] with only the built-in names.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+	
+	//		with_set															
+	/// Adds a named set of days, replacing any set with the same name.
+	/// 
+	/// Returns [`None`] if the name is not valid, or if it is one of the
+	/// built-in names.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name, which must start with a letter or underscore, and
+	///   contain only letters, digits, and underscores.
+	/// * `days` - The days in the set.
+	/// 
+	#[must_use]
+	pub fn with_set(mut self, name: &str, days: Weekdays) -> Option<Self> {
+		let lowercase = name.to_lowercase();
+		let valid     = name.chars().next().is_some_and(is_name_start) && name.chars().all(is_name_char);
+		if !valid || built_in(&lowercase).is_some() {
+			return None;
+		}
+		_ = self.sets.insert(lowercase, days);
+		Some(self)
+	}
+	
+	//		Public methods														
+	
+	//		get																	
+	/// Returns the days for the given name, if known.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The name to look up.
+	/// 
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<Weekdays> {
+		let lowercase = name.to_lowercase();
+		built_in(&lowercase).or_else(|| self.sets.get(&lowercase).copied())
+	}
+}
+
+//		Parser																	
+/// A recursive-descent parser over the tokens of an expression.
+struct Parser {
+	/// How deeply the expression being parsed is nested at this point.
+	depth:    usize,
+	
+	/// The length of the source text, used for errors at the end.
+	end:      usize,
+	
+	/// The index of the next token.
+	position: usize,
+	
+	/// The tokens of the expression.
+	tokens:   Vec<Token>,
+}
+
+//󰭅		Parser																	
+impl Parser {
+	//		Public methods														
+	
+	//		parse_binary														
+	/// Parses binary operations that bind at least as tightly as the given
+	/// level.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `level` - The lowest operator precedence to accept.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the tokens do not form a valid expression.
+	/// 
+	/// Returns [`ExpressionError::TooDeep`] if the expression is nested too
+	/// deeply.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Precedence levels are small")]
+	fn parse_binary(&mut self, level: u8) -> Result<Node, ExpressionError> {
+		let depth    = self.depth;
+		let mut left = self.parse_unary()?;
+		while let Some(operator) = self.peek_operator().filter(|operator| operator.precedence() >= level) {
+			self.nest()?;
+			self.advance();
+			let right = self.parse_binary(operator.precedence() + 1)?;
+			left      = Node::Binary { operator, left: Box::new(left), right: Box::new(right) };
+		}
+		self.depth = depth;
+		Ok(left)
+	}
+	
+	//		peek																
+	/// Returns the next token, without consuming it.
+	fn peek(&self) -> Option<&Token> {
+		self.tokens.get(self.position)
+	}
+	
+	//		Private methods														
+	
+	//		advance																
+	/// Moves on to the next token.
+	#[expect(clippy::arithmetic_side_effects, reason = "There cannot be usize::MAX tokens")]
+	fn advance(&mut self) {
+		self.position += 1;
+	}
+	
+	//		nest																
+	/// Goes one level deeper into the expression, at the next token.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`ExpressionError::TooDeep`] if this passes the limit.
+	/// 
+	fn nest(&mut self) -> Result<(), ExpressionError> {
+		if self.depth >= MAX_DEPTH {
+			let span = self.peek().map_or(self.end..self.end, |token| token.span.clone());
+			return Err(ExpressionError::TooDeep { span });
+		}
+		self.depth = self.depth.saturating_add(1);
+		Ok(())
+	}
+	
+	//		parse_unary															
+	/// Parses a name, a complement, or a parenthesised expression.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the tokens do not form a valid expression, or
+	/// [`ExpressionError::TooDeep`] if the expression is nested too deeply.
+	/// 
+	fn parse_unary(&mut self) -> Result<Node, ExpressionError> {
+		let Some(token) = self.peek().cloned() else {
+			return Err(ExpressionError::UnexpectedEnd { span: self.end..self.end });
+		};
+		let depth = self.depth;
+		self.nest()?;
+		self.advance();
+		let node  = match token.kind.clone() {
+			TokenKind::Name(name)                      => Ok(Node::Name { name, span: token.span }),
+			TokenKind::Not                             => Ok(Node::Not(Box::new(self.parse_unary()?))),
+			TokenKind::Open                            => {
+				let inner = self.parse_binary(1)?;
+				if self.peek().map(|next| &next.kind) != Some(&TokenKind::Close) {
+					return Err(ExpressionError::UnclosedParenthesis { span: token.span });
+				}
+				self.advance();
+				Ok(inner)
+			}
+			TokenKind::Close | TokenKind::Operator(_)  => Err(token.unexpected()),
+		};
+		self.depth = depth;
+		node
+	}
+	
+	//		peek_operator														
+	/// Returns the next token if it is a binary operator, without consuming
+	/// it.
+	fn peek_operator(&self) -> Option<Operator> {
+		match self.peek()?.kind {
+			TokenKind::Operator(operator)                                            => Some(operator),
+			TokenKind::Close | TokenKind::Name(_) | TokenKind::Not | TokenKind::Open => None,
+		}
+	}
+}
+
+//		Token																	
+/// A token in an expression, with its span in the source text.
+#[derive(Clone, Debug)]
+struct Token {
+	/// The kind of token.
+	kind: TokenKind,
+	
+	/// The span of the token in the source text.
+	span: Range<usize>,
+}
+
+//󰭅		Token																	
+impl Token {
+	//		Public methods														
+	
+	//		unexpected															
+	/// Creates an error for the token being found where it is not allowed.
+	fn unexpected(&self) -> ExpressionError {
+		ExpressionError::UnexpectedToken {
+			token: self.kind.to_string(),
+			span:  self.span.clone(),
+		}
+	}
+}
+
+
+
+
+//		Functions
+
+//		evaluate																
+/// Parses and evaluates an expression in one step.
+/// 
+/// # Parameters
+/// 
+/// * `source` - The expression to evaluate.
+/// * `sets`   - The named sets of days.
+/// 
+/// # Errors
+/// 
+/// Returns an [`ExpressionError`] if the expression cannot be parsed, or if it
+/// uses a name that is not known.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, expression::{NamedSets, evaluate}};
+/// 
+/// assert_eq!(evaluate("(weekdays | sat) - wed", &NamedSets::new()), Ok(Weekdays::parse_const("mon-tue, thu-sat")));
+/// assert!(evaluate("weekdays |", &NamedSets::new()).is_err());
+/// ```
+/// 
+pub fn evaluate(source: &str, sets: &NamedSets) -> Result<Weekdays, ExpressionError> {
+	source.parse::<Expression>()?.evaluate(sets)
+}
+
+//		built_in																
+/// Returns the days for a built-in name, if it is one.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The name, in lowercase.
+/// 
+fn built_in(name: &str) -> Option<Weekdays> {
+	BUILT_IN_SETS.iter().find(|&&(candidate, _)| candidate == name).map(|&(_, days)| days)
+}
+
+//		is_name_char															
+/// Checks if a character can appear in a name.
+/// 
+/// # Parameters
+/// 
+/// * `character` - The character to check.
+/// 
+const fn is_name_char(character: char) -> bool {
+	character.is_ascii_alphanumeric() || character == '_'
+}
+
+//		is_name_start															
+/// Checks if a character can start a name.
+/// 
+/// # Parameters
+/// 
+/// * `character` - The character to check.
+/// 
+const fn is_name_start(character: char) -> bool {
+	character.is_ascii_alphabetic() || character == '_'
+}
+
+//		tokenize																
+/// Splits an expression into tokens.
+/// 
+/// # Parameters
+/// 
+/// * `source` - The expression to split.
+/// 
+/// # Errors
+/// 
+/// Returns [`ExpressionError::InvalidCharacter`] if the expression contains a
+/// character that is not part of the language.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Offsets are within the source text")]
+fn tokenize(source: &str) -> Result<Vec<Token>, ExpressionError> {
+	let mut tokens     = Vec::new();
+	let mut characters = source.char_indices().peekable();
+	while let Some((start, character)) = characters.next() {
+		let kind = match character {
+			'(' => TokenKind::Open,
+			')' => TokenKind::Close,
+			'!' => TokenKind::Not,
+			'&' => TokenKind::Operator(Operator::And),
+			'|' => TokenKind::Operator(Operator::Or),
+			'-' => TokenKind::Operator(Operator::Sub),
+			'^' => TokenKind::Operator(Operator::Xor),
+			_ if character.is_whitespace() => continue,
+			_ if is_name_start(character)  => {
+				let mut name = character.to_ascii_lowercase().to_string();
+				while let Some(&(_, next)) = characters.peek().filter(|&&(_, next)| is_name_char(next)) {
+					name.push(next.to_ascii_lowercase());
+					_ = characters.next();
+				}
+				let end = start + name.len();
+				tokens.push(Token { kind: TokenKind::Name(name), span: start..end });
+				continue;
+			}
+			_ => return Err(ExpressionError::InvalidCharacter {
+				character,
+				span: start..start + character.len_utf8(),
+			}),
+		};
+		tokens.push(Token { kind, span: start..start + character.len_utf8() });
+	}
+	Ok(tokens)
+}
+
+//		write_operand															
+/// Writes an operand, in parentheses if needed.
+/// 
+/// # Parameters
+/// 
+/// * `f`       - The formatter to write to.
+/// * `node`    - The operand to write.
+/// * `bracket` - Whether to put the operand in parentheses.
+/// 
+fn write_operand(f: &mut Formatter<'_>, node: &Node, bracket: bool) -> fmt::Result {
+	if bracket {
+		write!(f, "({node})")
+	} else {
+		write!(f, "{node}")
+	}
+}
@@ -0,0 +1,683 @@
+//! Reading and writing GTFS service calendars.
+//! 
+//! Transit feeds in the [General Transit Feed Specification](https://gtfs.org/schedule/reference/)
+//! define when each service runs using two files:
+//! 
+//!   - `calendar.txt`, which gives the days of the week that a service runs,
+//!     as seven `0` or `1` columns from `monday` to `sunday`, along with the
+//!     first and last dates of the service.
+//!   - `calendar_dates.txt`, which adds or removes service on specific dates,
+//!     such as for public holidays.
+//! 
+//! The [`read_calendar()`] and [`read_calendar_dates()`] functions stream
+//! records from any CSV source, and [`write_calendar()`] and
+//! [`write_calendar_dates()`] write them back out. The [`ServiceSchedule`]
+//! struct combines both sets of records to answer which services run on a given
+//! date.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/gtfs.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use chrono::{Datelike, NaiveDate};
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	iter,
+};
+use csv::{ReaderBuilder, StringRecord, Trim, Writer};
+use std::{
+	collections::{BTreeMap, BTreeSet, btree_map::Entry},
+	io::{Read, Write},
+};
+
+
+
+//		Constants
+
+/// The columns of `calendar.txt`, in the order they are written.
+const CALENDAR_COLUMNS: [&str; 10] = [
+	"service_id", "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday", "start_date", "end_date",
+];
+
+/// The columns of `calendar_dates.txt`, in the order they are written.
+const CALENDAR_DATES_COLUMNS: [&str; 3] = ["service_id", "date", "exception_type"];
+
+/// The format used for dates.
+const DATE_FORMAT: &str = "%Y%m%d";
+
+
+
+//		Enums
+
+//		ExceptionType															
+/// The kinds of exception given in `calendar_dates.txt`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ExceptionType {
+	/// Service has been added for the date, written as `1`.
+	Added,
+	
+	/// Service has been removed for the date, written as `2`.
+	Removed,
+}
+
+//		GtfsError																
+/// The errors that can occur when reading or writing GTFS calendars.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GtfsError {
+	/// The CSV source could not be read or written, with the underlying error
+	/// message.
+	Csv(String),
+	
+	/// A service has more than one exception for the same date.
+	DuplicateDate {
+		/// The ID of the service.
+		service_id: String,
+		
+		/// The date that was repeated.
+		date:       NaiveDate,
+	},
+	
+	/// A service has more than one calendar.
+	DuplicateService(String),
+	
+	/// The service ends before it starts.
+	InvalidDateRange(String),
+	
+	/// A field did not have a valid value for its column.
+	InvalidValue {
+		/// The name of the column.
+		column: String,
+		
+		/// The value that was found.
+		value:  String,
+	},
+	
+	/// A required column was not found in the header row.
+	MissingColumn(String),
+}
+
+//󰭅		Display																	
+impl Display for GtfsError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Csv(ref message)                       => write!(f, "CSV error: {message}"),
+			Self::DuplicateDate { ref service_id, date } => write!(f, "Duplicate exception for service {service_id} on {date}"),
+			Self::DuplicateService(ref service)          => write!(f, "Duplicate calendar for service: {service}"),
+			Self::InvalidDateRange(ref service)          => write!(f, "Invalid date range for service: {service}"),
+			Self::InvalidValue { ref column, ref value } => write!(f, "Invalid value for {column}: {value}"),
+			Self::MissingColumn(ref column)              => write!(f, "Missing column: {column}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for GtfsError {}
+
+//󰭅		From<csv::Error>														
+impl From<csv::Error> for GtfsError {
+	//		from																
+	fn from(error: csv::Error) -> Self {
+		Self::Csv(error.to_string())
+	}
+}
+
+
+
+//		Structs
+
+//		CalendarDate															
+/// A record from `calendar_dates.txt`, adding or removing service on a date.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CalendarDate {
+	/// The date of the exception.
+	date:           NaiveDate,
+	
+	/// Whether service is added or removed.
+	exception_type: ExceptionType,
+	
+	/// The ID of the service.
+	service_id:     String,
+}
+
+//󰭅		CalendarDate															
+impl CalendarDate {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`CalendarDate`].
+	/// 
+	/// # Parameters
+	/// 
+	/// * `service_id`     - The ID of the service.
+	/// * `date`           - The date of the exception.
+	/// * `exception_type` - Whether service is added or removed.
+	/// 
+	#[must_use]
+	pub fn new(service_id: &str, date: NaiveDate, exception_type: ExceptionType) -> Self {
+		Self { date, exception_type, service_id: service_id.to_owned() }
+	}
+	
+	//		Public methods														
+	
+	//		date																
+	/// Returns the date of the exception.
+	#[must_use]
+	pub const fn date(&self) -> NaiveDate {
+		self.date
+	}
+	
+	//		exception_type														
+	/// Returns whether service is added or removed.
+	#[must_use]
+	pub const fn exception_type(&self) -> ExceptionType {
+		self.exception_type
+	}
+	
+	//		service_id															
+	/// Returns the ID of the service.
+	#[must_use]
+	pub fn service_id(&self) -> &str {
+		&self.service_id
+	}
+}
+
+//		ServiceCalendar															
+/// A record from `calendar.txt`, giving the days of the week that a service
+/// runs between two dates.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveDate;
+/// use weekdays::{Weekdays, gtfs::ServiceCalendar};
+/// 
+/// let calendar = ServiceCalendar::new(
+///     "weekday",
+///     Weekdays::WEEKDAYS,
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+/// ).unwrap();
+/// 
+/// assert!( calendar.is_active(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()));
+/// assert!(!calendar.is_active(NaiveDate::from_ymd_opt(2024, 3, 2).unwrap()));
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ServiceCalendar {
+	/// The days of the week that the service runs.
+	days:       Weekdays,
+	
+	/// The last date of the service, inclusive.
+	end_date:   NaiveDate,
+	
+	/// The ID of the service.
+	service_id: String,
+	
+	/// The first date of the service.
+	start_date: NaiveDate,
+}
+
+//󰭅		ServiceCalendar															
+impl ServiceCalendar {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`ServiceCalendar`].
+	/// 
+	/// Returns [`None`] if the service ID is empty, or the end date is before
+	/// the start date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `service_id` - The ID of the service.
+	/// * `days`       - The days of the week that the service runs.
+	/// * `start_date` - The first date of the service.
+	/// * `end_date`   - The last date of the service, inclusive.
+	/// 
+	#[must_use]
+	pub fn new(service_id: &str, days: Weekdays, start_date: NaiveDate, end_date: NaiveDate) -> Option<Self> {
+		(!service_id.is_empty() && start_date <= end_date).then(|| Self {
+			days,
+			end_date,
+			service_id: service_id.to_owned(),
+			start_date,
+		})
+	}
+	
+	//		Public methods														
+	
+	//		days																
+	/// Returns the days of the week that the service runs.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		end_date															
+	/// Returns the last date of the service, inclusive.
+	#[must_use]
+	pub const fn end_date(&self) -> NaiveDate {
+		self.end_date
+	}
+	
+	//		is_active															
+	/// Checks if the service runs on the given date, ignoring any exceptions.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	#[must_use]
+	pub fn is_active(&self, date: NaiveDate) -> bool {
+		date >= self.start_date && date <= self.end_date && self.days.contains(Weekdays::from(date.weekday()))
+	}
+	
+	//		service_id															
+	/// Returns the ID of the service.
+	#[must_use]
+	pub fn service_id(&self) -> &str {
+		&self.service_id
+	}
+	
+	//		start_date															
+	/// Returns the first date of the service.
+	#[must_use]
+	pub const fn start_date(&self) -> NaiveDate {
+		self.start_date
+	}
+}
+
+//		ServiceSchedule															
+/// The combined service calendars and exceptions of a feed.
+/// 
+/// Exceptions take precedence over calendars, so a service runs on a date if
+/// service was added for that date, or if its calendar includes the date and
+/// service was not removed. Services can be defined by exceptions alone,
+/// without a calendar.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveDate;
+/// use weekdays::gtfs::{ServiceSchedule, read_calendar, read_calendar_dates};
+/// 
+/// let calendar = "service_id,monday,tuesday,wednesday,thursday,friday,saturday,sunday,start_date,end_date\n\
+///                 weekday,1,1,1,1,1,0,0,20240101,20241231\n\
+///                 weekend,0,0,0,0,0,1,1,20240101,20241231\n";
+/// let dates    = "service_id,date,exception_type\n\
+///                 weekday,20241225,2\n\
+///                 weekend,20241225,1\n";
+/// 
+/// let schedule = ServiceSchedule::new(
+///     read_calendar(calendar.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap(),
+///     read_calendar_dates(dates.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap(),
+/// ).unwrap();
+/// let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+/// 
+/// assert_eq!(schedule.active_services(christmas), vec!["weekend"]);
+/// ```
+/// 
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ServiceSchedule {
+	/// The calendars, keyed by service ID.
+	calendars:  BTreeMap<String, ServiceCalendar>,
+	
+	/// The exceptions, keyed by service ID and then date.
+	exceptions: BTreeMap<String, BTreeMap<NaiveDate, ExceptionType>>,
+}
+
+//󰭅		ServiceSchedule															
+impl ServiceSchedule {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`ServiceSchedule`] from calendars and exceptions.
+	/// 
+	/// The service ID is the primary key of `calendar.txt`, and the service ID
+	/// and date together are the primary key of `calendar_dates.txt`, so the
+	/// records are rejected if either key is repeated.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `calendars`  - The records from `calendar.txt`.
+	/// * `exceptions` - The records from `calendar_dates.txt`.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`GtfsError::DuplicateService`] if a service has more than one
+	/// calendar, or [`GtfsError::DuplicateDate`] if a service has more than one
+	/// exception for the same date.
+	/// 
+	pub fn new<C, D>(calendars: C, exceptions: D) -> Result<Self, GtfsError>
+		where
+			C: IntoIterator<Item = ServiceCalendar>,
+			D: IntoIterator<Item = CalendarDate>,
+	{
+		let mut schedule = Self::default();
+		for calendar in calendars {
+			match schedule.calendars.entry(calendar.service_id.clone()) {
+				Entry::Occupied(_)   => return Err(GtfsError::DuplicateService(calendar.service_id)),
+				Entry::Vacant(entry) => _ = entry.insert(calendar),
+			}
+		}
+		for exception in exceptions {
+			match schedule.exceptions.entry(exception.service_id.clone()).or_default().entry(exception.date) {
+				Entry::Occupied(_)   => return Err(GtfsError::DuplicateDate { service_id: exception.service_id, date: exception.date }),
+				Entry::Vacant(entry) => _ = entry.insert(exception.exception_type),
+			}
+		}
+		Ok(schedule)
+	}
+	
+	//		Public methods														
+	
+	//		active_services														
+	/// Returns the IDs of the services that run on the given date, in order.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	#[must_use]
+	pub fn active_services(&self, date: NaiveDate) -> Vec<&str> {
+		self.calendars.keys()
+			.chain(self.exceptions.keys())
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.filter(|service_id| self.is_active(service_id, date))
+			.map(String::as_str)
+			.collect()
+	}
+	
+	//		is_active															
+	/// Checks if the given service runs on the given date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `service_id` - The ID of the service.
+	/// * `date`       - The date to check.
+	/// 
+	#[must_use]
+	pub fn is_active(&self, service_id: &str, date: NaiveDate) -> bool {
+		match self.exceptions.get(service_id).and_then(|dates| dates.get(&date)) {
+			Some(&ExceptionType::Added)   => true,
+			Some(&ExceptionType::Removed) => false,
+			None                          => self.calendars.get(service_id).is_some_and(|calendar| calendar.is_active(date)),
+		}
+	}
+	
+	//		service_dates														
+	/// Returns every date on which the given service runs, in order.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `service_id` - The ID of the service.
+	/// 
+	#[must_use]
+	pub fn service_dates(&self, service_id: &str) -> Vec<NaiveDate> {
+		let scheduled = self.calendars.get(service_id).into_iter().flat_map(|calendar| {
+			calendar.start_date.iter_days().take_while(|&date| date <= calendar.end_date)
+		});
+		let added     = self.exceptions.get(service_id).into_iter().flat_map(BTreeMap::keys).copied();
+		scheduled
+			.chain(added)
+			.filter(|&date| self.is_active(service_id, date))
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.collect()
+	}
+}
+
+
+
+//		Functions
+
+//		read_calendar															
+/// Reads the records of `calendar.txt` from a CSV source.
+/// 
+/// The header row is read straight away, and the records are then read one at
+/// a time as the iterator is advanced. Columns can be in any order, and extra
+/// columns are ignored. Whitespace around values is trimmed.
+/// 
+/// # Parameters
+/// 
+/// * `source` - The CSV source to read from.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::MissingColumn`] if a required column is missing from
+/// the header row, or [`GtfsError::Csv`] if it cannot be read. Each record is
+/// also a [`Result`], which is an error if it cannot be read or is invalid.
+/// 
+pub fn read_calendar<R>(source: R) -> Result<impl Iterator<Item = Result<ServiceCalendar, GtfsError>>, GtfsError>
+	where R: Read,
+{
+	let mut reader = ReaderBuilder::new().trim(Trim::All).from_reader(source);
+	let columns    = find_columns(reader.headers()?, CALENDAR_COLUMNS)?;
+	Ok(reader.into_records().map(move |record| parse_calendar(&record?, &columns)))
+}
+
+//		read_calendar_dates														
+/// Reads the records of `calendar_dates.txt` from a CSV source.
+/// 
+/// This works in the same way as [`read_calendar()`].
+/// 
+/// # Parameters
+/// 
+/// * `source` - The CSV source to read from.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::MissingColumn`] if a required column is missing from
+/// the header row, or [`GtfsError::Csv`] if it cannot be read. Each record is
+/// also a [`Result`], which is an error if it cannot be read or is invalid.
+/// 
+pub fn read_calendar_dates<R>(source: R) -> Result<impl Iterator<Item = Result<CalendarDate, GtfsError>>, GtfsError>
+	where R: Read,
+{
+	let mut reader = ReaderBuilder::new().trim(Trim::All).from_reader(source);
+	let columns    = find_columns(reader.headers()?, CALENDAR_DATES_COLUMNS)?;
+	Ok(reader.into_records().map(move |record| parse_calendar_date(&record?, &columns)))
+}
+
+//		write_calendar															
+/// Writes records as `calendar.txt`, including the header row.
+/// 
+/// # Parameters
+/// 
+/// * `sink`      - The destination to write to.
+/// * `calendars` - The records to write.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::Csv`] if the records cannot be written.
+/// 
+pub fn write_calendar<'a, W, I>(sink: W, calendars: I) -> Result<(), GtfsError>
+	where
+		W: Write,
+		I: IntoIterator<Item = &'a ServiceCalendar>,
+{
+	let mut writer = Writer::from_writer(sink);
+	writer.write_record(CALENDAR_COLUMNS)?;
+	for calendar in calendars {
+		let flags = Weekdays::ALL_DAYS.iter().map(|day| if calendar.days.contains(day) { "1" } else { "0" });
+		writer.write_record(
+			iter::once(calendar.service_id.clone())
+				.chain(flags.map(str::to_owned))
+				.chain([calendar.start_date, calendar.end_date].map(|date| date.format(DATE_FORMAT).to_string())),
+		)?;
+	}
+	writer.flush().map_err(|error| GtfsError::Csv(error.to_string()))
+}
+
+//		write_calendar_dates													
+/// Writes records as `calendar_dates.txt`, including the header row.
+/// 
+/// # Parameters
+/// 
+/// * `sink`       - The destination to write to.
+/// * `exceptions` - The records to write.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::Csv`] if the records cannot be written.
+/// 
+pub fn write_calendar_dates<'a, W, I>(sink: W, exceptions: I) -> Result<(), GtfsError>
+	where
+		W: Write,
+		I: IntoIterator<Item = &'a CalendarDate>,
+{
+	let mut writer = Writer::from_writer(sink);
+	writer.write_record(CALENDAR_DATES_COLUMNS)?;
+	for exception in exceptions {
+		let kind = match exception.exception_type {
+			ExceptionType::Added   => "1",
+			ExceptionType::Removed => "2",
+		};
+		writer.write_record([exception.service_id.as_str(), &exception.date.format(DATE_FORMAT).to_string(), kind])?;
+	}
+	writer.flush().map_err(|error| GtfsError::Csv(error.to_string()))
+}
+
+//		fields																	
+/// Returns the values of the required columns of a record, paired with the
+/// names of the columns.
+/// 
+/// Missing values are given as empty strings.
+/// 
+/// # Parameters
+/// 
+/// * `record`  - The record to read.
+/// * `columns` - The positions of the columns.
+/// * `names`   - The names of the columns.
+/// 
+fn fields<'a>(record: &'a StringRecord, columns: &'a [usize], names: &'a [&'static str]) -> impl Iterator<Item = (&'static str, &'a str)> + 'a {
+	names.iter().zip(columns).map(|(&name, &column)| (name, record.get(column).unwrap_or_default()))
+}
+
+//		find_columns															
+/// Finds the position of each required column in the header row.
+/// 
+/// # Parameters
+/// 
+/// * `headers` - The header row.
+/// * `names`   - The names of the required columns.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::MissingColumn`] for the first column not found.
+/// 
+fn find_columns<const N: usize>(headers: &StringRecord, names: [&str; N]) -> Result<[usize; N], GtfsError> {
+	let mut columns = [0; N];
+	for (column, name) in columns.iter_mut().zip(names) {
+		*column = headers.iter().position(|header| header == name).ok_or_else(|| GtfsError::MissingColumn(name.to_owned()))?;
+	}
+	Ok(columns)
+}
+
+//		invalid																	
+/// Creates an error for an invalid value.
+/// 
+/// # Parameters
+/// 
+/// * `column` - The name of the column.
+/// * `value`  - The value that was found.
+/// 
+fn invalid(column: &str, value: &str) -> GtfsError {
+	GtfsError::InvalidValue { column: column.to_owned(), value: value.to_owned() }
+}
+
+//		parse_calendar															
+/// Parses a record from `calendar.txt`.
+/// 
+/// # Parameters
+/// 
+/// * `record`  - The record to parse.
+/// * `columns` - The positions of the columns, in the order of
+///   [`CALENDAR_COLUMNS`].
+/// 
+/// # Errors
+/// 
+/// Returns an error if any of the values are invalid.
+/// 
+fn parse_calendar(record: &StringRecord, columns: &[usize]) -> Result<ServiceCalendar, GtfsError> {
+	let mut fields          = fields(record, columns, &CALENDAR_COLUMNS);
+	let (_, service_id)     = fields.next().unwrap_or_default();
+	let days                = Weekdays::ALL_DAYS.iter().zip(fields.by_ref().take(7)).try_fold(Weekdays::NONE, |days, (day, (column, value))| {
+		match value {
+			"1" => Ok(days | day),
+			"0" => Ok(days),
+			_   => Err(invalid(column, value)),
+		}
+	})?;
+	let (start_date, end_date) = match (fields.next(), fields.next()) {
+		(Some((start_column, start)), Some((end_column, end))) => (parse_date(start_column, start)?, parse_date(end_column, end)?),
+		_                                                      => return Err(GtfsError::MissingColumn("end_date".to_owned())),
+	};
+	if service_id.is_empty() {
+		return Err(invalid("service_id", service_id));
+	}
+	ServiceCalendar::new(service_id, days, start_date, end_date).ok_or_else(|| GtfsError::InvalidDateRange(service_id.to_owned()))
+}
+
+//		parse_calendar_date														
+/// Parses a record from `calendar_dates.txt`.
+/// 
+/// # Parameters
+/// 
+/// * `record`  - The record to parse.
+/// * `columns` - The positions of the columns, in the order of
+///   [`CALENDAR_DATES_COLUMNS`].
+/// 
+/// # Errors
+/// 
+/// Returns an error if any of the values are invalid.
+/// 
+fn parse_calendar_date(record: &StringRecord, columns: &[usize]) -> Result<CalendarDate, GtfsError> {
+	let mut fields = fields(record, columns, &CALENDAR_DATES_COLUMNS);
+	match (fields.next(), fields.next(), fields.next()) {
+		(Some((_, "")), _, _)                                                    => Err(invalid("service_id", "")),
+		(Some((_, service_id)), Some((date_column, date)), Some((kind_column, kind))) => {
+			let exception_type = match kind {
+				"1" => ExceptionType::Added,
+				"2" => ExceptionType::Removed,
+				_   => return Err(invalid(kind_column, kind)),
+			};
+			Ok(CalendarDate::new(service_id, parse_date(date_column, date)?, exception_type))
+		}
+		_                                                                        => Err(GtfsError::MissingColumn("exception_type".to_owned())),
+	}
+}
+
+//		parse_date																
+/// Parses a date in the `YYYYMMDD` format.
+/// 
+/// # Parameters
+/// 
+/// * `column` - The name of the column, for errors.
+/// * `value`  - The value to parse.
+/// 
+/// # Errors
+/// 
+/// Returns [`GtfsError::InvalidValue`] if the date is not valid.
+/// 
+fn parse_date(column: &str, value: &str) -> Result<NaiveDate, GtfsError> {
+	(value.len() == 8 && value.bytes().all(|byte| byte.is_ascii_digit()))
+		.then(|| NaiveDate::parse_from_str(value, DATE_FORMAT).ok())
+		.flatten()
+		.ok_or_else(|| invalid(column, value))
+}
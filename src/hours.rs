@@ -0,0 +1,598 @@
+//! Availability in time slots across the week.
+//! 
+//! The main type provided is the [`WeekSlots`] struct, which is a bitmap of
+//! time slots for every day of the week. It is available in three widths:
+//! 
+//!   - [`WeekHours`]: 24 one-hour slots per day, 168 in total.
+//!   - [`WeekHalfHours`]: 48 half-hour slots per day, 336 in total.
+//!   - [`WeekQuarterHours`]: 96 quarter-hour slots per day, 672 in total.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/hours.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	fmt::Formatter,
+	fmt,
+	ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range, Sub, SubAssign},
+};
+
+#[cfg(feature = "postgres")]
+use ::{
+	bytes::BytesMut,
+	core::error::Error,
+	std::io::{Error as IoError, ErrorKind as IoErrorKind},
+	tokio_postgres::types::{FromSql, IsNull, ToSql, Type, to_sql_checked},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+
+
+
+//		Types
+
+/// Hourly slots across the week, 168 in total.
+pub type WeekHours        = WeekSlots<24>;
+
+/// Half-hourly slots across the week, 336 in total.
+pub type WeekHalfHours    = WeekSlots<48>;
+
+/// Quarter-hourly slots across the week, 672 in total.
+pub type WeekQuarterHours = WeekSlots<96>;
+
+
+
+//		Structs
+
+//		WeekSlots																
+/// A bitmap of time slots across the week.
+/// 
+/// Each day of the week is divided into `SLOTS` equal slots, starting from
+/// midnight, and each slot is either set or not. Following the conventions of
+/// [`Weekdays`], the bits are ordered from Monday to Sunday, and within each
+/// day from the first slot to the last, with the earliest slot being the most
+/// significant.
+/// 
+/// Only widths of 24, 48, and 96 slots per day are supported, and these are
+/// available as the [`WeekHours`], [`WeekHalfHours`], and [`WeekQuarterHours`]
+/// aliases. Other widths are rejected at compile time as soon as a bitmap is
+/// created, or any constant or method that depends on the width is used.
+/// 
+/// Days are given as [`Weekdays`] values. Methods that change slots apply to
+/// every day given, and methods that query slots require every day given to
+/// match.
+/// 
+/// When the `serde` feature is enabled, the bitmap is serialised as a string
+/// of hexadecimal digits, in the same order as the bits. When the `postgres`
+/// feature is enabled, it maps to a `BIT(n)` field, such as `BIT(168)` for
+/// [`WeekHours`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, hours::WeekHours};
+/// 
+/// let mut office = WeekHours::from_days(Weekdays::WEEKDAYS, 9..17);
+/// office.set_slot(Weekdays::SATURDAY, 10);
+/// 
+/// assert!( office.is_available_at(Weekdays::MONDAY,   9));
+/// assert!(!office.is_available_at(Weekdays::MONDAY,  17));
+/// assert!( office.is_available_at(Weekdays::SATURDAY, 10));
+/// assert_eq!(office.to_weekdays(), Weekdays::parse_const("mon-sat"));
+/// assert_eq!(office.count(),       41);
+/// ```
+/// 
+/// ```compile_fail
+/// use weekdays::hours::WeekSlots;
+/// 
+/// let broken = WeekSlots::<7>::new();
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WeekSlots<const SLOTS: u8> {
+	/// The slots for each day, from Monday to Sunday. The first slot of each
+	/// day is bit `SLOTS - 1`, and the last is bit zero.
+	days: [u128; 7],
+}
+
+//󰭅		WeekSlots																
+impl<const SLOTS: u8> WeekSlots<SLOTS> {
+	//		Public constants													
+	/// The number of slots in each day.
+	pub const SLOTS_PER_DAY: u8 = Self::WIDTH;
+	
+	/// The length of each slot, in minutes.
+	#[expect(clippy::integer_division, reason = "Supported widths divide the day exactly")]
+	pub const SLOT_MINUTES:  u16 = 1_440 / Self::WIDTH as u16;
+	
+	/// No slots set (empty).
+	pub const NONE:          Self = {
+		//	Any bitmap starts from here, so the width is checked here too
+		_ = Self::WIDTH;
+		Self { days: [0; 7] }
+	};
+	
+	/// All slots set.
+	pub const ALL:           Self = Self { days: [Self::DAY_MASK; 7] };
+	
+	//		Private constants													
+	/// The mask for all slots in a day.
+	const DAY_MASK:  u128 = u128::MAX >> (128 - Self::WIDTH as u32);
+	
+	/// The number of slots in each day, checked to be a supported width.
+	/// 
+	/// Everything that depends on the width goes through this constant rather
+	/// than `SLOTS`, so that using an unsupported width fails to compile.
+	const WIDTH:     u8 = {
+		assert!(SLOTS == 24 || SLOTS == 48 || SLOTS == 96, "Only 24, 48, or 96 slots per day are supported");
+		SLOTS
+	};
+	
+	/// The number of bytes used to encode the whole week.
+	#[expect(clippy::integer_division, reason = "Supported widths are whole bytes")]
+	const BYTES:     usize = Self::WIDTH as usize * 7 / 8;
+	
+	/// The number of bytes used to encode each day.
+	#[expect(clippy::integer_division, reason = "Supported widths are whole bytes")]
+	const DAY_BYTES: usize = Self::WIDTH as usize / 8;
+	
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new, empty bitmap.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self::NONE
+	}
+	
+	//		from_bytes															
+	/// Creates a bitmap from its big-endian byte encoding.
+	/// 
+	/// This is the inverse of [`to_bytes()`](Self::to_bytes()).
+	/// 
+	/// Returns [`None`] if the number of bytes is wrong.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bytes` - The encoded bitmap, with the same number of bits as there
+	///   are slots in the week.
+	/// 
+	#[must_use]
+	pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		if bytes.len() != Self::BYTES {
+			return None;
+		}
+		let mut slots = Self::NONE;
+		for (day, chunk) in slots.days.iter_mut().zip(bytes.chunks_exact(Self::DAY_BYTES)) {
+			*day = chunk.iter().fold(0, |bits, &byte| bits << 8_u8 | u128::from(byte));
+		}
+		Some(slots)
+	}
+	
+	//		from_days															
+	/// Creates a bitmap with the given range of hours set on the given days.
+	/// 
+	/// The range is half-open, so `9..17` sets the slots from 09:00 up to but
+	/// not including 17:00. The end of the range is capped at 24.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to set.
+	/// * `hours` - The hours to set.
+	/// 
+	#[must_use]
+	pub fn from_days(days: Weekdays, hours: Range<u8>) -> Self {
+		let mut slots = Self::NONE;
+		let per_hour  = Self::slots_per_hour();
+		let start     = hours.start.min(24).saturating_mul(per_hour);
+		let end       = hours.end.min(24).saturating_mul(per_hour);
+		for slot in start..end {
+			slots.set_slot(days, slot);
+		}
+		slots
+	}
+	
+	//		Public methods														
+	
+	//		clear_slot															
+	/// Clears a slot on the given days.
+	/// 
+	/// Slots beyond the end of the day are ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to change.
+	/// * `slot` - The slot to clear, from zero for the first slot of the day.
+	/// 
+	pub fn clear_slot(&mut self, days: Weekdays, slot: u8) {
+		let bit = Self::slot_bit(slot);
+		for (_, bits) in self.days_mut(days) {
+			*bits &= !bit;
+		}
+	}
+	
+	//		contains_slot														
+	/// Checks if a slot is set on all of the given days.
+	/// 
+	/// If no days are given, the result is `false`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to check.
+	/// * `slot` - The slot to check, from zero for the first slot of the day.
+	/// 
+	#[must_use]
+	pub fn contains_slot(&self, days: Weekdays, slot: u8) -> bool {
+		let bit = Self::slot_bit(slot);
+		!days.is_empty() && bit != 0 && self.day_bits(days).all(|bits| bits & bit != 0)
+	}
+	
+	//		count																
+	/// Returns the number of slots set across the whole week.
+	#[must_use]
+	pub fn count(&self) -> u16 {
+		self.days.iter().map(|bits| bits.count_ones()).sum::<u32>().try_into().unwrap_or(u16::MAX)
+	}
+	
+	//		is_available_at														
+	/// Checks if the slot starting at the given hour is set on all of the given
+	/// days.
+	/// 
+	/// For widths narrower than an hour, only the first slot of the hour is
+	/// checked. If no days are given, or the hour is not between 0 and 23, the
+	/// result is `false`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to check.
+	/// * `hour` - The hour to check, from 0 to 23.
+	/// 
+	#[must_use]
+	pub fn is_available_at(&self, days: Weekdays, hour: u8) -> bool {
+		hour < 24 && self.contains_slot(days, hour.saturating_mul(Self::slots_per_hour()))
+	}
+	
+	//		is_empty															
+	/// Checks if no slots are set.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.days.iter().all(|&bits| bits == 0)
+	}
+	
+	//		iter																
+	/// Returns an iterator over the slots that are set.
+	/// 
+	/// Each item is a pair of the day and the slot number within that day. The
+	/// slots are yielded in order, from the first slot on Monday to the last
+	/// slot on Sunday.
+	/// 
+	pub fn iter(&self) -> impl Iterator<Item = (Weekdays, u8)> + '_ {
+		Weekdays::ALL_DAYS.iter().zip(self.days.iter()).flat_map(|(day, &bits)| {
+			(0..Self::WIDTH).filter(move |&slot| bits & Self::slot_bit(slot) != 0).map(move |slot| (day, slot))
+		})
+	}
+	
+	//		set_slot															
+	/// Sets a slot on the given days.
+	/// 
+	/// Slots beyond the end of the day are ignored.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to change.
+	/// * `slot` - The slot to set, from zero for the first slot of the day.
+	/// 
+	pub fn set_slot(&mut self, days: Weekdays, slot: u8) {
+		let bit = Self::slot_bit(slot);
+		for (_, bits) in self.days_mut(days) {
+			*bits |= bit;
+		}
+	}
+	
+	//		to_bytes															
+	/// Encodes the bitmap as big-endian bytes.
+	/// 
+	/// The bits are in the same order as described for [`WeekSlots`], so the
+	/// most significant bit of the first byte is the first slot on Monday.
+	/// 
+	#[must_use]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		self.days
+			.iter()
+			.flat_map(|bits| bits.to_be_bytes().into_iter().skip(16_usize.saturating_sub(Self::DAY_BYTES)))
+			.collect()
+	}
+	
+	//		to_weekdays															
+	/// Projects the bitmap down to the days that have any slot set.
+	#[must_use]
+	pub fn to_weekdays(&self) -> Weekdays {
+		Weekdays::ALL_DAYS
+			.iter()
+			.zip(self.days.iter())
+			.filter(|&(_, &bits)| bits != 0)
+			.fold(Weekdays::NONE, |days, (day, _)| days | day)
+	}
+	
+	//		Private methods														
+	
+	//		day_bits															
+	/// Returns an iterator over the slot bits for the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to return.
+	/// 
+	fn day_bits(&self, days: Weekdays) -> impl Iterator<Item = u128> + '_ {
+		Weekdays::ALL_DAYS
+			.iter()
+			.zip(self.days.iter())
+			.filter(move |&(day, _)| days.contains(day))
+			.map(|(_, &bits)| bits)
+	}
+	
+	//		days_mut															
+	/// Returns an iterator over mutable slot bits for the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to return.
+	/// 
+	fn days_mut(&mut self, days: Weekdays) -> impl Iterator<Item = (Weekdays, &mut u128)> {
+		Weekdays::ALL_DAYS.iter().zip(self.days.iter_mut()).filter(move |&(day, _)| days.contains(day))
+	}
+	
+	//		slot_bit															
+	/// Returns the bit for the given slot, or zero if it is out of range.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `slot` - The slot, from zero for the first slot of the day.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Slot is checked to be in range")]
+	const fn slot_bit(slot: u8) -> u128 {
+		if slot < Self::WIDTH {
+			1 << (Self::WIDTH - 1 - slot)
+		} else {
+			0
+		}
+	}
+	
+	//		slots_per_hour														
+	/// Returns the number of slots in each hour.
+	#[expect(clippy::integer_division, reason = "Supported widths divide the day exactly")]
+	const fn slots_per_hour() -> u8 {
+		Self::WIDTH / 24
+	}
+}
+
+//󰭅		Add																		
+impl<const SLOTS: u8> Add for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		add																	
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self.bitor_assign(rhs);
+		self
+	}
+}
+
+//󰭅		AddAssign																
+impl<const SLOTS: u8> AddAssign for WeekSlots<SLOTS> {
+	//		add_assign															
+	fn add_assign(&mut self, rhs: Self) {
+		self.bitor_assign(rhs);
+	}
+}
+
+//󰭅		BitAnd																	
+impl<const SLOTS: u8> BitAnd for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		bitand																
+	fn bitand(mut self, rhs: Self) -> Self::Output {
+		self &= rhs;
+		self
+	}
+}
+
+//󰭅		BitAndAssign															
+impl<const SLOTS: u8> BitAndAssign for WeekSlots<SLOTS> {
+	//		bitand_assign														
+	fn bitand_assign(&mut self, rhs: Self) {
+		for (bits, other) in self.days.iter_mut().zip(rhs.days) {
+			*bits &= other;
+		}
+	}
+}
+
+//󰭅		BitOr																	
+impl<const SLOTS: u8> BitOr for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		bitor																
+	fn bitor(mut self, rhs: Self) -> Self::Output {
+		self |= rhs;
+		self
+	}
+}
+
+//󰭅		BitOrAssign																
+impl<const SLOTS: u8> BitOrAssign for WeekSlots<SLOTS> {
+	//		bitor_assign														
+	fn bitor_assign(&mut self, rhs: Self) {
+		for (bits, other) in self.days.iter_mut().zip(rhs.days) {
+			*bits |= other;
+		}
+	}
+}
+
+//󰭅		BitXor																	
+impl<const SLOTS: u8> BitXor for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		bitxor																
+	fn bitxor(mut self, rhs: Self) -> Self::Output {
+		self ^= rhs;
+		self
+	}
+}
+
+//󰭅		BitXorAssign															
+impl<const SLOTS: u8> BitXorAssign for WeekSlots<SLOTS> {
+	//		bitxor_assign														
+	fn bitxor_assign(&mut self, rhs: Self) {
+		for (bits, other) in self.days.iter_mut().zip(rhs.days) {
+			*bits ^= other;
+		}
+	}
+}
+
+//󰭅		Default																	
+impl<const SLOTS: u8> Default for WeekSlots<SLOTS> {
+	//		default																
+	fn default() -> Self {
+		Self::NONE
+	}
+}
+
+//󰭅		Deserialize																
+#[cfg(feature = "serde")]
+impl<'de, const SLOTS: u8> Deserialize<'de> for WeekSlots<SLOTS> {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		let hex    = String::deserialize(deserializer)?;
+		if hex.len() != Self::BYTES.saturating_mul(2) {
+			return Err(DeError::invalid_length(hex.len(), &"two hex digits per byte of slots"));
+		}
+		let digits = hex
+			.chars()
+			.map(|digit| digit.to_digit(16).and_then(|nibble| u8::try_from(nibble).ok()))
+			.collect::<Option<Vec<_>>>()
+			.ok_or_else(|| DeError::custom("invalid hexadecimal slots"))?;
+		let bytes  = digits
+			.chunks_exact(2)
+			.map(|pair| pair.iter().fold(0, |byte, &nibble| byte << 4_u8 | nibble))
+			.collect::<Vec<u8>>();
+		Self::from_bytes(&bytes).ok_or_else(|| DeError::custom("invalid hexadecimal slots"))
+	}
+}
+
+//󰭅		From: WeekSlots -> Weekdays												
+impl<const SLOTS: u8> From<WeekSlots<SLOTS>> for Weekdays {
+	//		from																
+	fn from(slots: WeekSlots<SLOTS>) -> Self {
+		slots.to_weekdays()
+	}
+}
+
+//󰭅		FromSql																	
+#[cfg(feature = "postgres")]
+impl<const SLOTS: u8> FromSql<'_> for WeekSlots<SLOTS> {
+	//		from_sql															
+	fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		match ty {
+			&Type::BIT => Self::from_bytes(raw).ok_or_else(|| Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid length for WeekSlots: {}", raw.len()),
+			)).into()),
+			unknown    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for WeekSlots: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+}
+
+//󰭅		LowerHex																
+impl<const SLOTS: u8> fmt::LowerHex for WeekSlots<SLOTS> {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		self.to_bytes().iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+	}
+}
+
+//󰭅		Not																		
+impl<const SLOTS: u8> Not for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		not																	
+	fn not(mut self) -> Self::Output {
+		for bits in &mut self.days {
+			*bits = !*bits & Self::DAY_MASK;
+		}
+		self
+	}
+}
+
+//󰭅		Serialize																
+#[cfg(feature = "serde")]
+impl<const SLOTS: u8> Serialize for WeekSlots<SLOTS> {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&format!("{self:x}"))
+	}
+}
+
+//󰭅		Sub																		
+impl<const SLOTS: u8> Sub for WeekSlots<SLOTS> {
+	type Output = Self;
+	
+	//		sub																	
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self.sub_assign(rhs);
+		self
+	}
+}
+
+//󰭅		SubAssign																
+impl<const SLOTS: u8> SubAssign for WeekSlots<SLOTS> {
+	//		sub_assign															
+	fn sub_assign(&mut self, rhs: Self) {
+		for (bits, other) in self.days.iter_mut().zip(rhs.days) {
+			*bits &= !other;
+		}
+	}
+}
+
+//󰭅		ToSql																	
+#[cfg(feature = "postgres")]
+impl<const SLOTS: u8> ToSql for WeekSlots<SLOTS> {
+	//		to_sql																
+	fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		match ty {
+			&Type::BIT => {
+				//	PostgreSQL expects the bits in the same order
+				out.extend_from_slice(&self.to_bytes());
+				Ok(IsNull::No)
+			}
+			unknown    => Err(Box::new(IoError::new(
+				IoErrorKind::InvalidData,
+				format!("Invalid type for WeekSlots: {unknown}"),
+			))),
+		}
+	}
+	
+	//		accepts																
+	fn accepts(ty: &Type) -> bool {
+		ty.name() == "bit"
+	}
+	
+	to_sql_checked!();
+}
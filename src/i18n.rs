@@ -0,0 +1,776 @@
+//! Localised day names for formatting and parsing.
+//! 
+//! Day names vary by language, and also by width and by context. The
+//! [`DayNames`] struct formats and parses [`Weekdays`] for a given [`Locale`],
+//! using day names embedded from the [Unicode CLDR](https://cldr.unicode.org/)
+//! data:
+//! 
+//!   - [`NameWidth`] chooses between wide names, such as "lundi",
+//!     abbreviated names, such as "lun.", and narrow names, such as "L".
+//!   - [`NameContext`] chooses between names used within a sentence or date,
+//!     and stand-alone names used on their own, such as in calendar headings.
+//!     Some languages, such as German and Polish, write these differently.
+//! 
+//! Days are written in week order, starting from the first day of the week
+//! used by the locale, so that an American English list starts on Sunday while
+//! a French list starts on Monday.
+//! 
+//! The first day of the week depends on the region of the [`Locale`], following
+//! CLDR, so that `en-GB` starts on Monday while `fr-CA` starts on Sunday. Where
+//! no region is given, the region where the language is most widely spoken is
+//! used, so English uses the United States, Portuguese uses Brazil, and Chinese
+//! uses mainland China.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/i18n.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	str::{FromStr, self},
+};
+
+
+
+//		Constants
+
+/// Chinese day names.
+const CHINESE: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"],
+	abbreviated: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+	narrow:      ["一", "二", "三", "四", "五", "六", "日"],
+});
+
+/// Dutch day names.
+const DUTCH: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"],
+	abbreviated: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+	narrow:      ["M", "D", "W", "D", "V", "Z", "Z"],
+});
+
+/// English day names.
+const ENGLISH: LocaleData = LocaleData::same(Weekdays::SUNDAY, &NameSet {
+	wide:        ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+	abbreviated: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+	narrow:      ["M", "T", "W", "T", "F", "S", "S"],
+});
+
+/// French day names.
+const FRENCH: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+	abbreviated: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+	narrow:      ["L", "M", "M", "J", "V", "S", "D"],
+});
+
+/// German day names.
+const GERMAN: LocaleData = LocaleData {
+	first_day:   Weekdays::MONDAY,
+	format:      NameSet {
+		wide:        ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"],
+		abbreviated: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+		narrow:      ["M", "D", "M", "D", "F", "S", "S"],
+	},
+	stand_alone: NameSet {
+		wide:        ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"],
+		abbreviated: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+		narrow:      ["M", "D", "M", "D", "F", "S", "S"],
+	},
+};
+
+/// Italian day names.
+const ITALIAN: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+	abbreviated: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+	narrow:      ["L", "M", "M", "G", "V", "S", "D"],
+});
+
+/// Japanese day names.
+const JAPANESE: LocaleData = LocaleData::same(Weekdays::SUNDAY, &NameSet {
+	wide:        ["月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日", "日曜日"],
+	abbreviated: ["月", "火", "水", "木", "金", "土", "日"],
+	narrow:      ["月", "火", "水", "木", "金", "土", "日"],
+});
+
+/// Korean day names.
+const KOREAN: LocaleData = LocaleData::same(Weekdays::SUNDAY, &NameSet {
+	wide:        ["월요일", "화요일", "수요일", "목요일", "금요일", "토요일", "일요일"],
+	abbreviated: ["월", "화", "수", "목", "금", "토", "일"],
+	narrow:      ["월", "화", "수", "목", "금", "토", "일"],
+});
+
+/// Polish day names.
+const POLISH: LocaleData = LocaleData {
+	first_day:   Weekdays::MONDAY,
+	format:      NameSet {
+		wide:        ["poniedziałek", "wtorek", "środa", "czwartek", "piątek", "sobota", "niedziela"],
+		abbreviated: ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."],
+		narrow:      ["p", "w", "ś", "c", "p", "s", "n"],
+	},
+	stand_alone: NameSet {
+		wide:        ["poniedziałek", "wtorek", "środa", "czwartek", "piątek", "sobota", "niedziela"],
+		abbreviated: ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."],
+		narrow:      ["P", "W", "Ś", "C", "P", "S", "N"],
+	},
+};
+
+/// Portuguese day names.
+const PORTUGUESE: LocaleData = LocaleData::same(Weekdays::SUNDAY, &NameSet {
+	wide:        ["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"],
+	abbreviated: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+	narrow:      ["S", "T", "Q", "Q", "S", "S", "D"],
+});
+
+/// The regions whose weeks do not start on Monday, with the day they start
+/// on, from the CLDR week data. Weeks in all other regions start on Monday.
+const REGION_FIRST_DAYS: [(Weekdays, &[&str]); 3] = [
+	(Weekdays::FRIDAY,   &["MV"]),
+	(Weekdays::SATURDAY, &["AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY"]),
+	(Weekdays::SUNDAY,   &[
+		"AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CO", "DM", "DO", "ET", "GT", "GU", "HK", "HN", "ID",
+		"IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX", "MZ", "NI", "NP", "PA", "PE",
+		"PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA",
+		"ZW",
+	]),
+];
+
+/// Russian day names.
+const RUSSIAN: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["понедельник", "вторник", "среда", "четверг", "пятница", "суббота", "воскресенье"],
+	abbreviated: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+	narrow:      ["П", "В", "С", "Ч", "П", "С", "В"],
+});
+
+/// The characters that separate day names when parsing, in addition to
+/// whitespace.
+const SEPARATORS: [char; 3] = [',', '、', '，'];
+
+/// Spanish day names.
+const SPANISH: LocaleData = LocaleData::same(Weekdays::MONDAY, &NameSet {
+	wide:        ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+	abbreviated: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+	narrow:      ["L", "M", "X", "J", "V", "S", "D"],
+});
+
+
+
+//		Enums
+
+//		I18nError																
+/// The errors that can occur when working with localised day names.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum I18nError {
+	/// The name could refer to more than one day, such as the narrow name
+	/// "M" for Tuesday and Wednesday in French.
+	AmbiguousDay(String),
+	
+	/// The name was not a day name in the locale.
+	UnknownDay(String),
+	
+	/// The locale tag did not refer to a supported language.
+	UnknownLocale(String),
+}
+
+//󰭅		Display																	
+impl Display for I18nError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::AmbiguousDay(ref name)  => write!(f, "Ambiguous day name: {name}"),
+			Self::UnknownDay(ref name)    => write!(f, "Unknown day name: {name}"),
+			Self::UnknownLocale(ref tag)  => write!(f, "Unknown locale: {tag}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for I18nError {}
+
+//		Language																
+/// The supported languages.
+/// 
+/// Each language has its own day names, and a first day of the week that is
+/// used by a [`Locale`] without a region. It is written as the language code
+/// by [`Display`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Language {
+	/// Chinese (`zh`), with weeks starting on Monday.
+	Chinese,
+	
+	/// Dutch (`nl`), with weeks starting on Monday.
+	Dutch,
+	
+	/// English (`en`), with weeks starting on Sunday.
+	English,
+	
+	/// French (`fr`), with weeks starting on Monday.
+	French,
+	
+	/// German (`de`), with weeks starting on Monday.
+	German,
+	
+	/// Italian (`it`), with weeks starting on Monday.
+	Italian,
+	
+	/// Japanese (`ja`), with weeks starting on Sunday.
+	Japanese,
+	
+	/// Korean (`ko`), with weeks starting on Sunday.
+	Korean,
+	
+	/// Polish (`pl`), with weeks starting on Monday.
+	Polish,
+	
+	/// Portuguese (`pt`), with weeks starting on Sunday.
+	Portuguese,
+	
+	/// Russian (`ru`), with weeks starting on Monday.
+	Russian,
+	
+	/// Spanish (`es`), with weeks starting on Monday.
+	Spanish,
+}
+
+//󰭅		Language																
+impl Language {
+	//		Public constants													
+	
+	/// All supported languages.
+	pub const ALL: [Self; 12] = [
+		Self::Chinese,
+		Self::Dutch,
+		Self::English,
+		Self::French,
+		Self::German,
+		Self::Italian,
+		Self::Japanese,
+		Self::Korean,
+		Self::Polish,
+		Self::Portuguese,
+		Self::Russian,
+		Self::Spanish,
+	];
+	
+	//		Public methods														
+	
+	//		tag																	
+	/// Returns the language code, such as `fr`.
+	#[must_use]
+	pub const fn tag(self) -> &'static str {
+		match self {
+			Self::Chinese    => "zh",
+			Self::Dutch      => "nl",
+			Self::English    => "en",
+			Self::French     => "fr",
+			Self::German     => "de",
+			Self::Italian    => "it",
+			Self::Japanese   => "ja",
+			Self::Korean     => "ko",
+			Self::Polish     => "pl",
+			Self::Portuguese => "pt",
+			Self::Russian    => "ru",
+			Self::Spanish    => "es",
+		}
+	}
+	
+	//		Private methods														
+	
+	//		data																
+	/// Returns the day names and week data for the language.
+	const fn data(self) -> &'static LocaleData {
+		match self {
+			Self::Chinese    => &CHINESE,
+			Self::Dutch      => &DUTCH,
+			Self::English    => &ENGLISH,
+			Self::French     => &FRENCH,
+			Self::German     => &GERMAN,
+			Self::Italian    => &ITALIAN,
+			Self::Japanese   => &JAPANESE,
+			Self::Korean     => &KOREAN,
+			Self::Polish     => &POLISH,
+			Self::Portuguese => &PORTUGUESE,
+			Self::Russian    => &RUSSIAN,
+			Self::Spanish    => &SPANISH,
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for Language {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.tag())
+	}
+}
+
+//		NameContext																
+/// The contexts in which day names are used.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum NameContext {
+	/// Names used within a sentence or a formatted date.
+	#[default]
+	Format,
+	
+	/// Names used on their own, such as in calendar headings.
+	StandAlone,
+}
+
+//		NameWidth																
+/// The widths of day names.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum NameWidth {
+	/// Full names, such as "Monday".
+	Wide,
+	
+	/// Abbreviated names, such as "Mon".
+	#[default]
+	Abbreviated,
+	
+	/// Narrow names, such as "M", which are not always unique.
+	Narrow,
+}
+
+
+
+//		Structs
+
+//		DayNames																
+/// Formats and parses sets of days using localised day names.
+/// 
+/// Names are abbreviated and in the format context unless configured
+/// otherwise. Parsing accepts names of any width and in either context, so
+/// that anything written by [`format()`](Self::format()) can be read back,
+/// except for narrow names that could refer to more than one day.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, i18n::{DayNames, Language, NameWidth}};
+/// 
+/// let days   = Weekdays::parse_const("mon, tue");
+/// let french = DayNames::new(Language::French.into());
+/// assert_eq!(french.format(days), "lun., mar.");
+/// assert_eq!(french.parse("lundi, Mar."), Ok(days));
+/// 
+/// let japanese = DayNames::new(Language::Japanese.into());
+/// assert_eq!(japanese.format(days),                                "月, 火");
+/// assert_eq!(japanese.with_width(NameWidth::Wide).format(days),    "月曜日, 火曜日");
+/// 
+/// let english = DayNames::new(Language::English.into());
+/// let british = DayNames::new("en-GB".parse().unwrap());
+/// assert_eq!(english.format(Weekdays::parse_const("mon, sun")),   "Sun, Mon");
+/// assert_eq!(british.format(Weekdays::parse_const("mon, sun")),   "Mon, Sun");
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DayNames {
+	/// The context in which names are written.
+	context: NameContext,
+	
+	/// The locale of the names.
+	locale:  Locale,
+	
+	/// The width of the names written.
+	width:   NameWidth,
+}
+
+//󰭅		DayNames																
+impl DayNames {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`DayNames`] for the given locale, using abbreviated
+	/// names in the format context.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `locale` - The locale of the names.
+	/// 
+	#[must_use]
+	pub const fn new(locale: Locale) -> Self {
+		Self { context: NameContext::Format, locale, width: NameWidth::Abbreviated }
+	}
+	
+	//		with_context														
+	/// Sets the context in which names are written.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `context` - The context.
+	/// 
+	#[must_use]
+	pub const fn with_context(mut self, context: NameContext) -> Self {
+		self.context = context;
+		self
+	}
+	
+	//		with_width															
+	/// Sets the width of the names written.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `width` - The width.
+	/// 
+	#[must_use]
+	pub const fn with_width(mut self, width: NameWidth) -> Self {
+		self.width = width;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		format																
+	/// Formats a set of days as a list of day names.
+	/// 
+	/// Days are written in the order used by the locale, starting from its
+	/// first day of the week, and separated by commas. If no days are set, the
+	/// result is empty.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to format.
+	/// 
+	#[must_use]
+	pub fn format(&self, days: Weekdays) -> String {
+		self.locale.week_order()
+			.into_iter()
+			.filter(|&day| days.contains(day))
+			.filter_map(|day| self.name(day))
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+	
+	//		name																
+	/// Returns the name of a single day.
+	/// 
+	/// If the given value contains more than one day, the earliest day in the
+	/// week is used, in the same way as [`Weekdays::range()`]. If no days are
+	/// set, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to name.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, i18n::{DayNames, Language, NameContext}};
+	/// 
+	/// let german = DayNames::new(Language::German.into());
+	/// assert_eq!(german.name(Weekdays::MONDAY),                                        Some("Mo."));
+	/// assert_eq!(german.with_context(NameContext::StandAlone).name(Weekdays::MONDAY), Some("Mo"));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn name(&self, day: Weekdays) -> Option<&'static str> {
+		let index = Weekdays::ALL_DAYS.iter().position(|candidate| day.contains(candidate))?;
+		self.locale.language.data().names(self.context, self.width).get(index).copied()
+	}
+	
+	//		parse																
+	/// Parses a list of day names into a set of days.
+	/// 
+	/// Names can be given in any width and in either context, and are matched
+	/// without regard to case, or to a trailing full stop. They can be
+	/// separated by commas, including the ideographic commas used in Chinese
+	/// and Japanese, or by whitespace. An empty list gives
+	/// [`NONE`](Weekdays::NONE).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `text` - The list of day names.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`I18nError::UnknownDay`] if a name is not a day name in the
+	/// locale, or [`I18nError::AmbiguousDay`] if it could refer to more than
+	/// one day.
+	/// 
+	pub fn parse(&self, text: &str) -> Result<Weekdays, I18nError> {
+		text.split(|character: char| character.is_whitespace() || SEPARATORS.contains(&character))
+			.filter(|name| !name.is_empty())
+			.try_fold(Weekdays::NONE, |days, name| Ok(days | self.parse_name(name)?))
+	}
+	
+	//		Private methods														
+	
+	//		parse_name															
+	/// Parses a single day name.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The day name.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the name is unknown or ambiguous.
+	/// 
+	fn parse_name(self, name: &str) -> Result<Weekdays, I18nError> {
+		let wanted  = normalise(name);
+		let data    = self.locale.language.data();
+		let matches = [NameContext::Format, NameContext::StandAlone]
+			.into_iter()
+			.flat_map(|context| [NameWidth::Wide, NameWidth::Abbreviated, NameWidth::Narrow].map(|width| data.names(context, width)))
+			.flat_map(|names| Weekdays::ALL_DAYS.iter().zip(names))
+			.filter(|&(_, candidate)| normalise(candidate) == wanted)
+			.fold(Weekdays::NONE, |days, (day, _)| days | day);
+		match matches.days() {
+			0 => Err(I18nError::UnknownDay(name.to_owned())),
+			1 => Ok(matches),
+			_ => Err(I18nError::AmbiguousDay(name.to_owned())),
+		}
+	}
+}
+
+//		Locale																	
+/// A locale, made up of a language and an optional region.
+/// 
+/// The language gives the day names, and the region gives the first day of the
+/// week, following CLDR. Without a region, the first day of the week is the
+/// default for the language.
+/// 
+/// Locales can be parsed from BCP 47 or POSIX tags, such as `fr`, `fr-CA`,
+/// `zh-Hant-TW`, or `fr_FR.UTF-8`. Any script, encoding, variant, or extension
+/// is ignored, as are numeric regions such as `419`. Locales are written as
+/// the language code followed by any region, such as `fr-CA`, by [`Display`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, i18n::{Language, Locale}};
+/// 
+/// let locale: Locale = "en_GB.UTF-8".parse().unwrap();
+/// assert_eq!(locale.language(),  Language::English);
+/// assert_eq!(locale.region(),    Some("GB"));
+/// assert_eq!(locale.to_string(), "en-GB");
+/// assert_eq!(locale.first_day(), Weekdays::MONDAY);
+/// 
+/// assert_eq!(Locale::new(Language::English).first_day(), Weekdays::SUNDAY);
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Locale {
+	/// The language.
+	language: Language,
+	
+	/// The region, as an uppercase two-letter code.
+	region:   Option<[u8; 2]>,
+}
+
+//󰭅		Locale																	
+impl Locale {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Locale`] for the given language, without a region.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `language` - The language.
+	/// 
+	#[must_use]
+	pub const fn new(language: Language) -> Self {
+		Self { language, region: None }
+	}
+	
+	//		Public methods														
+	
+	//		first_day															
+	/// Returns the first day of the week used by the locale.
+	/// 
+	/// This is the first day used in the region, or the default for the
+	/// language if there is no region.
+	#[must_use]
+	pub fn first_day(self) -> Weekdays {
+		self.region().map_or_else(|| self.language.data().first_day, |region| {
+			REGION_FIRST_DAYS
+				.iter()
+				.find(|&&(_, regions)| regions.contains(&region))
+				.map_or(Weekdays::MONDAY, |&(day, _)| day)
+		})
+	}
+	
+	//		language															
+	/// Returns the language of the locale.
+	#[must_use]
+	pub const fn language(self) -> Language {
+		self.language
+	}
+	
+	//		region																
+	/// Returns the region of the locale, such as `CA`, if there is one.
+	#[must_use]
+	pub fn region(&self) -> Option<&str> {
+		self.region.as_ref().and_then(|code| str::from_utf8(code).ok())
+	}
+	
+	//		week_order															
+	/// Returns the days of the week in the order used by the locale, starting
+	/// from its first day.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, i18n::{Language, Locale}};
+	/// 
+	/// let english: Locale = Language::English.into();
+	/// let british: Locale = "en-GB".parse().unwrap();
+	/// assert_eq!(english.week_order()[..2], [Weekdays::SUNDAY, Weekdays::MONDAY]);
+	/// assert_eq!(british.week_order()[..2], [Weekdays::MONDAY, Weekdays::TUESDAY]);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn week_order(self) -> [Weekdays; 7] {
+		let mut days = [Weekdays::NONE; 7];
+		for (slot, day) in days.iter_mut().zip(Weekdays::ALL_DAYS.iter()) {
+			*slot = day;
+		}
+		let first    = days.iter().position(|&day| day == self.first_day()).unwrap_or(0);
+		days.rotate_left(first);
+		days
+	}
+}
+
+//󰭅		Display																	
+impl Display for Locale {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self.region() {
+			Some(region) => write!(f, "{}-{region}", self.language),
+			None         => write!(f, "{}", self.language),
+		}
+	}
+}
+
+//󰭅		From<Language>															
+impl From<Language> for Locale {
+	//		from																
+	fn from(language: Language) -> Self {
+		Self::new(language)
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for Locale {
+	type Err = I18nError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let tag         = s.split(['.', '@']).next().unwrap_or_default();
+		let mut subtags = tag.split(['-', '_']);
+		let code        = subtags.next().unwrap_or_default();
+		let language    = Language::ALL
+			.into_iter()
+			.find(|language| language.tag().eq_ignore_ascii_case(code))
+			.ok_or_else(|| I18nError::UnknownLocale(s.to_owned()))?;
+		//	The region follows any script, and comes before any single-letter
+		//	subtag that starts an extension
+		let region      = subtags
+			.take_while(|subtag| subtag.len() > 1)
+			.find_map(|subtag| match *subtag.as_bytes() {
+				[first, second] if first.is_ascii_alphabetic() && second.is_ascii_alphabetic() => {
+					Some([first.to_ascii_uppercase(), second.to_ascii_uppercase()])
+				},
+				_ => None,
+			});
+		Ok(Self { language, region })
+	}
+}
+
+//		LocaleData																
+/// The day names and week data for a locale.
+struct LocaleData {
+	/// The first day of the week.
+	first_day:   Weekdays,
+	
+	/// The names used in the format context.
+	format:      NameSet,
+	
+	/// The names used in the stand-alone context.
+	stand_alone: NameSet,
+}
+
+//󰭅		LocaleData																
+impl LocaleData {
+	//		Constructors														
+	
+	//		same																
+	/// Creates locale data where the format and stand-alone names are the
+	/// same.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `first_day` - The first day of the week.
+	/// * `names`     - The day names.
+	/// 
+	const fn same(first_day: Weekdays, names: &NameSet) -> Self {
+		Self { first_day, format: *names, stand_alone: *names }
+	}
+	
+	//		Public methods														
+	
+	//		names																
+	/// Returns the day names for the given context and width, from Monday to
+	/// Sunday.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `context` - The context.
+	/// * `width`   - The width.
+	/// 
+	const fn names(&self, context: NameContext, width: NameWidth) -> [&'static str; 7] {
+		let set = match context {
+			NameContext::Format     => &self.format,
+			NameContext::StandAlone => &self.stand_alone,
+		};
+		match width {
+			NameWidth::Wide        => set.wide,
+			NameWidth::Abbreviated => set.abbreviated,
+			NameWidth::Narrow      => set.narrow,
+		}
+	}
+}
+
+//		NameSet																	
+/// The day names of each width for a single context, from Monday to Sunday.
+#[derive(Clone, Copy)]
+struct NameSet {
+	/// The wide names.
+	wide:        [&'static str; 7],
+	
+	/// The abbreviated names.
+	abbreviated: [&'static str; 7],
+	
+	/// The narrow names.
+	narrow:      [&'static str; 7],
+}
+
+
+
+//		Functions
+
+//		normalise																
+/// Normalises a day name for comparison, by converting it to lowercase and
+/// removing any trailing full stop.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The day name.
+/// 
+fn normalise(name: &str) -> String {
+	name.strip_suffix('.').unwrap_or(name).to_lowercase()
+}
@@ -0,0 +1,610 @@
+//! Generation of iCalendar documents from weekday schedules.
+//! 
+//! The main type provided is the [`IcsEvent`] struct, which describes an event
+//! that repeats on a set of [`Weekdays`] at a fixed time. One or more events
+//! can be written as an `.ics` document with [`write_calendar()`], ready for
+//! calendar apps to import or subscribe to.
+//! 
+//! The output follows [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545): lines
+//! end with CRLF and are folded at 75 octets, text values are escaped, and each
+//! event has a `DTSTART`, `DURATION`, and `RRULE`, along with an `EXDATE` for
+//! any dates that are skipped. Times can be floating, in UTC, or in a named
+//! [`IcsTimeZone`], whose `VTIMEZONE` component is written once before the
+//! events. The output depends only on the events given, so it is suitable for
+//! snapshot testing.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/ics.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{Weekdays, rrule::WeeklyRule};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use core::slice;
+
+
+
+//		Constants
+
+/// The product identifier written to each document.
+const PRODUCT_ID: &str = "-//weekdays//NONSGML weekdays//EN";
+
+/// The maximum length of a line, in octets, before it must be folded.
+const LINE_LIMIT: usize = 75;
+
+
+
+//		Enums
+
+//		EventTimes																
+/// The ways in which the times of an event can be written.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum EventTimes {
+	/// Floating local times, which are shown in the time zone of the viewer.
+	Floating,
+	
+	/// Times in UTC.
+	Utc,
+	
+	/// Times in a named time zone.
+	Zoned(IcsTimeZone),
+}
+
+
+
+//		Structs
+
+//		IcsEvent																
+/// An event that repeats on a set of days at a fixed time.
+/// 
+/// The event starts on the first of the given days on or after the start date,
+/// and repeats weekly on each of the days until the optional end date. Dates
+/// can be skipped by adding exceptions.
+/// 
+/// Times are written as floating local times, which calendar apps show in the
+/// time zone of the viewer, unless [`with_utc_times()`](Self::with_utc_times())
+/// is used to write them in UTC, or [`with_time_zone()`](Self::with_time_zone())
+/// to write them in a named time zone.
+/// 
+/// The `DTSTAMP` of the event is the time the schedule was created or last
+/// changed, which is given when creating the event, so that the output does
+/// not depend on the current time.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::{NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+/// use weekdays::{Weekdays, ics::{IcsEvent, write_calendar}};
+/// 
+/// let event = IcsEvent::new(
+///     "yoga-1@example.com",
+///     "Yoga, beginners",
+///     Weekdays::parse_const("tue, thu"),
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_time(NaiveTime::from_hms_opt(18, 30, 0).unwrap()),
+///     TimeDelta::minutes(45),
+///     Utc.with_ymd_and_hms(2023, 12, 1, 12, 0, 0).unwrap(),
+/// ).unwrap();
+/// 
+/// let ics = write_calendar(&[event]);
+/// assert!(ics.contains("DTSTAMP:20231201T120000Z\r\n"));
+/// assert!(ics.contains("DTSTART:20240102T183000\r\n"));
+/// assert!(ics.contains("RRULE:FREQ=WEEKLY;BYDAY=TU,TH\r\n"));
+/// assert!(ics.contains("SUMMARY:Yoga\\, beginners\r\n"));
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IcsEvent {
+	/// An optional longer description of the event.
+	description: Option<String>,
+	
+	/// The length of each occurrence. This is never negative.
+	duration:    TimeDelta,
+	
+	/// The last date on which the event can occur.
+	end_date:    Option<NaiveDate>,
+	
+	/// Dates on which the event does not occur.
+	exceptions:  Vec<NaiveDate>,
+	
+	/// An optional location for the event.
+	location:    Option<String>,
+	
+	/// The weekly rule for the event, without any limit. This always has an
+	/// interval of one, and at least one day.
+	rule:        WeeklyRule,
+	
+	/// The time at which the event was created or last changed.
+	stamp:       DateTime<Utc>,
+	
+	/// The date from which the event can occur, and the time of each
+	/// occurrence.
+	start:       NaiveDateTime,
+	
+	/// A short summary of the event, shown as its title.
+	summary:     String,
+	
+	/// How the times of the event are written.
+	times:       EventTimes,
+	
+	/// A globally unique identifier for the event.
+	uid:         String,
+}
+
+//󰭅		IcsEvent																
+impl IcsEvent {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`IcsEvent`] with no end date.
+	/// 
+	/// Returns [`None`] if no days are given, or the duration is negative.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `uid`      - A globally unique identifier for the event, which should
+	///   stay the same when the event is changed.
+	/// * `summary`  - A short summary of the event, shown as its title.
+	/// * `days`     - The days on which the event repeats.
+	/// * `start`    - The date from which the event can occur, and the time of
+	///   each occurrence.
+	/// * `duration` - The length of each occurrence.
+	/// * `stamp`    - The time at which the event was created or last changed.
+	/// 
+	#[must_use]
+	pub fn new(
+		uid:      &str,
+		summary:  &str,
+		days:     Weekdays,
+		start:    NaiveDateTime,
+		duration: TimeDelta,
+		stamp:    DateTime<Utc>,
+	) -> Option<Self> {
+		if days.is_empty() || duration < TimeDelta::zero() {
+			return None;
+		}
+		Some(Self {
+			description: None,
+			duration,
+			end_date:    None,
+			exceptions:  Vec::new(),
+			location:    None,
+			rule:        WeeklyRule::new(days, 1)?,
+			stamp,
+			start,
+			summary:     summary.to_owned(),
+			times:       EventTimes::Floating,
+			uid:         uid.to_owned(),
+		})
+	}
+	
+	//		with_description													
+	/// Sets a longer description of the event.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `description` - The description.
+	/// 
+	#[must_use]
+	pub fn with_description(mut self, description: &str) -> Self {
+		self.description = Some(description.to_owned());
+		self
+	}
+	
+	//		with_end_date														
+	/// Sets the last date on which the event can occur.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `end_date` - The last date, which is included.
+	/// 
+	#[must_use]
+	pub const fn with_end_date(mut self, end_date: NaiveDate) -> Self {
+		self.end_date = Some(end_date);
+		self
+	}
+	
+	//		with_exceptions														
+	/// Adds dates on which the event does not occur.
+	/// 
+	/// The dates are written in order, and duplicates are removed.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `dates` - The dates to skip.
+	/// 
+	#[must_use]
+	pub fn with_exceptions<I>(mut self, dates: I) -> Self
+		where I: IntoIterator<Item = NaiveDate>,
+	{
+		self.exceptions.extend(dates);
+		self.exceptions.sort_unstable();
+		self.exceptions.dedup();
+		self
+	}
+	
+	//		with_location														
+	/// Sets the location of the event.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `location` - The location.
+	/// 
+	#[must_use]
+	pub fn with_location(mut self, location: &str) -> Self {
+		self.location = Some(location.to_owned());
+		self
+	}
+	
+	//		with_time_zone														
+	/// Sets a named time zone for the times of the event.
+	/// 
+	/// The times are written with a `TZID` parameter, and the `VTIMEZONE`
+	/// component of the time zone is written in the document.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `time_zone` - The time zone.
+	/// 
+	#[must_use]
+	pub fn with_time_zone(mut self, time_zone: IcsTimeZone) -> Self {
+		self.times = EventTimes::Zoned(time_zone);
+		self
+	}
+	
+	//		with_utc_times														
+	/// Marks the times of the event as being in UTC, rather than floating
+	/// local times.
+	#[must_use]
+	pub fn with_utc_times(mut self) -> Self {
+		self.times = EventTimes::Utc;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		days																
+	/// Returns the days on which the event repeats.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.rule.days()
+	}
+	
+	//		first_occurrence													
+	/// Returns the date of the first occurrence, if there is one.
+	/// 
+	/// This is the first of the days on or after the start date, provided it is
+	/// not after the end date. Exceptions are not taken into account.
+	/// 
+	#[must_use]
+	pub fn first_occurrence(&self) -> Option<NaiveDate> {
+		//	The start date is always an occurrence of the rule, but is only the
+		//	first occurrence of the event if it is on one of the days
+		self.rule
+			.occurrences(self.start.date())
+			.find(|&date| self.days().contains(Weekdays::from(date.weekday())))
+			.filter(|&date| self.end_date.map_or(true, |end| date <= end))
+	}
+	
+	//		to_ics																
+	/// Writes the event as a complete `.ics` document.
+	/// 
+	/// This is the same as calling [`write_calendar()`] with just this event.
+	/// 
+	#[must_use]
+	pub fn to_ics(&self) -> String {
+		write_calendar(slice::from_ref(self))
+	}
+	
+	//		Private methods														
+	
+	//		format_times														
+	/// Formats dates at the time of the event, marked as UTC or with a time
+	/// zone if needed.
+	/// 
+	/// The result is ready to follow a property name, such as `DTSTART`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `dates` - The dates to format.
+	/// 
+	fn format_times(&self, dates: &[NaiveDate]) -> String {
+		let format = match self.times {
+			EventTimes::Utc                             => "%Y%m%dT%H%M%SZ",
+			EventTimes::Floating | EventTimes::Zoned(_) => "%Y%m%dT%H%M%S",
+		};
+		let times  = dates
+			.iter()
+			.map(|date| date.and_time(self.start.time()).format(format).to_string())
+			.collect::<Vec<_>>()
+			.join(",");
+		match self.times {
+			EventTimes::Zoned(ref time_zone)       => format!(";TZID={}:{times}", time_zone.id),
+			EventTimes::Floating | EventTimes::Utc => format!(":{times}"),
+		}
+	}
+	
+	//		write																
+	/// Writes the event as a `VEVENT` component.
+	/// 
+	/// Nothing is written if the event has no occurrences.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `lines` - The lines to append to.
+	/// 
+	fn write(&self, lines: &mut Vec<String>) {
+		let Some(first) = self.first_occurrence() else {
+			return;
+		};
+		let mut rule = self.rule;
+		if let Some(end) = self.end_date {
+			//	The number of occurrences is given instead of UNTIL, which would
+			//	need to match DTSTART in being floating or in UTC
+			let count = rule.occurrences(first).take_while(|&date| date <= end).count();
+			rule      = rule.with_count(u32::try_from(count).unwrap_or(u32::MAX));
+		}
+		lines.push("BEGIN:VEVENT".to_owned());
+		lines.push(format!("UID:{}", escape_text(&self.uid)));
+		lines.push(format!("DTSTAMP:{}", self.stamp.format("%Y%m%dT%H%M%SZ")));
+		lines.push(format!("DTSTART{}", self.format_times(&[first])));
+		lines.push(format!("DURATION:{}", format_duration(self.duration)));
+		lines.push(format!("RRULE:{rule}"));
+		if !self.exceptions.is_empty() {
+			lines.push(format!("EXDATE{}", self.format_times(&self.exceptions)));
+		}
+		lines.push(format!("SUMMARY:{}", escape_text(&self.summary)));
+		if let Some(ref description) = self.description {
+			lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+		}
+		if let Some(ref location) = self.location {
+			lines.push(format!("LOCATION:{}", escape_text(location)));
+		}
+		lines.push("END:VEVENT".to_owned());
+	}
+}
+
+
+
+//		IcsTimeZone																
+/// A named time zone, along with the `VTIMEZONE` component that describes it.
+/// 
+/// Calendar apps need the offsets and daylight saving rules of a time zone in
+/// order to show times in it, and RFC 5545 requires them to be given in a
+/// `VTIMEZONE` component for each time zone used. As these rules come from
+/// time zone data, the component is supplied by the caller, such as from a
+/// time zone database or a service like [tzurl.org](https://www.tzurl.org/).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::{NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+/// use weekdays::{Weekdays, ics::{IcsEvent, IcsTimeZone}};
+/// 
+/// let london = IcsTimeZone::new("Europe/London", "\
+///     BEGIN:VTIMEZONE\n\
+///     TZID:Europe/London\n\
+///     BEGIN:DAYLIGHT\n\
+///     TZOFFSETFROM:+0000\n\
+///     TZOFFSETTO:+0100\n\
+///     TZNAME:BST\n\
+///     DTSTART:19700329T010000\n\
+///     RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\n\
+///     END:DAYLIGHT\n\
+///     BEGIN:STANDARD\n\
+///     TZOFFSETFROM:+0100\n\
+///     TZOFFSETTO:+0000\n\
+///     TZNAME:GMT\n\
+///     DTSTART:19701025T020000\n\
+///     RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\n\
+///     END:STANDARD\n\
+///     END:VTIMEZONE\n\
+/// ").unwrap();
+/// 
+/// let event = IcsEvent::new(
+///     "yoga-1@example.com",
+///     "Yoga",
+///     Weekdays::parse_const("tue, thu"),
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_time(NaiveTime::from_hms_opt(18, 30, 0).unwrap()),
+///     TimeDelta::minutes(45),
+///     Utc.with_ymd_and_hms(2023, 12, 1, 12, 0, 0).unwrap(),
+/// ).unwrap().with_time_zone(london);
+/// 
+/// let ics = event.to_ics();
+/// assert!(ics.contains("BEGIN:VTIMEZONE\r\nTZID:Europe/London\r\n"));
+/// assert!(ics.contains("DTSTART;TZID=Europe/London:20240102T183000\r\n"));
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IcsTimeZone {
+	/// The unfolded lines of the `VTIMEZONE` component.
+	component: Vec<String>,
+	
+	/// The identifier of the time zone, such as `Europe/London`.
+	id:        String,
+}
+
+//󰭅		IcsTimeZone																
+impl IcsTimeZone {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`IcsTimeZone`] from its identifier and `VTIMEZONE`
+	/// component.
+	/// 
+	/// The component can have CRLF or LF line endings, and its lines can be
+	/// folded. It must begin with `BEGIN:VTIMEZONE`, end with `END:VTIMEZONE`,
+	/// and have a `TZID` property that matches the identifier, or else [`None`]
+	/// is returned. [`None`] is also returned if the identifier is empty, or
+	/// has characters that cannot be written in a parameter without quoting,
+	/// which are control characters, double quotes, `;`, `:`, and `,`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `id`        - The identifier of the time zone, such as
+	///   `Europe/London`.
+	/// * `component` - The `VTIMEZONE` component describing the time zone.
+	/// 
+	#[must_use]
+	pub fn new(id: &str, component: &str) -> Option<Self> {
+		if id.is_empty() || id.chars().any(|c| c.is_control() || matches!(c, '"' | ';' | ':' | ',')) {
+			return None;
+		}
+		let mut lines: Vec<String> = Vec::new();
+		for line in component.lines() {
+			match line.strip_prefix([' ', '\t']) {
+				Some(continuation)      => lines.last_mut()?.push_str(continuation),
+				None if line.is_empty() => {}
+				None                    => lines.push(line.to_owned()),
+			}
+		}
+		let begins = lines.first().is_some_and(|line| line.eq_ignore_ascii_case("BEGIN:VTIMEZONE"));
+		let ends   = lines.last().is_some_and(|line| line.eq_ignore_ascii_case("END:VTIMEZONE"));
+		let named  = lines.iter().any(|line| {
+			line.split_once(':').is_some_and(|(name, value)| name.eq_ignore_ascii_case("TZID") && value == id)
+		});
+		(begins && ends && named).then(|| Self { component: lines, id: id.to_owned() })
+	}
+	
+	//		Public methods														
+	
+	//		id																	
+	/// Returns the identifier of the time zone.
+	#[must_use]
+	pub fn id(&self) -> &str {
+		&self.id
+	}
+}
+
+
+
+//		Functions
+
+//		write_calendar															
+/// Writes events as a complete `.ics` document.
+/// 
+/// The document is a single `VCALENDAR` with a `VEVENT` for each event, in the
+/// order given. Events with no occurrences are left out. The `VTIMEZONE`
+/// component of each time zone used is written once, before the events. If
+/// different time zones have the same identifier, the first one is used.
+/// 
+/// # Parameters
+/// 
+/// * `events` - The events to write.
+/// 
+#[must_use]
+pub fn write_calendar(events: &[IcsEvent]) -> String {
+	let mut lines = vec![
+		"BEGIN:VCALENDAR".to_owned(),
+		"VERSION:2.0".to_owned(),
+		format!("PRODID:{PRODUCT_ID}"),
+		"CALSCALE:GREGORIAN".to_owned(),
+	];
+	let mut time_zones: Vec<&IcsTimeZone> = Vec::new();
+	for event in events.iter().filter(|event| event.first_occurrence().is_some()) {
+		if let EventTimes::Zoned(ref time_zone) = event.times {
+			if !time_zones.iter().any(|known| known.id == time_zone.id) {
+				time_zones.push(time_zone);
+			}
+		}
+	}
+	for time_zone in time_zones {
+		lines.extend(time_zone.component.iter().cloned());
+	}
+	for event in events {
+		event.write(&mut lines);
+	}
+	lines.push("END:VCALENDAR".to_owned());
+	lines.iter().fold(String::new(), |mut output, line| {
+		output.push_str(&fold_line(line));
+		output.push_str("\r\n");
+		output
+	})
+}
+
+//		escape_text																
+/// Escapes a value for a `TEXT` property.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The text to escape.
+/// 
+fn escape_text(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			';'  => escaped.push_str("\\;"),
+			','  => escaped.push_str("\\,"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => {}
+			_    => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+//		fold_line																
+/// Folds a content line so that no line is longer than 75 octets.
+/// 
+/// Each continuation line starts with a single space, which counts towards
+/// its length. Lines are only folded between characters, so multi-byte
+/// characters are never split.
+/// 
+/// # Parameters
+/// 
+/// * `line` - The line to fold.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Lengths are bounded by the line length")]
+fn fold_line(line: &str) -> String {
+	let mut folded = String::with_capacity(line.len());
+	let mut length = 0;
+	for c in line.chars() {
+		if length + c.len_utf8() > LINE_LIMIT {
+			folded.push_str("\r\n ");
+			length = 1;
+		}
+		folded.push(c);
+		length += c.len_utf8();
+	}
+	folded
+}
+
+//		format_duration															
+/// Formats a duration as an iCalendar `DURATION` value.
+/// 
+/// Units with a value of zero are left out, except that minutes are always
+/// written between hours and seconds, as RFC 5545 does not allow hours to be
+/// followed directly by seconds.
+/// 
+/// # Parameters
+/// 
+/// * `duration` - The duration to format, which must not be negative.
+/// 
+#[expect(clippy::integer_division, reason = "Only whole units are wanted")]
+fn format_duration(duration: TimeDelta) -> String {
+	let total   = duration.num_seconds();
+	let days    = total / 86_400;
+	let hours   = total % 86_400 / 3_600;
+	let seconds = total % 60;
+	let time    = [(hours, 'H'), (total % 3_600 / 60, 'M'), (seconds, 'S')]
+		.iter()
+		.filter(|&&(amount, unit)| amount > 0 || (unit == 'M' && hours > 0 && seconds > 0))
+		.fold(String::new(), |mut time, &(amount, unit)| {
+			time.push_str(&amount.to_string());
+			time.push(unit);
+			time
+		});
+	match (days, time.is_empty()) {
+		(0, true)  => "PT0S".to_owned(),
+		(0, false) => format!("PT{time}"),
+		(_, true)  => format!("P{days}D"),
+		(_, false) => format!("P{days}DT{time}"),
+	}
+}
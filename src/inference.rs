@@ -0,0 +1,418 @@
+//! Inference of recurring patterns from observed dates.
+//! 
+//! The main type provided is the [`PatternInference`] struct, which looks at a
+//! history of dates, such as the dates of a customer's orders, and works out
+//! which days of the week they most likely fall on. The result is an
+//! [`InferredPattern`], which reports how confident the inference is for each
+//! day, and whether the pattern repeats over more than one week, such as
+//! fortnightly.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/inference.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{Weekdays, multiweek::MultiWeekPattern};
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeSet;
+
+
+
+//		Structs
+
+//		InferredPattern															
+/// A pattern of days inferred from a history of dates.
+/// 
+/// The history is considered week by week, from the Monday of the week of the
+/// earliest date to the Sunday of the week of the latest date, and each day of
+/// each week is either observed or not. Repeated dates count only once.
+/// 
+/// The inferred pattern is always held as a [`MultiWeekPattern`], anchored to
+/// the first observed week. Most histories are best explained by a pattern
+/// that repeats every week, in which case the cycle is one week long.
+/// 
+#[derive(Clone, Debug, PartialEq)]
+pub struct InferredPattern {
+	/// The proportion of matching days in the history.
+	accuracy:     f64,
+	
+	/// The proportion of weeks in which each day was observed, from Monday to
+	/// Sunday.
+	confidence:   [f64; 7],
+	
+	/// The number of distinct dates observed.
+	observations: usize,
+	
+	/// The inferred pattern.
+	pattern:      MultiWeekPattern,
+	
+	/// The number of weeks in the history.
+	weeks:        usize,
+}
+
+//󰭅		InferredPattern															
+impl InferredPattern {
+	//		Public methods														
+	
+	//		accuracy															
+	/// Returns how well the pattern matches the history.
+	/// 
+	/// This is the proportion of all days in the history, whether observed or
+	/// not, on which the pattern agrees with what was observed. It ranges from
+	/// `0.0` to `1.0`.
+	/// 
+	#[must_use]
+	pub const fn accuracy(&self) -> f64 {
+		self.accuracy
+	}
+	
+	//		confidence															
+	/// Returns the confidence that the given day is part of a weekly pattern.
+	/// 
+	/// This is the proportion of weeks in the history in which the day was
+	/// observed, and ranges from `0.0` to `1.0`. A day in a fortnightly pattern
+	/// will therefore have a confidence of around `0.5`.
+	/// 
+	/// If more than one day is given, the lowest confidence of those days is
+	/// returned. If no days are given, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn confidence(&self, day: Weekdays) -> Option<f64> {
+		Weekdays::ALL_DAYS.iter()
+			.zip(self.confidence)
+			.filter(|&(candidate, _)| day.contains(candidate))
+			.map(|(_, confidence)| confidence)
+			.reduce(f64::min)
+	}
+	
+	//		confidences															
+	/// Returns the confidence for each day of the week, from Monday to Sunday.
+	/// 
+	/// See [`confidence()`](Self::confidence()) for details.
+	/// 
+	#[must_use]
+	pub const fn confidences(&self) -> [f64; 7] {
+		self.confidence
+	}
+	
+	//		days																
+	/// Returns the days included in the pattern.
+	/// 
+	/// If the pattern repeats over more than one week, this is every day that
+	/// is included in any week of the cycle.
+	/// 
+	#[must_use]
+	pub fn days(&self) -> Weekdays {
+		self.pattern.weeks().iter().fold(Weekdays::NONE, |days, &week| days | week)
+	}
+	
+	//		observations														
+	/// Returns the number of distinct dates in the history.
+	#[must_use]
+	pub const fn observations(&self) -> usize {
+		self.observations
+	}
+	
+	//		pattern																
+	/// Returns the inferred pattern, anchored to the first observed week.
+	#[must_use]
+	pub const fn pattern(&self) -> &MultiWeekPattern {
+		&self.pattern
+	}
+	
+	//		period																
+	/// Returns the number of weeks after which the pattern repeats.
+	/// 
+	/// This is `1` for a weekly pattern, `2` for a fortnightly pattern, and so
+	/// on.
+	/// 
+	#[must_use]
+	pub fn period(&self) -> usize {
+		self.pattern.cycle_len()
+	}
+	
+	//		weeks																
+	/// Returns the number of weeks in the history.
+	#[must_use]
+	pub const fn weeks(&self) -> usize {
+		self.weeks
+	}
+}
+
+//		PatternInference														
+/// Infers patterns of days from histories of dates.
+/// 
+/// A day is included in the inferred pattern if it was observed in at least a
+/// minimum proportion of weeks, known as the minimum support, which defaults
+/// to half of the weeks.
+/// 
+/// Patterns that repeat over several weeks are also considered, up to a
+/// maximum period, which defaults to four weeks. For each period, the weeks of
+/// the history are split by their position in the cycle, and the minimum
+/// support is applied to each position separately. A longer period is only
+/// chosen if it leaves fewer than half as many days on which the pattern and
+/// the history disagree as the best shorter period, so that noise is not
+/// mistaken for a cycle. A period is only considered if the history covers it
+/// at least twice.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveDate;
+/// use weekdays::{Weekdays, inference::PatternInference};
+/// 
+/// // Orders on Tuesdays and Fridays, with one Friday missed
+/// let orders: Vec<NaiveDate> = ["2024-01-02", "2024-01-05", "2024-01-09", "2024-01-16", "2024-01-19"]
+///     .iter()
+///     .map(|date| date.parse().unwrap())
+///     .collect();
+/// let inferred = PatternInference::new().infer(orders).unwrap();
+/// 
+/// assert_eq!(inferred.days(),                         Weekdays::parse_const("tue, fri"));
+/// assert_eq!(inferred.period(),                       1);
+/// assert_eq!(inferred.confidence(Weekdays::TUESDAY),  Some(1.0));
+/// assert_eq!(inferred.confidence(Weekdays::WEDNESDAY), Some(0.0));
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PatternInference {
+	/// The longest period, in weeks, to consider.
+	max_period:  usize,
+	
+	/// The minimum proportion of weeks in which a day must be observed.
+	min_support: f64,
+}
+
+//󰭅		PatternInference														
+impl PatternInference {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`PatternInference`] with the default settings.
+	/// 
+	/// The minimum support is `0.5`, and the maximum period is four weeks.
+	/// 
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			max_period:  4,
+			min_support: 0.5,
+		}
+	}
+	
+	//		with_max_period														
+	/// Sets the longest period, in weeks, to consider.
+	/// 
+	/// A maximum period of `1` disables the detection of patterns that repeat
+	/// over more than one week.
+	/// 
+	/// Returns [`None`] if the period is zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `weeks` - The longest period, in weeks.
+	/// 
+	#[must_use]
+	pub const fn with_max_period(mut self, weeks: usize) -> Option<Self> {
+		if weeks == 0 {
+			return None;
+		}
+		self.max_period = weeks;
+		Some(self)
+	}
+	
+	//		with_min_support													
+	/// Sets the minimum proportion of weeks in which a day must be observed to
+	/// be included in the pattern.
+	/// 
+	/// Returns [`None`] if the support is out of range.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `support` - The minimum support, above `0.0` and up to `1.0`.
+	/// 
+	#[must_use]
+	pub fn with_min_support(mut self, support: f64) -> Option<Self> {
+		if !(support > 0.0_f64 && support <= 1.0_f64) {
+			return None;
+		}
+		self.min_support = support;
+		Some(self)
+	}
+	
+	//		Public methods														
+	
+	//		infer																
+	/// Infers the most likely pattern from a history of dates.
+	/// 
+	/// Anything with a date can be given, including [`NaiveDate`] and
+	/// timestamps such as [`DateTime`](chrono::DateTime), in which case only
+	/// the date part is used. The dates do not need to be in order.
+	/// 
+	/// Returns [`None`] if no dates are given.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `dates` - The observed dates.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Week numbers are bounded by the supported dates")]
+	#[expect(clippy::cast_precision_loss,     reason = "Precision loss is acceptable for proportions")]
+	#[must_use]
+	pub fn infer<I>(&self, dates: I) -> Option<InferredPattern>
+		where
+			I:       IntoIterator,
+			I::Item: Datelike,
+	{
+		let observed: BTreeSet<(i64, Weekdays)> = dates.into_iter()
+			.map(|date| (week_number(&date), Weekdays::from(date.weekday())))
+			.collect();
+		let first       = observed.first()?.0;
+		let last        = observed.last()?.0;
+		let mut history = vec![Weekdays::NONE; usize::try_from(last - first + 1).ok()?];
+		for &(week, day) in &observed {
+			if let Some(days) = usize::try_from(week - first).ok().and_then(|index| history.get_mut(index)) {
+				*days |= day;
+			}
+		}
+		let (weeks, errors) = (1..=self.max_period)
+			.take_while(|&period| period == 1 || history.len() >= period * 2)
+			.map(|period| self.fit(&history, period))
+			.reduce(|best, candidate| if candidate.1 * 2 < best.1 { candidate } else { best })?;
+		let confidence = Weekdays::ALL_DAYS.iter()
+			.map(|day| history.iter().filter(|week| week.contains(day)).count() as f64 / history.len() as f64);
+		let anchor     = NaiveDate::from_num_days_from_ce_opt(i32::try_from(first * 7 + 1).ok()?)?;
+		Some(InferredPattern {
+			accuracy:     1.0 - errors as f64 / (history.len() * 7) as f64,
+			confidence:   array(confidence),
+			observations: observed.len(),
+			pattern:      MultiWeekPattern::new(anchor, weeks)?,
+			weeks:        history.len(),
+		})
+	}
+	
+	//		Private methods														
+	
+	//		fit																	
+	/// Fits a pattern with the given period to a history of weeks.
+	/// 
+	/// Returns the days for each week of the cycle, and the number of days on
+	/// which the pattern and the history disagree.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `history` - The observed days for each week.
+	/// * `period`  - The number of weeks in the cycle.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Counts are bounded by the length of the history")]
+	#[expect(clippy::cast_precision_loss,     reason = "Precision loss is acceptable for proportions")]
+	fn fit(&self, history: &[Weekdays], period: usize) -> (Vec<Weekdays>, usize) {
+		let mut errors = 0;
+		let weeks      = (0..period).map(|phase| {
+			let observed = history.iter().skip(phase).step_by(period);
+			let total    = observed.clone().count();
+			let days     = Weekdays::ALL_DAYS.iter()
+				.filter(|&day| {
+					let count = observed.clone().filter(|week| week.contains(day)).count();
+					count as f64 >= self.min_support * total as f64
+				})
+				.fold(Weekdays::NONE, |days, day| days | day);
+			errors += observed.map(|week| usize::from(week.hamming_distance(days))).sum::<usize>();
+			days
+		}).collect();
+		(weeks, errors)
+	}
+}
+
+//󰭅		Default																	
+impl Default for PatternInference {
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+
+
+//		Functions
+
+//		infer_pattern															
+/// Infers the most likely pattern from a history of dates, using the default
+/// settings.
+/// 
+/// This is a shortcut for [`PatternInference::new()`] followed by
+/// [`PatternInference::infer()`].
+/// 
+/// Returns [`None`] if no dates are given.
+/// 
+/// # Parameters
+/// 
+/// * `dates` - The observed dates.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::{Days, NaiveDate};
+/// use weekdays::{Weekdays, inference::infer_pattern};
+/// 
+/// // Bins collected every other Thursday
+/// let start    = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+/// let dates    = (0..10).map(|fortnight| start + Days::new(fortnight * 14));
+/// let inferred = infer_pattern(dates).unwrap();
+/// 
+/// assert_eq!(inferred.period(),         2);
+/// assert_eq!(inferred.pattern().weeks(), &[Weekdays::THURSDAY, Weekdays::NONE]);
+/// assert_eq!(inferred.accuracy(),       1.0);
+/// ```
+/// 
+pub fn infer_pattern<I>(dates: I) -> Option<InferredPattern>
+	where
+		I:       IntoIterator,
+		I::Item: Datelike,
+{
+	PatternInference::new().infer(dates)
+}
+
+//		array																	
+/// Collects the values for each day of the week into an array.
+/// 
+/// # Parameters
+/// 
+/// * `values` - The values, from Monday to Sunday.
+/// 
+fn array<I>(values: I) -> [f64; 7]
+	where I: Iterator<Item = f64>,
+{
+	let mut array = [0.0_f64; 7];
+	for (slot, value) in array.iter_mut().zip(values) {
+		*slot = value;
+	}
+	array
+}
+
+//		week_number																
+/// Returns the number of the week containing the given date.
+/// 
+/// Weeks run from Monday to Sunday, and are counted from the week containing
+/// the first day of the Common Era, which was a Monday.
+/// 
+/// # Parameters
+/// 
+/// * `date` - The date.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "A day count less one cannot overflow an i64")]
+fn week_number<D>(date: &D) -> i64
+	where D: Datelike,
+{
+	(i64::from(date.num_days_from_ce()) - 1).div_euclid(7)
+}
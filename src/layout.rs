@@ -0,0 +1,194 @@
+//! Day-of-week bitmask conventions used by other platforms.
+//! 
+//! [`Weekdays`](crate::Weekdays) always stores Monday in the most significant
+//! of seven bits and Sunday in the least significant. Other systems number
+//! their bits differently, and the [`BitLayout`] enum describes where each day
+//! sits for a number of well-known conventions, as well as for custom ones.
+//! Values are converted using [`Weekdays::to_layout()`](crate::Weekdays::to_layout)
+//! and [`Weekdays::from_layout()`](crate::Weekdays::from_layout).
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/layout.rs"]
+mod tests;
+
+
+
+//		Enums
+
+//		BitLayout																
+/// The bit layouts used by various platforms to represent days of the week.
+/// 
+/// Each layout maps every day to a single bit in a 32-bit value. The named
+/// layouts cover the conventions in common use, and [`Custom`](Self::Custom)
+/// allows any other arrangement to be described.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, layout::BitLayout};
+/// 
+/// let bits = Weekdays::WEEKDAYS.to_layout(BitLayout::WindowsSunday1);
+/// assert_eq!(bits, 0b011_1110);
+/// assert_eq!(Weekdays::from_layout(bits, BitLayout::WindowsSunday1), Some(Weekdays::WEEKDAYS));
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BitLayout {
+	/// The layout used by this crate, with Monday as `64` through to Sunday as
+	/// `1`.
+	Native,
+	
+	/// The reversed ISO order used by some cron daemons, with Monday as `1`
+	/// through to Sunday as `64`.
+	ReversedIso,
+	
+	/// Java `DayOfWeek` ordinals, where each day is `1 << ordinal()`, giving
+	/// Monday as `1` through to Sunday as `64`.
+	JavaOrdinal,
+	
+	/// JavaScript `Date.getDay()` values, where each day is `1 << getDay()`,
+	/// giving Sunday as `1`, Monday as `2`, through to Saturday as `64`.
+	JavaScript,
+	
+	/// `MySQL` `DAYOFWEEK()` values, where each day is `1 << DAYOFWEEK()`,
+	/// giving Sunday as `2`, Monday as `4`, through to Saturday as `128`.
+	MySql,
+	
+	/// The layout used by Windows Task Scheduler and Microsoft Exchange, with
+	/// Sunday as `1`, Monday as `2`, through to Saturday as `64`.
+	WindowsSunday1,
+	
+	/// A custom layout, with each day at the given bit position.
+	Custom(CustomLayout),
+}
+
+//󰭅		BitLayout																
+impl BitLayout {
+	//		Public methods														
+	
+	//		mask																
+	/// Returns the mask of all bits used by the layout.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::layout::BitLayout;
+	/// 
+	/// assert_eq!(BitLayout::Native.mask(), 0b111_1111);
+	/// assert_eq!(BitLayout::MySql.mask(),  0b1111_1110);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn mask(self) -> u32 {
+		self.positions().into_iter().fold(0, |mask, position| mask | bit(position))
+	}
+	
+	//		positions															
+	/// Returns the bit position of each day, from Monday to Sunday.
+	/// 
+	/// Positions are counted from zero for the least significant bit.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::layout::BitLayout;
+	/// 
+	/// assert_eq!(BitLayout::Native.positions(),         [6, 5, 4, 3, 2, 1, 0]);
+	/// assert_eq!(BitLayout::WindowsSunday1.positions(), [1, 2, 3, 4, 5, 6, 0]);
+	/// ```
+	/// 
+	#[must_use]
+	pub const fn positions(self) -> [u8; 7] {
+		match self {
+			Self::Native                            => [6, 5, 4, 3, 2, 1, 0],
+			Self::ReversedIso | Self::JavaOrdinal   => [0, 1, 2, 3, 4, 5, 6],
+			Self::JavaScript | Self::WindowsSunday1 => [1, 2, 3, 4, 5, 6, 0],
+			Self::MySql                             => [2, 3, 4, 5, 6, 7, 1],
+			Self::Custom(custom)                    => custom.positions,
+		}
+	}
+}
+
+
+
+//		Structs
+
+//		CustomLayout															
+/// A custom bit layout, giving the bit position of each day.
+/// 
+/// # See also
+/// 
+/// * [`BitLayout::Custom`]
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CustomLayout {
+	/// The bit position of each day, from Monday to Sunday.
+	positions: [u8; 7],
+}
+
+//󰭅		CustomLayout															
+impl CustomLayout {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new custom layout from the bit position of each day.
+	/// 
+	/// Positions are counted from zero for the least significant bit, and
+	/// are given from Monday to Sunday. If any position is 32 or more, or if
+	/// two days share a position, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `positions` - The bit position of each day, from Monday to Sunday.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, layout::{BitLayout, CustomLayout}};
+	/// 
+	/// let layout = BitLayout::Custom(CustomLayout::new([8, 9, 10, 11, 12, 13, 14]).unwrap());
+	/// assert_eq!(Weekdays::MONDAY.to_layout(layout), 0x100);
+	/// 
+	/// assert_eq!(CustomLayout::new([0, 0, 1, 2, 3, 4, 5]), None);
+	/// assert_eq!(CustomLayout::new([0, 1, 2, 3, 4, 5, 32]), None);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn new(positions: [u8; 7]) -> Option<Self> {
+		let mut seen = 0_u32;
+		for position in positions {
+			if position >= 32 || seen & bit(position) != 0 {
+				return None;
+			}
+			seen |= bit(position);
+		}
+		Some(Self { positions })
+	}
+	
+	//		Public methods														
+	
+	//		positions															
+	/// Returns the bit position of each day, from Monday to Sunday.
+	#[must_use]
+	pub const fn positions(&self) -> [u8; 7] {
+		self.positions
+	}
+}
+
+
+
+//		Functions
+
+//		bit																		
+/// Returns the value with only the given bit set.
+/// 
+/// Positions of 32 or more give zero.
+/// 
+fn bit(position: u8) -> u32 {
+	1_u32.checked_shl(u32::from(position)).unwrap_or(0)
+}
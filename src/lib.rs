@@ -1,6 +1,7 @@
 //! The Weekdays crate provides days of the week bit-mapped in a single byte.
 //! 
-//! The main type provided is the [`Weekdays`] struct.
+//! The main type provided is the [`Weekdays`] struct, and sets of days can be
+//! written concisely using the [`weekdays!`] macro.
 //! 
 
 
@@ -43,6 +44,38 @@
 #[path = "tests/lib.rs"]
 mod tests;
 
+mod macros;
+
+pub mod allocation;
+pub mod availability;
+pub mod calendar;
+pub mod conflict;
+pub mod cron;
+pub mod expression;
+#[cfg(feature = "gtfs")]
+pub mod gtfs;
+pub mod hours;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+#[cfg(feature = "chrono")]
+pub mod ics;
+#[cfg(feature = "chrono")]
+pub mod inference;
+pub mod layout;
+pub mod map;
+#[cfg(feature = "chrono")]
+pub mod multiweek;
+pub mod opening_hours;
+pub mod phrase;
+#[cfg(all(feature = "chrono", feature = "serde"))]
+pub mod recurrence;
+pub mod rota;
+pub mod rrule;
+#[cfg(feature = "serde")]
+pub mod schema_org;
+pub mod stats;
+pub mod systemd;
+
 
 
 //		Packages
@@ -52,9 +85,14 @@ use core::{
 	fmt,
 	ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
 };
+use crate::layout::BitLayout;
 
 #[cfg(feature = "chrono")]
 use chrono::Weekday;
+#[cfg(all(test, feature = "chrono"))]
+use chrono::NaiveDate;
+#[cfg(test)]
+use core::str::FromStr;
 #[cfg(feature = "postgres")]
 use ::{
 	bytes::BytesMut,
@@ -67,6 +105,41 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 
 
+//		Constants
+
+/// The latest minute that a time window can end at, which is midnight at the
+/// end of the following day.
+const MAX_MINUTE: u16 = 2_880;
+
+/// The number of minutes in a day.
+const MINUTES_PER_DAY: u16 = 1_440;
+
+
+
+//		Enums
+
+//		Similarity																
+/// The measures available for comparing sets of days.
+/// 
+/// # See also
+/// 
+/// * [`Weekdays::nearest()`]
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Similarity {
+	/// Rank by [`Weekdays::hamming_distance()`], lowest first.
+	Hamming,
+	
+	/// Rank by [`Weekdays::jaccard_similarity()`], highest first.
+	Jaccard,
+	
+	/// Rank by [`Weekdays::overlap()`], highest first.
+	Overlap,
+}
+
+
+
 //		Structs
 
 //		Weekdays																
@@ -139,8 +212,71 @@ impl Weekdays {
 	/// The mask for all days of the week.
 	const ALL_DAYS_MASK: u8 = 0b111_1111;
 	
+	/// The English names of the days of the week as written in text, from
+	/// Monday to Sunday.
+	const DISPLAY_NAMES: [&str; 7] = [
+		"Monday",
+		"Tuesday",
+		"Wednesday",
+		"Thursday",
+		"Friday",
+		"Saturday",
+		"Sunday",
+	];
+	
+	/// The lowercase names of the days of the week, from Monday to Sunday.
+	const NAMES: [&str; 7] = [
+		"monday",
+		"tuesday",
+		"wednesday",
+		"thursday",
+		"friday",
+		"saturday",
+		"sunday",
+	];
+	
 	//		Constructors														
 	
+	//		from_layout															
+	/// Creates a new [`Weekdays`] struct from a value in another bit layout.
+	/// 
+	/// This converts from the day-of-week bitmask convention used by another
+	/// platform, such as Windows Task Scheduler or JavaScript, into the layout
+	/// used by this crate. If the value has any bits set that are not used by
+	/// the layout, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bits`   - The bit-mapped value to convert.
+	/// * `layout` - The layout that the value uses.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, layout::BitLayout};
+	/// 
+	/// assert_eq!(Weekdays::from_layout(0b100_0001, BitLayout::WindowsSunday1), Some(Weekdays::WEEKENDS));
+	/// assert_eq!(Weekdays::from_layout(0b001_1111, BitLayout::JavaOrdinal),    Some(Weekdays::WEEKDAYS));
+	/// assert_eq!(Weekdays::from_layout(0b000_0001, BitLayout::MySql),          None);
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`Weekdays::to_layout()`]
+	/// 
+	#[must_use]
+	pub fn from_layout(bits: u32, layout: BitLayout) -> Option<Self> {
+		if bits & !layout.mask() != 0 {
+			return None;
+		}
+		Some(Self::ALL_DAYS
+			.iter()
+			.zip(layout.positions())
+			.filter(|&(_, position)| bits.checked_shr(u32::from(position)).is_some_and(|value| value & 1 == 1))
+			.fold(Self::NONE, |days, (day, _)| days.union(day))
+		)
+	}
+	
 	//		new																	
 	/// Creates a new [`Weekdays`] struct from the given number of days.
 	/// 
@@ -171,6 +307,102 @@ impl Weekdays {
 		Self(days & Self::ALL_DAYS_MASK)
 	}
 	
+	//		parse_const															
+	/// Parses a textual specification of days in a `const` context.
+	/// 
+	/// The specification is a comma-separated list of day names and inclusive
+	/// day ranges, such as `"mon-fri"` or `"Mon, Wed-Fri, Sun"`. Day names are
+	/// case-insensitive, and may be given either in full or as their
+	/// three-letter abbreviations. Ranges are cyclic, so `"fri-mon"` covers
+	/// Friday, Saturday, Sunday, and Monday. Whitespace around names is ignored,
+	/// and an empty specification gives [`NONE`](Self::NONE).
+	/// 
+	/// Because this method is `const`, using it to define a constant means that
+	/// an invalid specification will fail at compile time rather than at
+	/// runtime.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `spec` - The specification to parse.
+	/// 
+	/// # Panics
+	/// 
+	/// Panics if the specification contains an unknown day name, an empty
+	/// entry, or a malformed range. When evaluated in a `const` context, this
+	/// results in a compilation error.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// const WORKING: Weekdays = Weekdays::parse_const("mon-fri");
+	/// assert_eq!(WORKING, Weekdays::WEEKDAYS);
+	/// 
+	/// const CLASSES: Weekdays = Weekdays::parse_const("Mon, Wed-Thu, sunday");
+	/// assert_eq!(CLASSES, Weekdays::new(0b101_1001));
+	/// ```
+	/// 
+	/// ```compile_fail
+	/// use weekdays::Weekdays;
+	/// 
+	/// const BROKEN: Weekdays = Weekdays::parse_const("mon-fry");
+	/// ```
+	/// 
+	#[expect(clippy::panic, reason = "Panicking is the only way to fail at compile time")]
+	#[must_use]
+	pub const fn parse_const(spec: &str) -> Self {
+		match Self::parse_bytes(spec.as_bytes()) {
+			Some(days) => days,
+			None       => panic!("Invalid weekdays specification"),
+		}
+	}
+	
+	//		range																
+	/// Creates an inclusive range of days.
+	/// 
+	/// The range starts at the given start day and runs through to the given
+	/// end day, wrapping around from Sunday to Monday if the end day comes
+	/// before the start day in the week. This means that a range from Friday to
+	/// Monday will contain Friday, Saturday, Sunday, and Monday.
+	/// 
+	/// If either of the given values contains more than one day, the earliest
+	/// day in the week is used. If either is empty, the result will be empty.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `start` - The first day of the range.
+	/// * `end`   - The last day of the range.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let range = Weekdays::range(Weekdays::MONDAY, Weekdays::FRIDAY);
+	/// assert_eq!(range, Weekdays::WEEKDAYS);
+	/// 
+	/// let range = Weekdays::range(Weekdays::SATURDAY, Weekdays::MONDAY);
+	/// assert_eq!(range, Weekdays::new(0b100_0011));
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Non-empty values always have a leading zero")]
+	#[must_use]
+	pub const fn range(start: Self, end: Self) -> Self {
+		if start.is_empty() || end.is_empty() {
+			return Self::NONE;
+		}
+		//	The leading zero count is the position from Monday, plus one for the
+		//	unused eighth bit
+		let from = Self::ALL_DAYS_MASK >> (start.0.leading_zeros() - 1);
+		let to   = Self::ALL_DAYS_MASK >> end.0.leading_zeros();
+		if from > to {
+			Self(from & !to)
+		} else {
+			Self((from | !to) & Self::ALL_DAYS_MASK)
+		}
+	}
+	
 	//		Public methods														
 	
 	//		contains															
@@ -223,6 +455,94 @@ impl Weekdays {
 		self.0.count_ones() as u8
 	}
 	
+	//		gaps																
+	/// Returns an iterator over the gaps between the days that are set.
+	/// 
+	/// A gap is a run of consecutive days that are not set. As with
+	/// [`runs()`](Self::runs()), the week is treated as cyclic, so a gap can
+	/// wrap around from Sunday to Monday. This is useful for finding the rest
+	/// days between shifts, for instance.
+	/// 
+	/// Each gap is given as a pair of the first day of the gap and the number of
+	/// days in it. The gaps are yielded in order of their first day, starting
+	/// from Monday.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let shifts = Weekdays::parse_const("mon-tue, thu-fri");
+	/// let gaps   = shifts.gaps().collect::<Vec<_>>();
+	/// 
+	/// assert_eq!(gaps, vec![
+	///     (Weekdays::WEDNESDAY, 1),
+	///     (Weekdays::SATURDAY,  2),
+	/// ]);
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`max_gap()`](Self::max_gap())
+	/// * [`runs()`](Self::runs())
+	/// 
+	#[must_use]
+	pub const fn gaps(&self) -> WeekdaysRuns {
+		WeekdaysRuns {
+			days:     !self.0 & Self::ALL_DAYS_MASK,
+			position: 0,
+		}
+	}
+	
+	//		hamming_distance													
+	/// Returns the Hamming distance to another set of days.
+	/// 
+	/// This is the number of days that are set in one of the sets of days but
+	/// not the other, and so ranges from zero for identical sets to seven for
+	/// complementary sets.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to compare against.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let days = Weekdays::parse_const("mon-wed");
+	/// assert_eq!(days.hamming_distance(Weekdays::parse_const("tue-thu")), 2);
+	/// assert_eq!(days.hamming_distance(days),                             0);
+	/// ```
+	/// 
+	#[must_use]
+	pub const fn hamming_distance(&self, other: Self) -> u8 {
+		Self(self.0 ^ other.0).days()
+	}
+	
+	//		is_contiguous														
+	/// Checks if the set of days forms a single unbroken run.
+	/// 
+	/// The week is treated as cyclic, so Saturday to Monday counts as a single
+	/// run. An empty set of days has no runs, and so is not contiguous, whereas
+	/// all days of the week form one complete run.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// assert!( Weekdays::WEEKDAYS.is_contiguous());
+	/// assert!( Weekdays::parse_const("sat-mon").is_contiguous());
+	/// assert!(!Weekdays::parse_const("mon, wed").is_contiguous());
+	/// assert!(!Weekdays::NONE.is_contiguous());
+	/// ```
+	/// 
+	#[must_use]
+	pub fn is_contiguous(&self) -> bool {
+		self.runs().count() == 1
+	}
+	
 	//		is_empty															
 	/// Checks if the set of days is empty.
 	/// 
@@ -283,6 +603,34 @@ impl Weekdays {
 		self.0 & Self::WEEKDAYS.0 == 0
 	}
 	
+	//		jaccard_similarity													
+	/// Returns the Jaccard similarity with another set of days.
+	/// 
+	/// This is the number of days the two sets have in common, divided by the
+	/// number of days in either set. It ranges from `0.0` for sets with no days
+	/// in common to `1.0` for identical sets. Two empty sets are considered
+	/// identical, and so have a similarity of `1.0`.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to compare against.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let days = Weekdays::parse_const("mon-wed");
+	/// assert_eq!(days.jaccard_similarity(Weekdays::parse_const("tue-thu")), 0.5);
+	/// assert_eq!(days.jaccard_similarity(Weekdays::WEEKENDS),               0.0);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn jaccard_similarity(&self, other: Self) -> f64 {
+		let (common, total) = self.jaccard_ratio(other);
+		f64::from(common) / f64::from(total)
+	}
+	
 	//		iter																
 	/// Returns an iterator over the days of the week.
 	/// 
@@ -317,6 +665,190 @@ impl Weekdays {
 		}
 	}
 	
+	//		longest_run															
+	/// Returns the length of the longest run of consecutive days.
+	/// 
+	/// The week is treated as cyclic, so a run can wrap around from Sunday to
+	/// Monday. If no days are set, the result will be zero.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// assert_eq!(Weekdays::parse_const("mon-wed, fri").longest_run(), 3);
+	/// assert_eq!(Weekdays::parse_const("fri-mon, wed").longest_run(), 4);
+	/// assert_eq!(Weekdays::NONE.longest_run(),                         0);
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`runs()`](Self::runs())
+	/// 
+	#[must_use]
+	pub fn longest_run(&self) -> u8 {
+		self.runs().map(|(_, length)| length).max().unwrap_or(0)
+	}
+	
+	//		max_gap																
+	/// Returns the length of the longest gap between the days that are set.
+	/// 
+	/// The week is treated as cyclic, so a gap can wrap around from Sunday to
+	/// Monday. If no days are set, the whole week is one gap of seven days, and
+	/// if all days are set, the result will be zero.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// assert_eq!(Weekdays::parse_const("mon, thu").max_gap(), 3);
+	/// assert_eq!(Weekdays::WEEKDAYS.max_gap(),                2);
+	/// assert_eq!(Weekdays::ALL_DAYS.max_gap(),                0);
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`gaps()`](Self::gaps())
+	/// 
+	#[must_use]
+	pub fn max_gap(&self) -> u8 {
+		self.gaps().map(|(_, length)| length).max().unwrap_or(0)
+	}
+	
+	//		nearest																
+	/// Finds the candidates that are most similar to the set of days.
+	/// 
+	/// The candidates are ranked by the given similarity measure, from most to
+	/// least similar, and the top `k` are returned along with their positions
+	/// in the given slice. Candidates that are equally similar keep their
+	/// original order, so the results are stable.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `candidates` - The sets of days to search.
+	/// * `k`          - The maximum number of results to return.
+	/// * `similarity` - The measure to rank the candidates by.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Similarity, Weekdays};
+	/// 
+	/// let shift      = Weekdays::parse_const("mon-wed");
+	/// let volunteers = [
+	///     Weekdays::parse_const("sat-sun"),
+	///     Weekdays::parse_const("tue-thu"),
+	///     Weekdays::parse_const("mon-tue"),
+	///     Weekdays::parse_const("mon-fri"),
+	/// ];
+	/// 
+	/// assert_eq!(shift.nearest(&volunteers, 2, Similarity::Jaccard), vec![
+	///     (2, volunteers[2]),
+	///     (3, volunteers[3]),
+	/// ]);
+	/// assert_eq!(shift.nearest(&volunteers, 2, Similarity::Overlap), vec![
+	///     (3, volunteers[3]),
+	///     (1, volunteers[1]),
+	/// ]);
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Day counts are at most 7, so cannot overflow")]
+	#[must_use]
+	pub fn nearest(&self, candidates: &[Self], k: usize, similarity: Similarity) -> Vec<(usize, Self)> {
+		let mut ranked = candidates.iter().copied().enumerate().collect::<Vec<_>>();
+		//	The sort is stable, so equal candidates keep their original order
+		ranked.sort_by(|&(_, a), &(_, b)| match similarity {
+			Similarity::Hamming => self.hamming_distance(a).cmp(&self.hamming_distance(b)),
+			Similarity::Jaccard => {
+				//	Compare the ratios without division, to avoid rounding
+				let (common_a, total_a) = self.jaccard_ratio(a);
+				let (common_b, total_b) = self.jaccard_ratio(b);
+				(common_b * total_a).cmp(&(common_a * total_b))
+			},
+			Similarity::Overlap => self.overlap(b).cmp(&self.overlap(a)),
+		});
+		ranked.truncate(k);
+		ranked
+	}
+	
+	//		overlap																
+	/// Returns the number of days in common with another set of days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to compare against.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let days = Weekdays::parse_const("mon-wed");
+	/// assert_eq!(days.overlap(Weekdays::parse_const("tue-thu")), 2);
+	/// assert_eq!(days.overlap(Weekdays::WEEKENDS),               0);
+	/// ```
+	/// 
+	#[must_use]
+	pub const fn overlap(&self, other: Self) -> u8 {
+		Self(self.0 & other.0).days()
+	}
+	
+	//		runs																
+	/// Returns an iterator over the runs of consecutive days that are set.
+	/// 
+	/// The week is treated as cyclic, so a run can wrap around from Sunday to
+	/// Monday, such as Saturday to Monday being a single run of three days.
+	/// 
+	/// Each run is given as a pair of the first day of the run and the number of
+	/// days in it. The runs are yielded in order of their first day, starting
+	/// from Monday. If all days are set, there is a single run of seven days
+	/// starting on Monday.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let days = Weekdays::parse_const("mon, wed-thu, sat-sun");
+	/// let runs = days.runs().collect::<Vec<_>>();
+	/// 
+	/// assert_eq!(runs, vec![
+	///     (Weekdays::WEDNESDAY, 2),
+	///     (Weekdays::SATURDAY,  3),
+	/// ]);
+	/// ```
+	/// 
+	/// The runs can be used to produce range-collapsed output:
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// let days  = Weekdays::parse_const("mon-wed, fri");
+	/// let names = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+	/// let text  = days.runs().map(|(start, length)| {
+	///     let first = Weekdays::ALL_DAYS.iter().position(|day| day == start).unwrap();
+	///     let last  = (first + usize::from(length) - 1) % 7;
+	///     if length == 1 { names[first].to_owned() } else { format!("{}-{}", names[first], names[last]) }
+	/// }).collect::<Vec<_>>().join(", ");
+	/// 
+	/// assert_eq!(text, "Mon-Wed, Fri");
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`gaps()`](Self::gaps())
+	/// * [`is_contiguous()`](Self::is_contiguous())
+	/// * [`longest_run()`](Self::longest_run())
+	/// 
+	#[must_use]
+	pub const fn runs(&self) -> WeekdaysRuns {
+		WeekdaysRuns {
+			days:     self.0,
+			position: 0,
+		}
+	}
+	
 	//		to_chrono_vec														
 	/// Converts the set of days to a [`Vec`] of Chrono [`Weekday`]s.
 	/// 
@@ -360,6 +892,41 @@ impl Weekdays {
 			.collect()
 	}
 	
+	//		to_layout															
+	/// Converts the set of days to a value in another bit layout.
+	/// 
+	/// This converts into the day-of-week bitmask convention used by another
+	/// platform, such as Windows Task Scheduler or JavaScript. The layout used
+	/// by this crate, as given to [`Weekdays::new()`], is not affected.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `layout` - The layout to convert to.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, layout::BitLayout};
+	/// 
+	/// assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::WindowsSunday1), 0b000_0010);
+	/// assert_eq!(Weekdays::SUNDAY.to_layout(BitLayout::MySql),          0b000_0010);
+	/// assert_eq!(Weekdays::WEEKDAYS.to_layout(BitLayout::ReversedIso),  0b001_1111);
+	/// assert_eq!(Weekdays::WEEKDAYS.to_layout(BitLayout::Native),       0b111_1100);
+	/// ```
+	/// 
+	/// # See also
+	/// 
+	/// * [`Weekdays::from_layout()`]
+	/// 
+	#[must_use]
+	pub fn to_layout(&self, layout: BitLayout) -> u32 {
+		Self::ALL_DAYS
+			.iter()
+			.zip(layout.positions())
+			.filter(|&(day, _)| self.contains(day))
+			.fold(0, |bits, (_, position)| bits | 1_u32.checked_shl(u32::from(position)).unwrap_or(0))
+	}
+	
 	//		to_vec																
 	/// Converts the set of days to a [`Vec`] of days.
 	/// 
@@ -389,6 +956,216 @@ impl Weekdays {
 	pub fn to_vec(&self) -> Vec<Self> {
 		self.iter().collect()
 	}
+	
+	//		union																
+	/// Combines the set of days with another set of days.
+	/// 
+	/// This is the same as using the `|` operator, but is available in `const`
+	/// contexts.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to add.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::Weekdays;
+	/// 
+	/// const WEEKENDS: Weekdays = Weekdays::SATURDAY.union(Weekdays::SUNDAY);
+	/// assert_eq!(WEEKENDS, Weekdays::WEEKENDS);
+	/// ```
+	/// 
+	#[must_use]
+	pub const fn union(&self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+	
+	//		Private methods														
+	
+	//		first_index															
+	/// Returns the position of the earliest day in the set, from zero for
+	/// Monday to six for Sunday.
+	/// 
+	/// If the set of days is empty, [`None`] is returned.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Non-empty values always have a leading zero")]
+	const fn first_index(self) -> Option<usize> {
+		if self.0 == 0 {
+			None
+		} else {
+			Some((self.0.leading_zeros() - 1) as usize)
+		}
+	}
+	
+	//		from_index															
+	/// Returns the day at the given position, from zero for Monday to six for
+	/// Sunday.
+	/// 
+	/// If the position is outside the week, [`NONE`](Self::NONE) is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index` - The position of the day.
+	/// 
+	fn from_index(index: usize) -> Self {
+		Self::ALL_DAYS.iter().nth(index).unwrap_or(Self::NONE)
+	}
+	
+	//		from_name															
+	/// Parses a single day name, in full or as a three-letter abbreviation,
+	/// without regard to case.
+	/// 
+	/// Unlike [`parse_bytes()`](Self::parse_bytes()), the name must match
+	/// exactly, without surrounding whitespace, and ranges are not accepted.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `name` - The day name.
+	/// 
+	fn from_name(name: &str) -> Option<Self> {
+		Self::ALL_DAYS.iter()
+			.zip(Self::NAMES)
+			.find(|&(_, full)| {
+				name.eq_ignore_ascii_case(full) || full.get(..3).is_some_and(|short| name.eq_ignore_ascii_case(short))
+			})
+			.map(|(day, _)| day)
+	}
+	
+	//		jaccard_ratio														
+	/// Returns the parts of the Jaccard similarity with another set of days.
+	/// 
+	/// The result is a pair of the number of days in common and the number of
+	/// days in either set. Two empty sets give `(1, 1)`, so that the ratio is
+	/// always defined.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The days to compare against.
+	/// 
+	const fn jaccard_ratio(self, other: Self) -> (u8, u8) {
+		let total = Self(self.0 | other.0).days();
+		if total == 0 {
+			return (1, 1);
+		}
+		(self.overlap(other), total)
+	}
+	
+	//		parse_bytes															
+	/// Parses a textual specification of days from bytes.
+	/// 
+	/// This is the implementation behind [`parse_const()`](Self::parse_const),
+	/// and returns [`None`] if the specification is invalid.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bytes` - The specification to parse.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions are bounded by the slice length")]
+	#[expect(clippy::indexing_slicing,        reason = "Positions are checked against the slice length")]
+	const fn parse_bytes(bytes: &[u8]) -> Option<Self> {
+		let (first, last) = Self::trim(bytes, 0, bytes.len());
+		if first == last {
+			return Some(Self::NONE);
+		}
+		let mut days  = Self::NONE;
+		let mut start = 0;
+		let mut pos   = 0;
+		while pos <= bytes.len() {
+			if pos == bytes.len() || bytes[pos] == b',' {
+				match Self::parse_entry(bytes, start, pos) {
+					Some(entry) => days = days.union(entry),
+					None        => return None,
+				}
+				start = pos + 1;
+			}
+			pos += 1;
+		}
+		Some(days)
+	}
+	
+	//		parse_entry															
+	/// Parses a single day name or day range from part of a specification.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bytes` - The full specification.
+	/// * `start` - The start position of the entry, inclusive.
+	/// * `end`   - The end position of the entry, exclusive.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions are bounded by the slice length")]
+	#[expect(clippy::indexing_slicing,        reason = "Positions are checked against the slice length")]
+	const fn parse_entry(bytes: &[u8], start: usize, end: usize) -> Option<Self> {
+		let mut pos = start;
+		while pos < end {
+			if bytes[pos] == b'-' {
+				return match (Self::parse_name(bytes, start, pos), Self::parse_name(bytes, pos + 1, end)) {
+					(Some(from), Some(to)) => Some(Self::range(from, to)),
+					_                      => None,
+				};
+			}
+			pos += 1;
+		}
+		Self::parse_name(bytes, start, end)
+	}
+	
+	//		parse_name															
+	/// Parses a single day name from part of a specification.
+	/// 
+	/// Names are matched case-insensitively, in full or as three-letter
+	/// abbreviations, after trimming surrounding whitespace.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bytes` - The full specification.
+	/// * `start` - The start position of the name, inclusive.
+	/// * `end`   - The end position of the name, exclusive.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions are bounded by the slice length")]
+	#[expect(clippy::indexing_slicing,        reason = "Positions are checked against the slice length")]
+	const fn parse_name(bytes: &[u8], start: usize, end: usize) -> Option<Self> {
+		let (first, last) = Self::trim(bytes, start, end);
+		let length        = last - first;
+		let mut index     = 0;
+		while index < Self::NAMES.len() {
+			let name = Self::NAMES[index].as_bytes();
+			if length == 3 || length == name.len() {
+				let mut pos = 0;
+				while pos < length && bytes[first + pos].to_ascii_lowercase() == name[pos] {
+					pos += 1;
+				}
+				if pos == length {
+					return Some(Self(0b100_0000 >> index));
+				}
+			}
+			index += 1;
+		}
+		None
+	}
+	
+	//		trim																
+	/// Finds the bounds of part of a specification without surrounding
+	/// whitespace.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `bytes` - The full specification.
+	/// * `start` - The start position to trim from, inclusive.
+	/// * `end`   - The end position to trim from, exclusive.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions are bounded by the slice length")]
+	#[expect(clippy::indexing_slicing,        reason = "Positions are checked against the slice length")]
+	const fn trim(bytes: &[u8], start: usize, end: usize) -> (usize, usize) {
+		let mut first = start;
+		let mut last  = end;
+		while first < last && bytes[first].is_ascii_whitespace() {
+			first += 1;
+		}
+		while last > first && bytes[last - 1].is_ascii_whitespace() {
+			last -= 1;
+		}
+		(first, last)
+	}
 }
 
 //󰭅		Add																		
@@ -655,3 +1432,149 @@ impl Iterator for WeekdaysIter {
 		None
 	}
 }
+
+//		WeekdaysRuns															
+/// An iterator over the runs of consecutive days of the week.
+/// 
+/// Each item is a pair of the first day of the run and the number of days in
+/// the run. The week is treated as cyclic, so runs can wrap around from Sunday
+/// to Monday.
+/// 
+/// # See also
+/// 
+/// * [`Weekdays::gaps()`]
+/// * [`Weekdays::runs()`]
+/// 
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WeekdaysRuns {
+	/// The days to find runs in.
+	days:     u8,
+	
+	/// The current position in the iteration.
+	position: u8,
+}
+
+//󰭅		WeekdaysRuns															
+impl WeekdaysRuns {
+	//		Private methods														
+	
+	//		is_set																
+	/// Checks if the day at the given position is set.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `position` - The position of the day, from zero for Monday, wrapping
+	///   around so that seven is Monday again.
+	/// 
+	const fn is_set(&self, position: u8) -> bool {
+		self.days & (0b100_0000 >> (position % 7)) != 0
+	}
+}
+
+//󰭅		Iterator																
+impl Iterator for WeekdaysRuns {
+	type Item = (Weekdays, u8);
+	
+	//		next																
+	#[expect(clippy::arithmetic_side_effects, reason = "Positions and lengths are bounded by the week")]
+	fn next(&mut self) -> Option<Self::Item> {
+		//	A full week has no start, so is a single run from Monday
+		if self.days == Weekdays::ALL_DAYS_MASK {
+			if self.position > 0 {
+				return None;
+			}
+			self.position = 7;
+			return Some((Weekdays::MONDAY, 7));
+		}
+		while self.position < 7 {
+			let position  = self.position;
+			self.position += 1;
+			
+			//	A run starts on a set day that follows an unset day
+			if self.is_set(position) && !self.is_set(position + 6) {
+				let mut length = 1;
+				while self.is_set(position + length) {
+					length += 1;
+				}
+				return Some((Weekdays(0b100_0000 >> position), length));
+			}
+		}
+		None
+	}
+}
+
+
+
+//		Functions
+
+//		date																	
+/// Creates a date from the given year, month, and day, panicking if it is
+/// invalid.
+/// 
+/// This is shared by the unit tests of the submodules.
+/// 
+#[cfg(all(test, feature = "chrono"))]
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+	NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+//		format_clock															
+/// Formats a number of minutes from midnight as a time, such as `08:30`.
+/// 
+/// Times after midnight at the end of the day are written with hours past 24,
+/// such as `26:00`, so callers that need times on the following day must
+/// subtract a day first.
+/// 
+/// # Parameters
+/// 
+/// * `minute` - The number of minutes from midnight.
+/// 
+#[expect(clippy::integer_division, reason = "Whole hours are needed")]
+fn format_clock(minute: u16) -> String {
+	format!("{:02}:{:02}", minute / 60, minute % 60)
+}
+
+//		format_runs																
+/// Formats a set of days as a comma-separated list of names, writing runs of
+/// three or more days as ranges.
+/// 
+/// Runs are split at the end of the week rather than wrapping round, so the
+/// days are always listed from Monday to Sunday. If no days are set, the
+/// result is empty.
+/// 
+/// # Parameters
+/// 
+/// * `days`      - The days to format.
+/// * `names`     - The day names, from Monday to Sunday.
+/// * `separator` - The separator between the first and last days of a range.
+/// 
+fn format_runs(days: Weekdays, names: [&str; 7], separator: &str) -> String {
+	let mut runs = days.runs()
+		.filter_map(|(start, length)| Some((start.first_index()?, start.first_index()?.saturating_add(usize::from(length)))))
+		.flat_map(|(first, end)| [(first, end.min(7)), (0, end.saturating_sub(7))])
+		.filter(|&(first, end)| first < end)
+		.collect::<Vec<_>>();
+	runs.sort_unstable();
+	runs.into_iter()
+		.filter_map(|(first, end)| names.get(first..end))
+		.map(|run| match *run {
+			[first, _, .., last] => format!("{first}{separator}{last}"),
+			_                    => run.join(","),
+		})
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+//		parse																	
+/// Parses a value, panicking if it is invalid.
+/// 
+/// This is shared by the unit tests of the submodules.
+/// 
+#[cfg(test)]
+fn parse<T>(source: &str) -> T
+	where
+		T:      FromStr,
+		T::Err: Debug,
+{
+	source.parse().unwrap()
+}
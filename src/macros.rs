@@ -0,0 +1,74 @@
+//! Macros for building sets of days.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/macros.rs"]
+mod tests;
+
+
+
+//		Macros
+
+//		weekdays!																
+/// Creates a [`Weekdays`](crate::Weekdays) value from a list of days.
+/// 
+/// The list is made up of day names and inclusive day ranges, separated by
+/// commas. Days can be given as three-letter abbreviations or in full, such as
+/// `Mon` or `Monday`, and ranges are written as `Wed..=Fri`. As with
+/// [`Weekdays::range()`](crate::Weekdays::range()), ranges are cyclic, so
+/// `Sat..=Mon` covers Saturday, Sunday, and Monday.
+/// 
+/// The result is a constant expression, so the macro can be used to define
+/// constants. Unknown day names fail to compile.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, weekdays};
+/// 
+/// const ROTA: Weekdays = weekdays!(Mon, Wed..=Fri, Sun);
+/// assert_eq!(ROTA, Weekdays::new(0b101_1101));
+/// 
+/// assert_eq!(weekdays!(Monday..=Friday), Weekdays::WEEKDAYS);
+/// assert_eq!(weekdays!(Sat, Sun),        Weekdays::WEEKENDS);
+/// assert_eq!(weekdays!(),                Weekdays::NONE);
+/// ```
+/// 
+/// ```compile_fail
+/// use weekdays::weekdays;
+/// 
+/// let days = weekdays!(Mon, Fry);
+/// ```
+/// 
+#[macro_export]
+macro_rules! weekdays {
+	(@day Mon)       => { $crate::Weekdays::MONDAY    };
+	(@day Monday)    => { $crate::Weekdays::MONDAY    };
+	(@day Tue)       => { $crate::Weekdays::TUESDAY   };
+	(@day Tuesday)   => { $crate::Weekdays::TUESDAY   };
+	(@day Wed)       => { $crate::Weekdays::WEDNESDAY };
+	(@day Wednesday) => { $crate::Weekdays::WEDNESDAY };
+	(@day Thu)       => { $crate::Weekdays::THURSDAY  };
+	(@day Thursday)  => { $crate::Weekdays::THURSDAY  };
+	(@day Fri)       => { $crate::Weekdays::FRIDAY    };
+	(@day Friday)    => { $crate::Weekdays::FRIDAY    };
+	(@day Sat)       => { $crate::Weekdays::SATURDAY  };
+	(@day Saturday)  => { $crate::Weekdays::SATURDAY  };
+	(@day Sun)       => { $crate::Weekdays::SUNDAY    };
+	(@day Sunday)    => { $crate::Weekdays::SUNDAY    };
+	(@entry $day:ident) => {
+		$crate::weekdays!(@day $day)
+	};
+	(@entry $start:ident ..= $end:ident) => {
+		$crate::Weekdays::range($crate::weekdays!(@day $start), $crate::weekdays!(@day $end))
+	};
+	() => {
+		$crate::Weekdays::NONE
+	};
+	($($start:ident $(..= $end:ident)?),+ $(,)?) => {
+		$crate::Weekdays::NONE $(.union($crate::weekdays!(@entry $start $(..= $end)?)))+
+	};
+}
@@ -0,0 +1,444 @@
+//! Values keyed by day of the week.
+//! 
+//! The main type provided is the [`WeekdaysMap`] struct, which holds an optional
+//! value for each day of the week.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/map.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+};
+
+#[cfg(feature = "serde")]
+use ::{
+	core::marker::PhantomData,
+	serde::{
+		Deserialize,
+		Deserializer,
+		Serialize,
+		Serializer,
+		de::{Error as DeError, MapAccess, Visitor},
+		ser::SerializeMap,
+	},
+};
+
+
+
+//		Enums
+
+//		MapError																
+/// The errors that can occur when changing a [`WeekdaysMap`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MapError {
+	/// A value was given for no days, or for more than one day, where a single
+	/// day was expected.
+	NotSingleDay(Weekdays),
+}
+
+//󰭅		Display																	
+impl Display for MapError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::NotSingleDay(days) => write!(f, "Expected a single day, found {} days", days.days()),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for MapError {}
+
+
+
+//		Structs
+
+//		WeekdaysMap																
+/// A fixed-size map holding an optional value for each day of the week.
+/// 
+/// This is a replacement for keeping a `[T; 7]` array alongside a [`Weekdays`]
+/// value, such as for opening hours, quotas, or prices per day. Entries are
+/// keyed by day, and are always iterated over in week order, from Monday to
+/// Sunday.
+/// 
+/// Days are given as [`Weekdays`] values. Where a lookup expects a single day
+/// but is given more than one, the earliest day in the week is used, in the
+/// same way as [`Weekdays::range()`]. Values are set for a single day with
+/// [`insert()`](Self::insert()), or for several days at once with
+/// [`insert_all()`](Self::insert_all()), which is also how maps are collected
+/// from pairs of days and values.
+/// 
+/// When the `serde` feature is enabled, the map is serialised as an object
+/// keyed by the lowercase day names, such as `{"monday": 1, "friday": 2}`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, map::WeekdaysMap};
+/// 
+/// let mut hours = WeekdaysMap::new();
+/// hours.insert_all(Weekdays::WEEKDAYS, "09:00-17:00");
+/// hours.insert(Weekdays::SATURDAY, "10:00-14:00").unwrap();
+/// 
+/// assert_eq!(hours.get(Weekdays::MONDAY), Some(&"09:00-17:00"));
+/// assert_eq!(hours.get(Weekdays::SUNDAY), None);
+/// assert_eq!(hours.keys(),                Weekdays::parse_const("mon-sat"));
+/// assert_eq!(hours.groups(), vec![
+///     (Weekdays::WEEKDAYS, &"09:00-17:00"),
+///     (Weekdays::SATURDAY, &"10:00-14:00"),
+/// ]);
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WeekdaysMap<T> {
+	/// The entries for each day, from Monday to Sunday.
+	entries: [Option<T>; 7],
+}
+
+//󰭅		WeekdaysMap																
+impl<T> WeekdaysMap<T> {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new, empty [`WeekdaysMap`].
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			entries: [None, None, None, None, None, None, None],
+		}
+	}
+	
+	//		Public methods														
+	
+	//		clear																
+	/// Removes all entries from the map.
+	pub fn clear(&mut self) {
+		self.entries = Self::new().entries;
+	}
+	
+	//		filter_by															
+	/// Keeps only the entries for the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to keep.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, map::WeekdaysMap};
+	/// 
+	/// let quotas   = WeekdaysMap::from([(Weekdays::MONDAY, 10), (Weekdays::SUNDAY, 5)]);
+	/// let weekends = quotas.filter_by(Weekdays::WEEKENDS);
+	/// 
+	/// assert_eq!(weekends.keys(), Weekdays::SUNDAY);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn filter_by(self, days: Weekdays) -> Self {
+		let mut entries = self.entries;
+		for (day, entry) in Weekdays::ALL_DAYS.iter().zip(entries.iter_mut()) {
+			if !days.contains(day) {
+				*entry = None;
+			}
+		}
+		Self { entries }
+	}
+	
+	//		get																	
+	/// Returns a reference to the value for the given day, if there is one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn get(&self, day: Weekdays) -> Option<&T> {
+		day.first_index().and_then(|index| self.entries.get(index)).and_then(Option::as_ref)
+	}
+	
+	//		get_mut																
+	/// Returns a mutable reference to the value for the given day, if there is
+	/// one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn get_mut(&mut self, day: Weekdays) -> Option<&mut T> {
+		day.first_index().and_then(|index| self.entries.get_mut(index)).and_then(Option::as_mut)
+	}
+	
+	//		groups																
+	/// Groups together the days that have equal values.
+	/// 
+	/// Each group is a pair of the days in the group and their shared value.
+	/// The groups are ordered by their earliest day. This is useful for
+	/// producing summaries such as "Mon–Fri: 9–5".
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, map::WeekdaysMap};
+	/// 
+	/// let prices = WeekdaysMap::from([
+	///     (Weekdays::MONDAY,   5),
+	///     (Weekdays::TUESDAY,  8),
+	///     (Weekdays::SATURDAY, 5),
+	/// ]);
+	/// 
+	/// assert_eq!(prices.groups(), vec![
+	///     (Weekdays::parse_const("mon, sat"), &5),
+	///     (Weekdays::TUESDAY,                 &8),
+	/// ]);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn groups(&self) -> Vec<(Weekdays, &T)>
+	where
+		T: PartialEq,
+	{
+		let mut groups: Vec<(Weekdays, &T)> = vec![];
+		for (day, value) in self.iter() {
+			match groups.iter_mut().find(|&&mut (_, existing)| existing == value) {
+				Some(&mut (ref mut days, _)) => *days |= day,
+				None                         => groups.push((day, value)),
+			}
+		}
+		groups
+	}
+	
+	//		insert																
+	/// Sets the value for the given day.
+	/// 
+	/// If there was already a value for the day, it is returned. To set the
+	/// same value for several days, use [`insert_all()`](Self::insert_all()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day`   - The day to set.
+	/// * `value` - The value to set.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`MapError::NotSingleDay`] if no days or more than one day are
+	/// given, in which case the map is not changed.
+	/// 
+	pub fn insert(&mut self, day: Weekdays, value: T) -> Result<Option<T>, MapError> {
+		match day.first_index().and_then(|index| self.entries.get_mut(index)) {
+			Some(entry) if day.days() == 1 => Ok(entry.replace(value)),
+			_                              => Err(MapError::NotSingleDay(day)),
+		}
+	}
+	
+	//		insert_all															
+	/// Sets the value for each of the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to set.
+	/// * `value` - The value to set.
+	/// 
+	pub fn insert_all(&mut self, days: Weekdays, value: T)
+	where
+		T: Clone,
+	{
+		for (day, entry) in Weekdays::ALL_DAYS.iter().zip(self.entries.iter_mut()) {
+			if days.contains(day) {
+				*entry = Some(value.clone());
+			}
+		}
+	}
+	
+	//		is_empty															
+	/// Checks if the map has no entries.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.iter().all(Option::is_none)
+	}
+	
+	//		iter																
+	/// Returns an iterator over the entries, in week order.
+	/// 
+	/// Each item is a pair of the day and a reference to its value. Days
+	/// without values are skipped.
+	/// 
+	pub fn iter(&self) -> impl Iterator<Item = (Weekdays, &T)> {
+		Weekdays::ALL_DAYS
+			.iter()
+			.zip(self.entries.iter())
+			.filter_map(|(day, entry)| entry.as_ref().map(|value| (day, value)))
+	}
+	
+	//		iter_mut															
+	/// Returns an iterator over the entries, in week order, with mutable
+	/// references to the values.
+	/// 
+	/// Each item is a pair of the day and a mutable reference to its value.
+	/// Days without values are skipped.
+	/// 
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = (Weekdays, &mut T)> {
+		Weekdays::ALL_DAYS
+			.iter()
+			.zip(self.entries.iter_mut())
+			.filter_map(|(day, entry)| entry.as_mut().map(|value| (day, value)))
+	}
+	
+	//		keys																
+	/// Returns the days that have values.
+	#[must_use]
+	pub fn keys(&self) -> Weekdays {
+		self.iter().fold(Weekdays::NONE, |days, (day, _)| days | day)
+	}
+	
+	//		len																	
+	/// Returns the number of entries in the map.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.iter().filter(|entry| entry.is_some()).count()
+	}
+	
+	//		map																	
+	/// Converts each value in the map, keeping the same days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `f` - The function to apply to each value.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, map::WeekdaysMap};
+	/// 
+	/// let quotas  = WeekdaysMap::from([(Weekdays::MONDAY, 10), (Weekdays::SUNDAY, 5)]);
+	/// let doubled = quotas.map(|quota| quota * 2);
+	/// 
+	/// assert_eq!(doubled.get(Weekdays::MONDAY), Some(&20));
+	/// assert_eq!(doubled.get(Weekdays::SUNDAY), Some(&10));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn map<U, F>(self, mut f: F) -> WeekdaysMap<U>
+	where
+		F: FnMut(T) -> U,
+	{
+		WeekdaysMap {
+			entries: self.entries.map(|entry| entry.map(&mut f)),
+		}
+	}
+	
+	//		remove																
+	/// Removes the value for the given day, returning it if there was one.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to clear.
+	/// 
+	pub fn remove(&mut self, day: Weekdays) -> Option<T> {
+		day.first_index().and_then(|index| self.entries.get_mut(index)).and_then(Option::take)
+	}
+	
+	//		values																
+	/// Returns an iterator over the values, in week order.
+	pub fn values(&self) -> impl Iterator<Item = &T> {
+		self.entries.iter().flatten()
+	}
+}
+
+//󰭅		Default																	
+impl<T> Default for WeekdaysMap<T> {
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//󰭅		Deserialize																
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for WeekdaysMap<T> {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(WeekdaysMapVisitor(PhantomData))
+	}
+}
+
+//󰭅		From: [(Weekdays, T); N] -> WeekdaysMap									
+impl<T: Clone, const N: usize> From<[(Weekdays, T); N]> for WeekdaysMap<T> {
+	//		from																
+	fn from(entries: [(Weekdays, T); N]) -> Self {
+		entries.into_iter().collect()
+	}
+}
+
+//󰭅		FromIterator															
+impl<T: Clone> FromIterator<(Weekdays, T)> for WeekdaysMap<T> {
+	//		from_iter															
+	fn from_iter<I: IntoIterator<Item = (Weekdays, T)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		for (days, value) in iter {
+			map.insert_all(days, value);
+		}
+		map
+	}
+}
+
+//󰭅		Serialize																
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for WeekdaysMap<T> {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut map = serializer.serialize_map(Some(self.len()))?;
+		for (name, entry) in Weekdays::NAMES.iter().zip(self.entries.iter()) {
+			if let Some(ref value) = *entry {
+				map.serialize_entry(name, value)?;
+			}
+		}
+		map.end()
+	}
+}
+
+//		WeekdaysMapVisitor														
+/// A visitor for deserialising a [`WeekdaysMap`].
+#[cfg(feature = "serde")]
+struct WeekdaysMapVisitor<T>(PhantomData<T>);
+
+//󰭅		Visitor																	
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Visitor<'de> for WeekdaysMapVisitor<T> {
+	type Value = WeekdaysMap<T>;
+	
+	//		expecting															
+	fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+		formatter.write_str("a map keyed by day names")
+	}
+	
+	//		visit_map															
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+		where A: MapAccess<'de>,
+	{
+		let mut days_map = WeekdaysMap::new();
+		while let Some(name) = map.next_key::<String>()? {
+			let day = Weekdays::from_name(&name).ok_or_else(|| DeError::custom(format!("invalid day name: {name}")))?;
+			if !matches!(days_map.insert(day, map.next_value()?), Ok(None)) {
+				return Err(DeError::custom(format!("duplicate day: {name}")));
+			}
+		}
+		Ok(days_map)
+	}
+}
@@ -0,0 +1,403 @@
+//! Recurrence patterns spanning more than one week.
+//! 
+//! The main type provided is the [`MultiWeekPattern`] struct, which holds a set
+//! of days for each week of a repeating cycle of weeks.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/multiweek.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use chrono::{Datelike, Days, NaiveDate};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, de::Error as DeError};
+
+
+
+//		Structs
+
+//		MultiWeekPattern														
+/// A recurrence pattern that repeats over a cycle of several weeks.
+/// 
+/// A single [`Weekdays`] value can only express a pattern that repeats every
+/// week. Many real-world schedules, such as fortnightly bin collections, A/B
+/// school timetables, and shift rotas, repeat over two or more weeks instead.
+/// This struct holds one [`Weekdays`] value for each week of the cycle.
+/// 
+/// The cycle is anchored to a reference date, and the week containing that
+/// date is the first week of the cycle. Weeks run from Monday to Sunday, so
+/// the anchor is always stored as the Monday of its week. The cycle repeats
+/// both forwards and backwards in time from the anchor.
+/// 
+/// When the `serde` feature is enabled, the pattern is serialised as an object
+/// with the `anchor` date and the list of `weeks`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveDate;
+/// use weekdays::{Weekdays, multiweek::MultiWeekPattern};
+/// 
+/// // Bins are collected on Tuesdays, every other week
+/// let anchor  = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let pattern = MultiWeekPattern::new(anchor, vec![Weekdays::TUESDAY, Weekdays::NONE]).unwrap();
+/// 
+/// assert!( pattern.contains_date(NaiveDate::from_ymd_opt(2024, 1, 16).unwrap()));
+/// assert!(!pattern.contains_date(NaiveDate::from_ymd_opt(2024, 1,  9).unwrap()));
+/// assert_eq!(
+///     pattern.next_occurrence(NaiveDate::from_ymd_opt(2024, 1, 3).unwrap()),
+///     NaiveDate::from_ymd_opt(2024, 1, 16),
+/// );
+/// ```
+/// 
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MultiWeekPattern {
+	/// The Monday of the first week of the cycle.
+	anchor: NaiveDate,
+	
+	/// The days for each week of the cycle, in order. This is never empty.
+	weeks:  Vec<Weekdays>,
+}
+
+//󰭅		MultiWeekPattern														
+impl MultiWeekPattern {
+	//		Private constants													
+	/// The number of bits used to encode each week.
+	const BITS_PER_WEEK: usize = 7;
+	
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`MultiWeekPattern`] from the days for each week.
+	/// 
+	/// The anchor can be any date in the first week of the cycle, and will be
+	/// moved back to the Monday of that week.
+	/// 
+	/// Returns [`None`] if no weeks are given, or if the Monday of the anchor's
+	/// week is outside the range of supported dates.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `anchor` - A date in the first week of the cycle.
+	/// * `weeks`  - The days for each week of the cycle, in order.
+	/// 
+	#[must_use]
+	pub fn new(anchor: NaiveDate, weeks: Vec<Weekdays>) -> Option<Self> {
+		if weeks.is_empty() {
+			return None;
+		}
+		let monday = anchor.checked_sub_days(Days::new(u64::from(anchor.weekday().num_days_from_monday())))?;
+		Some(Self { anchor: monday, weeks })
+	}
+	
+	//		from_u16															
+	/// Creates a new [`MultiWeekPattern`] from a compact [`u16`] encoding.
+	/// 
+	/// This is the inverse of [`to_u16()`](Self::to_u16()), and can hold up to
+	/// two weeks.
+	/// 
+	/// Returns [`None`] if the number of weeks is zero or too large, or if any
+	/// bits are set beyond those used by the weeks.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `anchor` - A date in the first week of the cycle.
+	/// * `weeks`  - The number of weeks in the cycle.
+	/// * `bits`   - The encoded days.
+	/// 
+	#[must_use]
+	pub fn from_u16(anchor: NaiveDate, weeks: usize, bits: u16) -> Option<Self> {
+		Self::decode(anchor, weeks, u64::from(bits), 2)
+	}
+	
+	//		from_u32															
+	/// Creates a new [`MultiWeekPattern`] from a compact [`u32`] encoding.
+	/// 
+	/// This is the inverse of [`to_u32()`](Self::to_u32()), and can hold up to
+	/// four weeks.
+	/// 
+	/// Returns [`None`] if the number of weeks is zero or too large, or if any
+	/// bits are set beyond those used by the weeks.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `anchor` - A date in the first week of the cycle.
+	/// * `weeks`  - The number of weeks in the cycle.
+	/// * `bits`   - The encoded days.
+	/// 
+	#[must_use]
+	pub fn from_u32(anchor: NaiveDate, weeks: usize, bits: u32) -> Option<Self> {
+		Self::decode(anchor, weeks, u64::from(bits), 4)
+	}
+	
+	//		from_u64															
+	/// Creates a new [`MultiWeekPattern`] from a compact [`u64`] encoding.
+	/// 
+	/// This is the inverse of [`to_u64()`](Self::to_u64()), and can hold up to
+	/// nine weeks.
+	/// 
+	/// Returns [`None`] if the number of weeks is zero or too large, or if any
+	/// bits are set beyond those used by the weeks.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `anchor` - A date in the first week of the cycle.
+	/// * `weeks`  - The number of weeks in the cycle.
+	/// * `bits`   - The encoded days.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::{Weekdays, multiweek::MultiWeekPattern};
+	/// 
+	/// let anchor  = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+	/// let pattern = MultiWeekPattern::from_u64(anchor, 2, 0b1111100_0000011).unwrap();
+	/// 
+	/// assert_eq!(pattern.weeks(), &[Weekdays::WEEKDAYS, Weekdays::WEEKENDS]);
+	/// assert_eq!(pattern.to_u64(), Some(0b1111100_0000011));
+	/// ```
+	/// 
+	#[must_use]
+	pub fn from_u64(anchor: NaiveDate, weeks: usize, bits: u64) -> Option<Self> {
+		Self::decode(anchor, weeks, bits, 9)
+	}
+	
+	//		Public methods														
+	
+	//		anchor																
+	/// Returns the Monday of the first week of the cycle.
+	#[must_use]
+	pub const fn anchor(&self) -> NaiveDate {
+		self.anchor
+	}
+	
+	//		contains_date														
+	/// Checks if the pattern includes the given date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - The date to check.
+	/// 
+	#[must_use]
+	pub fn contains_date(&self, date: NaiveDate) -> bool {
+		self.week_of(date).contains(Weekdays::from(date.weekday()))
+	}
+	
+	//		count_occurrences													
+	/// Counts the dates in the given range that are included in the pattern.
+	/// 
+	/// The range is half-open, so includes `start` but not `end`. Whole cycles
+	/// are counted without visiting each date, so long ranges are cheap.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `start` - The first date in the range.
+	/// * `end`   - The date after the last date in the range.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::{Weekdays, multiweek::MultiWeekPattern};
+	/// 
+	/// let anchor  = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+	/// let pattern = MultiWeekPattern::new(anchor, vec![Weekdays::MONDAY, Weekdays::NONE]).unwrap();
+	/// 
+	/// assert_eq!(pattern.count_occurrences(anchor, NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()), 27);
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Counts are bounded by the number of days in range")]
+	#[expect(clippy::integer_division,        reason = "Only whole cycles are wanted")]
+	#[must_use]
+	pub fn count_occurrences(&self, start: NaiveDate, end: NaiveDate) -> u64 {
+		let Ok(total) = u64::try_from(end.signed_duration_since(start).num_days()) else {
+			return 0;
+		};
+		let cycle_days = self.cycle_days();
+		let per_cycle  = self.weeks.iter().map(|week| u64::from(week.days())).sum::<u64>();
+		let remainder  = total % cycle_days;
+		let partial    = start
+			.checked_add_days(Days::new(total - remainder))
+			.map_or(0, |from| {
+				from.iter_days()
+					.take(usize::try_from(remainder).unwrap_or(usize::MAX))
+					.filter(|&date| self.contains_date(date))
+					.count()
+			});
+		total / cycle_days * per_cycle + partial as u64
+	}
+	
+	//		cycle_len															
+	/// Returns the number of weeks in the cycle.
+	#[must_use]
+	pub fn cycle_len(&self) -> usize {
+		self.weeks.len()
+	}
+	
+	//		next_occurrence														
+	/// Returns the first date on or after the given date that is included in
+	/// the pattern.
+	/// 
+	/// If the pattern has no days at all, or the next occurrence would be
+	/// beyond the range of supported dates, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `from` - The date to start searching from.
+	/// 
+	#[must_use]
+	pub fn next_occurrence(&self, from: NaiveDate) -> Option<NaiveDate> {
+		//	Any occurrence must fall within one full cycle
+		from.iter_days()
+			.take(usize::try_from(self.cycle_days()).unwrap_or(usize::MAX))
+			.find(|&date| self.contains_date(date))
+	}
+	
+	//		to_u16																
+	/// Encodes the pattern as a compact [`u16`].
+	/// 
+	/// The weeks are packed together using the same bit ordering as
+	/// [`Weekdays`], with the first week in the most significant bits. The
+	/// anchor and number of weeks are not included.
+	/// 
+	/// If the cycle is longer than two weeks, [`None`] is returned.
+	/// 
+	#[must_use]
+	pub fn to_u16(&self) -> Option<u16> {
+		self.encode(2).and_then(|bits| u16::try_from(bits).ok())
+	}
+	
+	//		to_u32																
+	/// Encodes the pattern as a compact [`u32`].
+	/// 
+	/// The weeks are packed together using the same bit ordering as
+	/// [`Weekdays`], with the first week in the most significant bits. The
+	/// anchor and number of weeks are not included.
+	/// 
+	/// If the cycle is longer than four weeks, [`None`] is returned.
+	/// 
+	#[must_use]
+	pub fn to_u32(&self) -> Option<u32> {
+		self.encode(4).and_then(|bits| u32::try_from(bits).ok())
+	}
+	
+	//		to_u64																
+	/// Encodes the pattern as a compact [`u64`].
+	/// 
+	/// The weeks are packed together using the same bit ordering as
+	/// [`Weekdays`], with the first week in the most significant bits. The
+	/// anchor and number of weeks are not included.
+	/// 
+	/// If the cycle is longer than nine weeks, [`None`] is returned.
+	/// 
+	#[must_use]
+	pub fn to_u64(&self) -> Option<u64> {
+		self.encode(9)
+	}
+	
+	//		week_of																
+	/// Returns the days for the week of the cycle that contains the given date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - Any date in the week to look up.
+	/// 
+	#[expect(clippy::cast_possible_truncation, reason = "Index is always less than the number of weeks")]
+	#[expect(clippy::cast_possible_wrap,       reason = "Number of weeks cannot realistically exceed i64::MAX")]
+	#[expect(clippy::indexing_slicing,         reason = "Index is always less than the number of weeks")]
+	#[must_use]
+	pub fn week_of(&self, date: NaiveDate) -> Weekdays {
+		let week = date.signed_duration_since(self.anchor).num_days().div_euclid(7);
+		self.weeks[week.rem_euclid(self.weeks.len() as i64) as usize]
+	}
+	
+	//		weeks																
+	/// Returns the days for each week of the cycle, in order.
+	#[must_use]
+	pub fn weeks(&self) -> &[Weekdays] {
+		&self.weeks
+	}
+	
+	//		Private methods														
+	
+	//		cycle_days															
+	/// Returns the number of days in one full cycle.
+	#[expect(clippy::arithmetic_side_effects, reason = "Number of weeks cannot realistically overflow")]
+	fn cycle_days(&self) -> u64 {
+		self.weeks.len() as u64 * 7
+	}
+	
+	//		decode																
+	/// Decodes a pattern from packed bits.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `anchor`   - A date in the first week of the cycle.
+	/// * `weeks`    - The number of weeks in the cycle.
+	/// * `bits`     - The encoded days.
+	/// * `capacity` - The maximum number of weeks the encoding can hold.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Shifts are bounded by the capacity")]
+	#[expect(clippy::cast_possible_truncation, reason = "Value is masked to 7 bits")]
+	fn decode(anchor: NaiveDate, weeks: usize, bits: u64, capacity: usize) -> Option<Self> {
+		if weeks > capacity {
+			return None;
+		}
+		let used = weeks * Self::BITS_PER_WEEK;
+		if used < 64 && bits >> used != 0 {
+			return None;
+		}
+		let days = (1..=weeks)
+			.map(|week| Weekdays::new((bits >> ((weeks - week) * Self::BITS_PER_WEEK)) as u8))
+			.collect();
+		Self::new(anchor, days)
+	}
+	
+	//		encode																
+	/// Encodes the pattern as packed bits.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `capacity` - The maximum number of weeks the encoding can hold.
+	/// 
+	fn encode(&self, capacity: usize) -> Option<u64> {
+		(self.weeks.len() <= capacity).then(|| {
+			self.weeks.iter().fold(0, |bits, week| bits << Self::BITS_PER_WEEK | u64::from(week.0))
+		})
+	}
+}
+
+//󰭅		Deserialize																
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MultiWeekPattern {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		let RawMultiWeekPattern { anchor, weeks } = RawMultiWeekPattern::deserialize(deserializer)?;
+		Self::new(anchor, weeks).ok_or_else(|| DeError::custom("invalid multi-week pattern"))
+	}
+}
+
+//		RawMultiWeekPattern														
+/// The unvalidated form of a [`MultiWeekPattern`], used for deserialisation.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawMultiWeekPattern {
+	/// A date in the first week of the cycle.
+	anchor: NaiveDate,
+	
+	/// The days for each week of the cycle, in order.
+	weeks:  Vec<Weekdays>,
+}
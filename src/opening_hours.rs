@@ -0,0 +1,576 @@
+//! Parsing, evaluation, and formatting of OpenStreetMap opening hours.
+//! 
+//! The [`OpeningHours`] struct holds the weekly opening times given by the
+//! [`opening_hours`](https://wiki.openstreetmap.org/wiki/Key:opening_hours)
+//! tag, such as `Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off`. Only the subset of
+//! the grammar that selects days of the week and public holidays is supported,
+//! along with time ranges, `off`, `closed`, `open`, and `24/7`. Other selectors,
+//! such as months, weeks, and sunrise or sunset, are reported as unsupported.
+//! 
+//! When the `chrono` feature is enabled, the opening times can be evaluated
+//! against dates and times.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/opening_hours.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{MAX_MINUTE, MINUTES_PER_DAY, Weekdays, format_clock, format_runs, map::WeekdaysMap};
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	ops::Range,
+	str::FromStr,
+};
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+
+
+
+//		Constants
+
+/// The two-letter day names, from Monday to Sunday.
+const NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Selectors that are valid in opening hours, but have no equivalent as days
+/// of the week.
+const UNSUPPORTED_SELECTORS: [&str; 13] = [
+	"Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec", "SH",
+];
+
+
+
+//		Enums
+
+//		OpeningHoursError														
+/// The errors that can occur when parsing opening hours.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum OpeningHoursError {
+	/// The opening hours, a rule, or an entry in a list was empty.
+	Empty,
+	
+	/// The entry was not a valid two-letter day name.
+	InvalidDay(String),
+	
+	/// The entry was not a valid list of time ranges.
+	InvalidTime(String),
+	
+	/// The rule used syntax outside the supported subset, such as months,
+	/// school holidays, sunrise, or comments.
+	UnsupportedSyntax(String),
+}
+
+//󰭅		Display																	
+impl Display for OpeningHoursError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Empty                        => write!(f, "Empty opening hours entry"),
+			Self::InvalidDay(ref day)          => write!(f, "Invalid day of the week: {day}"),
+			Self::InvalidTime(ref time)        => write!(f, "Invalid time range: {time}"),
+			Self::UnsupportedSyntax(ref entry) => write!(f, "Unsupported opening hours syntax: {entry}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for OpeningHoursError {}
+
+
+
+//		Structs
+
+//		OpeningHours															
+/// Weekly opening hours, as given by the OpenStreetMap `opening_hours` tag.
+/// 
+/// Each day has a list of time ranges, given in minutes from midnight, during
+/// which it is open. A range can run past midnight into the following day, up
+/// to 48:00, so that `Fr 22:00-02:00` is held as `1320..1560` on Friday. Days
+/// without any ranges are closed. The ranges for each day are kept in order,
+/// with overlapping and adjacent ranges merged together.
+/// 
+/// Public holidays can be given their own ranges. These are kept and written
+/// back out, but are not used when evaluating, as the dates of holidays are not
+/// known.
+/// 
+/// As in the `opening_hours` grammar, each rule replaces the hours of the days
+/// it selects, so `Mo-Fr 08:00-18:00; We 10:00-12:00` is open on Wednesday from
+/// 10:00 to 12:00 only. Opening hours are written out in a canonical form, with
+/// days that share the same hours grouped together.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, opening_hours::OpeningHours};
+/// 
+/// let hours: OpeningHours = "Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off".parse().unwrap();
+/// 
+/// assert_eq!(hours.hours(Weekdays::MONDAY),   [480..1080]);
+/// assert_eq!(hours.hours(Weekdays::SUNDAY),   []);
+/// assert_eq!(hours.open_days(),               Weekdays::parse_const("mon-sat"));
+/// assert_eq!(hours.to_string(),               "Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off");
+/// ```
+/// 
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OpeningHours {
+	/// The time ranges for each day that is open, which are never empty.
+	days:     WeekdaysMap<Vec<Range<u16>>>,
+	
+	/// The time ranges for public holidays, if a rule was given for them.
+	holidays: Option<Vec<Range<u16>>>,
+}
+
+//󰭅		OpeningHours															
+impl OpeningHours {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`OpeningHours`] that is closed on every day.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			days:     WeekdaysMap::new(),
+			holidays: None,
+		}
+	}
+	
+	//		with_holidays														
+	/// Sets the time ranges for public holidays.
+	/// 
+	/// Returns [`None`] if any of the time ranges are invalid, as described for
+	/// [`with_hours()`](Self::with_hours()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `hours` - The time ranges, in minutes from midnight. If empty, public
+	///   holidays are closed.
+	/// 
+	#[must_use]
+	pub fn with_holidays(mut self, hours: &[Range<u16>]) -> Option<Self> {
+		self.holidays = Some(normalise(hours)?);
+		Some(self)
+	}
+	
+	//		with_hours															
+	/// Sets the time ranges for the given days.
+	/// 
+	/// Any hours already set for the days are replaced.
+	/// 
+	/// Returns [`None`] if any of the time ranges are empty, start at or after
+	/// midnight at the end of the day, or end after midnight at the end of the
+	/// following day.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to set.
+	/// * `hours` - The time ranges, in minutes from midnight. If empty, the days
+	///   are closed.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, opening_hours::OpeningHours};
+	/// 
+	/// let hours = OpeningHours::new()
+	///     .with_hours(Weekdays::WEEKDAYS, &[540..720, 780..1020]).unwrap()
+	///     .with_hours(Weekdays::FRIDAY,   &[1200..1560]).unwrap();
+	/// 
+	/// assert_eq!(hours.to_string(), "Mo-Th 09:00-12:00,13:00-17:00; Fr 20:00-02:00");
+	/// ```
+	/// 
+	#[must_use]
+	pub fn with_hours(mut self, days: Weekdays, hours: &[Range<u16>]) -> Option<Self> {
+		self.set_hours(days, normalise(hours)?);
+		Some(self)
+	}
+	
+	//		Public methods														
+	
+	//		holidays															
+	/// Returns the time ranges for public holidays, if any were given.
+	#[must_use]
+	pub fn holidays(&self) -> Option<&[Range<u16>]> {
+		self.holidays.as_deref()
+	}
+	
+	//		hours																
+	/// Returns the time ranges for the given day.
+	/// 
+	/// If more than one day is given, the earliest day in the week is used. If
+	/// the day is closed, the result is empty.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn hours(&self, day: Weekdays) -> &[Range<u16>] {
+		self.days.get(day).map(Vec::as_slice).unwrap_or_default()
+	}
+	
+	//		is_open_at															
+	/// Checks if the opening hours are open at the given date and time.
+	/// 
+	/// Time ranges from the previous day that run past midnight are taken into
+	/// account. Public holidays are not.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `at` - The date and time to check.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::opening_hours::OpeningHours;
+	/// 
+	/// let hours: OpeningHours = "Fr 22:00-02:00".parse().unwrap();
+	/// let date                = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+	/// 
+	/// assert!( hours.is_open_at(date.and_hms_opt(1, 30, 0).unwrap()));
+	/// assert!(!hours.is_open_at(date.and_hms_opt(2,  0, 0).unwrap()));
+	/// ```
+	/// 
+	#[cfg(feature = "chrono")]
+	#[expect(clippy::arithmetic_side_effects, reason = "Minutes in a day are always less than 1440")]
+	#[must_use]
+	pub fn is_open_at(&self, at: NaiveDateTime) -> bool {
+		let minute    = u16::try_from(at.hour() * 60 + at.minute()).unwrap_or_default();
+		let today     = Weekdays::from(at.weekday());
+		let yesterday = Weekdays::from(at.weekday().pred());
+		self.hours(today).iter().any(|range| range.contains(&minute))
+			|| self.hours(yesterday).iter().any(|range| range.contains(&(minute + MINUTES_PER_DAY)))
+	}
+	
+	//		next_change															
+	/// Returns the next time after the given date and time at which the
+	/// opening hours change from open to closed, or from closed to open.
+	/// 
+	/// If the opening hours never change, such as when they are `24/7` or
+	/// always closed, [`None`] is returned. Public holidays are not taken into
+	/// account.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `after` - The date and time to start from.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use weekdays::opening_hours::OpeningHours;
+	/// 
+	/// let hours: OpeningHours = "Mo-Fr 08:00-18:00".parse().unwrap();
+	/// let friday              = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+	/// let monday              = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+	/// 
+	/// assert_eq!(hours.next_change(friday.and_hms_opt(12, 0, 0).unwrap()), friday.and_hms_opt(18, 0, 0));
+	/// assert_eq!(hours.next_change(friday.and_hms_opt(18, 0, 0).unwrap()), monday.and_hms_opt( 8, 0, 0));
+	/// ```
+	/// 
+	#[cfg(feature = "chrono")]
+	#[must_use]
+	pub fn next_change(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+		let open     = self.is_open_at(after);
+		let midnight = after.date().and_time(NaiveTime::default());
+		//	The previous day is included for ranges that run past midnight, and
+		//	a full week ahead covers every change in the pattern
+		let mut changes = (-1..=7_i64)
+			.filter_map(|offset| midnight.checked_add_signed(TimeDelta::days(offset)))
+			.flat_map(|start| {
+				self.hours(Weekdays::from(start.weekday()))
+					.iter()
+					.flat_map(|range| [range.start, range.end])
+					.filter_map(move |minute| start.checked_add_signed(TimeDelta::minutes(i64::from(minute))))
+			})
+			.filter(|&change| change > after)
+			.collect::<Vec<_>>();
+		changes.sort_unstable();
+		changes.into_iter().find(|&change| self.is_open_at(change) != open)
+	}
+	
+	//		open_days															
+	/// Returns the days that are open at some time.
+	#[must_use]
+	pub fn open_days(&self) -> Weekdays {
+		self.days.keys()
+	}
+	
+	//		rules																
+	/// Groups together the days that have the same time ranges.
+	/// 
+	/// Each group is a pair of the days in the group and their time ranges, and
+	/// the groups are ordered by their earliest day. Closed days are left out.
+	/// 
+	#[must_use]
+	pub fn rules(&self) -> Vec<(Weekdays, &[Range<u16>])> {
+		self.days.groups().into_iter().map(|(days, hours)| (days, hours.as_slice())).collect()
+	}
+	
+	//		Private methods														
+	
+	//		set_hours															
+	/// Sets the time ranges for the given days, which must already be
+	/// normalised.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to set.
+	/// * `hours` - The time ranges. If empty, the days are closed.
+	/// 
+	fn set_hours(&mut self, days: Weekdays, hours: Vec<Range<u16>>) {
+		if hours.is_empty() {
+			for day in &days {
+				drop(self.days.remove(day));
+			}
+		} else {
+			self.days.insert_all(days, hours);
+		}
+	}
+}
+
+//󰭅		Display																	
+impl Display for OpeningHours {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let always    = |hours: &Vec<Range<u16>>| matches!(*hours.as_slice(), [ref range] if *range == (0..MINUTES_PER_DAY));
+		let mut rules = if self.days.keys() == Weekdays::ALL_DAYS && self.days.values().all(always) {
+			vec!["24/7".to_owned()]
+		} else {
+			self.rules().into_iter().map(|(days, hours)| format!("{} {}", format_runs(days, NAMES, "-"), format_ranges(hours))).collect()
+		};
+		match self.holidays {
+			Some(ref hours) => rules.push(format!("PH {}", format_ranges(hours))),
+			None if rules.is_empty() => rules.push("off".to_owned()),
+			None => {}
+		}
+		write!(f, "{}", rules.join("; "))
+	}
+}
+
+//󰭅		FromStr																	
+impl FromStr for OpeningHours {
+	type Err = OpeningHoursError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.split(';').try_fold(Self::new(), |mut hours, rule| {
+			let text              = rule.trim();
+			let (selector, times) = match text.split_once(char::is_whitespace) {
+				_ if text.is_empty()                      => return Err(OpeningHoursError::Empty),
+				_ if text == "24/7"                       => (None, "open"),
+				Some((first, rest)) if is_selector(first) => (Some(first), rest),
+				None if is_selector(text)                 => (Some(text), ""),
+				_                                         => (None, text),
+			};
+			let (days, holidays) = match selector {
+				Some(entries) => parse_selector(entries)?,
+				None          => (Weekdays::ALL_DAYS, false),
+			};
+			let ranges           = parse_ranges(times)?;
+			if holidays {
+				hours.holidays = Some(ranges.clone());
+			}
+			hours.set_hours(days, ranges);
+			Ok(hours)
+		})
+	}
+}
+
+
+
+//		Functions
+
+//		format_ranges															
+/// Formats a list of time ranges, or `off` if there are none.
+/// 
+/// # Parameters
+/// 
+/// * `ranges` - The time ranges to format.
+/// 
+fn format_ranges(ranges: &[Range<u16>]) -> String {
+	if ranges.is_empty() {
+		return "off".to_owned();
+	}
+	ranges.iter()
+		.map(|range| {
+			//	Ends on the following day are written as times on that day, as
+			//	is usual, unless the range is too long to be read back that way
+			let end = match range.end.checked_sub(MINUTES_PER_DAY) {
+				Some(wrapped) if wrapped > 0 && wrapped <= range.start => wrapped,
+				_                                                      => range.end,
+			};
+			format!("{}-{}", format_clock(range.start), format_clock(end))
+		})
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+//		is_selector																
+/// Checks if a word starts a selector, rather than a time or a state such as
+/// `off`.
+/// 
+/// Day names, public holidays, and months all start with a capital letter,
+/// whereas states and keywords such as `sunrise` do not.
+/// 
+/// # Parameters
+/// 
+/// * `word` - The word to check.
+/// 
+fn is_selector(word: &str) -> bool {
+	word.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+//		normalise																
+/// Checks and sorts a list of time ranges, merging any that overlap or touch.
+/// 
+/// Returns [`None`] if any of the ranges are empty or out of bounds.
+/// 
+/// # Parameters
+/// 
+/// * `ranges` - The time ranges to normalise.
+/// 
+fn normalise(ranges: &[Range<u16>]) -> Option<Vec<Range<u16>>> {
+	if ranges.iter().any(|range| range.is_empty() || range.start >= MINUTES_PER_DAY || range.end > MAX_MINUTE) {
+		return None;
+	}
+	let mut sorted = ranges.to_vec();
+	sorted.sort_unstable_by_key(|range| range.start);
+	let mut merged = Vec::<Range<u16>>::new();
+	for range in sorted {
+		match merged.last_mut() {
+			Some(previous) if range.start <= previous.end => previous.end = previous.end.max(range.end),
+			_                                             => merged.push(range),
+		}
+	}
+	Some(merged)
+}
+
+//		parse_clock																
+/// Parses a time such as `08:30` into a number of minutes from midnight.
+/// 
+/// Hours up to 48 are allowed, for times on the following day.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The time to parse.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Hours and minutes are checked first")]
+fn parse_clock(text: &str) -> Option<u16> {
+	let (hours, minutes) = text.split_once(':')?;
+	if hours.is_empty() || hours.len() > 2 || minutes.len() != 2 {
+		return None;
+	}
+	let (hour, minute) = (hours.parse::<u16>().ok()?, minutes.parse::<u16>().ok()?);
+	(hour <= 48 && minute < 60).then(|| hour * 60 + minute)
+}
+
+//		parse_day																
+/// Parses a single two-letter day name.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The name to parse.
+/// 
+/// # Errors
+/// 
+/// Returns [`OpeningHoursError::InvalidDay`] if the name is not recognised.
+/// 
+fn parse_day(name: &str) -> Result<Weekdays, OpeningHoursError> {
+	NAMES.iter()
+		.position(|&candidate| candidate == name)
+		.map(Weekdays::from_index)
+		.ok_or_else(|| OpeningHoursError::InvalidDay(name.to_owned()))
+}
+
+//		parse_range																
+/// Parses a single time range such as `22:00-02:00`.
+/// 
+/// A range that ends at or before its start is taken to run past midnight
+/// into the following day.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The range to parse.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Times are at most 48:00, so the sum fits")]
+fn parse_range(text: &str) -> Option<Range<u16>> {
+	let (from, to)   = text.split_once('-')?;
+	let (start, end) = (parse_clock(from)?, parse_clock(to)?);
+	let wrapped      = if end <= start { end + MINUTES_PER_DAY } else { end };
+	(start < MINUTES_PER_DAY && wrapped <= MAX_MINUTE).then_some(start..wrapped)
+}
+
+//		parse_ranges															
+/// Parses the times of a rule into a normalised list of time ranges.
+/// 
+/// The times can be a comma-separated list of ranges, `off` or `closed` for
+/// no ranges, or `open` or nothing at all for the whole day.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The times to parse.
+/// 
+/// # Errors
+/// 
+/// Returns [`OpeningHoursError::InvalidTime`] if a range is not valid, or
+/// [`OpeningHoursError::UnsupportedSyntax`] if the times use anything other
+/// than ranges, such as open ends, sunrise, or comments.
+/// 
+#[expect(clippy::single_range_in_vec_init, reason = "A single range for the whole day is intended")]
+fn parse_ranges(text: &str) -> Result<Vec<Range<u16>>, OpeningHoursError> {
+	let compact = text.split_whitespace().collect::<String>();
+	match compact.as_str() {
+		"" | "open"       => Ok(vec![0..MINUTES_PER_DAY]),
+		"off" | "closed"  => Ok(vec![]),
+		_ if compact.contains(|c: char| !c.is_ascii_digit() && !matches!(c, ':' | '-' | ',')) => {
+			Err(OpeningHoursError::UnsupportedSyntax(text.trim().to_owned()))
+		}
+		_                 => {
+			let ranges = compact
+				.split(',')
+				.map(|range| parse_range(range).ok_or_else(|| OpeningHoursError::InvalidTime(range.to_owned())))
+				.collect::<Result<Vec<_>, _>>()?;
+			normalise(&ranges).ok_or(OpeningHoursError::InvalidTime(compact))
+		}
+	}
+}
+
+//		parse_selector															
+/// Parses the selector of a rule into a set of days, and whether it selects
+/// public holidays.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The selector to parse, such as `Mo-Fr,PH`.
+/// 
+/// # Errors
+/// 
+/// Returns an [`OpeningHoursError`] describing the first entry that could not
+/// be parsed.
+/// 
+fn parse_selector(text: &str) -> Result<(Weekdays, bool), OpeningHoursError> {
+	text.split(',').try_fold((Weekdays::NONE, false), |(days, holidays), entry| {
+		if entry.is_empty() {
+			return Err(OpeningHoursError::Empty);
+		}
+		if entry == "PH" {
+			return Ok((days, true));
+		}
+		if entry.contains(['[', '(']) || UNSUPPORTED_SELECTORS.iter().any(|&selector| entry.starts_with(selector)) {
+			return Err(OpeningHoursError::UnsupportedSyntax(entry.to_owned()));
+		}
+		let (from, to) = entry.split_once('-').unwrap_or((entry, entry));
+		Ok((days | Weekdays::range(parse_day(from)?, parse_day(to)?), holidays))
+	})
+}
@@ -0,0 +1,635 @@
+//! Parsing of English schedule phrases.
+//! 
+//! People often describe days of the week in free text, such as "every weekday
+//! except Wednesday", "weekends and Mondays", or "Tue thru Thu". The
+//! [`parse_phrase()`] function turns such phrases into [`Weekdays`], building
+//! on the named sets [`WEEKDAYS`](Weekdays::WEEKDAYS),
+//! [`WEEKENDS`](Weekdays::WEEKENDS), [`ALL_DAYS`](Weekdays::ALL_DAYS), and
+//! [`NONE`](Weekdays::NONE).
+//! 
+//! Phrases are matched without regard to case or punctuation, and plurals
+//! such as "Mondays" are accepted. Misspelt days and named sets of days, such
+//! as "Wendesday", are corrected where the intended word is clear, and each
+//! correction lowers the confidence given in the resulting [`PhraseMatch`].
+//! Phrases that cannot be understood give a [`PhraseError`] naming the word
+//! at fault.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/phrase.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	mem,
+};
+
+
+
+//		Constants
+
+/// The shortest word that is checked for misspellings. Shorter words are too
+/// easily mistaken for each other.
+const MIN_CORRECTION_LENGTH: usize = 4;
+
+/// The words that are understood, and what they mean.
+const VOCABULARY: [(&str, Word); 61] = [
+	("monday",      Word::Day(Weekdays::MONDAY)),
+	("mon",         Word::Day(Weekdays::MONDAY)),
+	("tuesday",     Word::Day(Weekdays::TUESDAY)),
+	("tue",         Word::Day(Weekdays::TUESDAY)),
+	("tues",        Word::Day(Weekdays::TUESDAY)),
+	("wednesday",   Word::Day(Weekdays::WEDNESDAY)),
+	("wed",         Word::Day(Weekdays::WEDNESDAY)),
+	("weds",        Word::Day(Weekdays::WEDNESDAY)),
+	("thursday",    Word::Day(Weekdays::THURSDAY)),
+	("thu",         Word::Day(Weekdays::THURSDAY)),
+	("thur",        Word::Day(Weekdays::THURSDAY)),
+	("thurs",       Word::Day(Weekdays::THURSDAY)),
+	("friday",      Word::Day(Weekdays::FRIDAY)),
+	("fri",         Word::Day(Weekdays::FRIDAY)),
+	("saturday",    Word::Day(Weekdays::SATURDAY)),
+	("sat",         Word::Day(Weekdays::SATURDAY)),
+	("sunday",      Word::Day(Weekdays::SUNDAY)),
+	("sun",         Word::Day(Weekdays::SUNDAY)),
+	("weekday",     Word::Group(Weekdays::WEEKDAYS)),
+	("workday",     Word::Group(Weekdays::WEEKDAYS)),
+	("weekend",     Word::Group(Weekdays::WEEKENDS)),
+	("daily",       Word::Group(Weekdays::ALL_DAYS)),
+	("everyday",    Word::Group(Weekdays::ALL_DAYS)),
+	("day",         Word::Group(Weekdays::ALL_DAYS)),
+	("and",         Word::And),
+	("or",          Word::And),
+	("also",        Word::Include),
+	("plus",        Word::Include),
+	("including",   Word::Include),
+	("except",      Word::Except),
+	("but",         Word::Except),
+	("excluding",   Word::Except),
+	("without",     Word::Except),
+	("minus",       Word::Except),
+	("never",       Word::Never),
+	("not",         Word::Never),
+	("no",          Word::Never),
+	("none",        Word::Never),
+	("through",     Word::Through),
+	("thru",        Word::Through),
+	("to",          Word::Through),
+	("till",        Word::Through),
+	("until",       Word::Through),
+	("every",       Word::Filler),
+	("each",        Word::Filler),
+	("all",         Word::Filler),
+	("on",          Word::Filler),
+	("the",         Word::Filler),
+	("only",        Word::Filler),
+	("from",        Word::Filler),
+	("of",          Word::Filler),
+	("week",        Word::Filler),
+	("weekly",      Word::Filler),
+	("other",       Word::Unsupported),
+	("alternate",   Word::Unsupported),
+	("second",      Word::Unsupported),
+	("biweekly",    Word::Unsupported),
+	("fortnightly", Word::Unsupported),
+	("monthly",     Word::Unsupported),
+	("yearly",      Word::Unsupported),
+	("holiday",     Word::Unsupported),
+];
+
+
+
+//		Enums
+
+//		PhraseError																
+/// The errors that can occur when parsing a schedule phrase.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PhraseError {
+	/// The phrase did not mention any days.
+	Empty,
+	
+	/// The phrase ended with a word that needs something to follow it, such as
+	/// "except" or "through".
+	IncompletePhrase(String),
+	
+	/// A range did not start and end with single days, such as "weekdays to
+	/// Sunday".
+	InvalidRange(String),
+	
+	/// The phrase contained a word that was not understood, or a misspelling
+	/// that could be more than one word.
+	UnknownWord(String),
+	
+	/// The phrase contained a word describing a schedule that cannot be given
+	/// as a set of days, such as "every other Monday".
+	UnsupportedWord(String),
+}
+
+//󰭅		Display																	
+impl Display for PhraseError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Empty                      => write!(f, "No days given"),
+			Self::IncompletePhrase(ref word) => write!(f, "Phrase ends after: {word}"),
+			Self::InvalidRange(ref range)    => write!(f, "Invalid range of days: {range}"),
+			Self::UnknownWord(ref word)      => write!(f, "Unknown word: {word}"),
+			Self::UnsupportedWord(ref word)  => write!(f, "Unsupported schedule: {word}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for PhraseError {}
+
+//		Word																	
+/// The meaning of a word in a phrase.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Word {
+	/// Adds the following days, in the same way as a comma.
+	And,
+	
+	/// A single day, which can start or end a range.
+	Day(Weekdays),
+	
+	/// Removes the following days.
+	Except,
+	
+	/// A word with no meaning of its own, such as "every" or "on".
+	Filler,
+	
+	/// A named set of days, such as "weekends".
+	Group(Weekdays),
+	
+	/// Goes back to adding days after an exception.
+	Include,
+	
+	/// Removes the following days, or means no days if nothing follows.
+	Never,
+	
+	/// Makes a range from the day before to the day after.
+	Through,
+	
+	/// A word describing a schedule that is not a set of days.
+	Unsupported,
+}
+
+
+
+//		Structs
+
+//		Correction																
+/// A misspelt word that was corrected when parsing a phrase.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Correction {
+	/// The word as it was corrected to.
+	corrected: &'static str,
+	
+	/// The number of single-character edits needed to make the correction.
+	distance:  usize,
+	
+	/// The word as it was written, in lowercase.
+	original:  String,
+}
+
+//󰭅		Correction																
+impl Correction {
+	//		Public methods														
+	
+	//		corrected															
+	/// Returns the word as it was corrected to.
+	#[must_use]
+	pub const fn corrected(&self) -> &'static str {
+		self.corrected
+	}
+	
+	//		distance															
+	/// Returns the number of single-character edits needed to make the
+	/// correction.
+	/// 
+	/// Edits are insertions, deletions, substitutions, and swaps of adjacent
+	/// characters.
+	/// 
+	#[must_use]
+	pub const fn distance(&self) -> usize {
+		self.distance
+	}
+	
+	//		original															
+	/// Returns the word as it was written, in lowercase.
+	#[must_use]
+	pub fn original(&self) -> &str {
+		&self.original
+	}
+}
+
+//		PhraseMatch																
+/// The result of parsing a schedule phrase.
+/// 
+/// # See also
+/// 
+/// * [`parse_phrase()`]
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PhraseMatch {
+	/// The misspelt words that were corrected.
+	corrections: Vec<Correction>,
+	
+	/// The days described by the phrase.
+	days:        Weekdays,
+}
+
+//󰭅		PhraseMatch																
+impl PhraseMatch {
+	//		Public methods														
+	
+	//		confidence															
+	/// Returns how confident the match is, from `0.0` to `1.0`.
+	/// 
+	/// A phrase with no misspellings has a confidence of `1.0`. Each
+	/// correction lowers the confidence by the proportion of the word that had
+	/// to be changed.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::phrase::parse_phrase;
+	/// 
+	/// assert!((parse_phrase("weekdays").unwrap().confidence() - 1.0).abs() < f64::EPSILON);
+	/// assert!(parse_phrase("wendesdays").unwrap().confidence() < 1.0);
+	/// ```
+	/// 
+	#[expect(clippy::cast_precision_loss, reason = "Words are far too short for precision loss")]
+	#[must_use]
+	pub fn confidence(&self) -> f64 {
+		self.corrections.iter().fold(1.0_f64, |confidence, correction| {
+			let length = correction.original.chars().count().max(correction.distance);
+			confidence * (1.0_f64 - correction.distance as f64 / length as f64)
+		})
+	}
+	
+	//		corrections															
+	/// Returns the misspelt words that were corrected.
+	#[must_use]
+	pub fn corrections(&self) -> &[Correction] {
+		&self.corrections
+	}
+	
+	//		days																
+	/// Returns the days described by the phrase.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+}
+
+//		PhraseState																
+/// The state built up while reading the words of a phrase.
+#[derive(Default)]
+struct PhraseState {
+	/// The days added by the phrase.
+	added:       Weekdays,
+	
+	/// Whether the phrase mentioned any days.
+	any_days:    bool,
+	
+	/// The word that is waiting for something to follow it, if any.
+	dangling:    Option<String>,
+	
+	/// Whether the phrase said "never" or similar.
+	never:       bool,
+	
+	/// The last single day read, which can start a range, and the text it was
+	/// read from.
+	previous:    Option<(Weekdays, String)>,
+	
+	/// The start of a range that is waiting for its end, and the text so far.
+	range_start: Option<(Weekdays, String)>,
+	
+	/// The days removed by the phrase.
+	removed:     Weekdays,
+	
+	/// Whether the following days are removed rather than added.
+	removing:    bool,
+}
+
+//󰭅		PhraseState																
+impl PhraseState {
+	//		Public methods														
+	
+	//		apply																
+	/// Applies the next word of the phrase.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `word` - The meaning of the word.
+	/// * `text` - The word as it was written.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the word is unsupported, or if it ends a range that
+	/// did not start with a single day.
+	/// 
+	fn apply(&mut self, word: Word, text: String) -> Result<(), PhraseError> {
+		let previous  = self.previous.take();
+		self.dangling = None;
+		match word {
+			Word::And | Word::Filler => {}
+			Word::Day(day)           => self.add_days(day, true, text)?,
+			Word::Group(days)        => self.add_days(days, false, text)?,
+			Word::Except             => {
+				self.removing = true;
+				self.dangling = Some(text);
+			}
+			Word::Include            => {
+				self.removing = false;
+				self.dangling = Some(text);
+			}
+			Word::Never              => {
+				self.removing = true;
+				self.never    = true;
+			}
+			Word::Through            => {
+				let Some((day, start)) = previous else {
+					return Err(PhraseError::InvalidRange(text));
+				};
+				self.range_start = Some((day, format!("{start} {text}")));
+				self.dangling    = Some(text);
+			}
+			Word::Unsupported        => return Err(PhraseError::UnsupportedWord(text)),
+		}
+		Ok(())
+	}
+	
+	//		finish																
+	/// Works out the days described by the phrase, once all words are read.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if the phrase ended with a word that needs something
+	/// to follow it, or did not mention any days.
+	/// 
+	fn finish(self) -> Result<Weekdays, PhraseError> {
+		if let Some(word) = self.dangling {
+			return Err(PhraseError::IncompletePhrase(word));
+		}
+		if !self.any_days {
+			return if self.never { Ok(Weekdays::NONE) } else { Err(PhraseError::Empty) };
+		}
+		//	Phrases such as "except Mondays" start from every day
+		let base = if self.added.is_empty() { Weekdays::ALL_DAYS } else { self.added };
+		Ok(base & !self.removed)
+	}
+	
+	//		Private methods														
+	
+	//		add_days															
+	/// Adds or removes days, completing any range that is waiting for its end.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`   - The days to add or remove.
+	/// * `single` - Whether the days came from a single day name.
+	/// * `text`   - The word as it was written.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`PhraseError::InvalidRange`] if the days end a range, but are
+	/// not a single day.
+	/// 
+	fn add_days(&mut self, days: Weekdays, single: bool, text: String) -> Result<(), PhraseError> {
+		let selected = match self.range_start.take() {
+			Some((start, _)) if single     => Weekdays::range(start, days),
+			Some((_, range))               => return Err(PhraseError::InvalidRange(format!("{range} {text}"))),
+			None                           => days,
+		};
+		if self.removing {
+			self.removed |= selected;
+		} else {
+			self.added   |= selected;
+		}
+		self.any_days = true;
+		if single {
+			self.previous = Some((days, text));
+		}
+		Ok(())
+	}
+}
+
+
+
+//		Functions
+
+//		parse_phrase															
+/// Parses an English schedule phrase into a set of days.
+/// 
+/// Phrases are built from day names, such as "Monday", "mon", or "Mondays",
+/// and named sets of days, such as "weekdays", "weekends", and "daily". These
+/// can be combined in the following ways:
+/// 
+///   - Lists, using commas, "and", "or", "&", or "plus", such as "weekends and
+///     Mondays".
+///   - Ranges, using "through", "thru", "to", "until", or a dash, such as
+///     "Tue thru Thu". Ranges wrap around the end of the week, so "Fri-Mon"
+///     covers four days.
+///   - Exceptions, using "except", "but", "excluding", "without", or "not",
+///     such as "every weekday except Wednesday". An exception with nothing
+///     before it starts from every day, so "not Sundays" covers six days.
+///     "Also" and "including" go back to adding days.
+///   - "Never", "none", or "no days" on their own mean no days.
+/// 
+/// Words such as "every", "on", and "the" are ignored. Words that describe
+/// schedules which are not a set of days, such as "every other Monday" or
+/// "monthly", are rejected.
+/// 
+/// # Parameters
+/// 
+/// * `phrase` - The phrase to parse.
+/// 
+/// # Errors
+/// 
+/// Returns a [`PhraseError`] describing the first problem found, if the
+/// phrase cannot be understood.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, phrase::parse_phrase};
+/// 
+/// assert_eq!(parse_phrase("every weekday except Wednesday").unwrap().days(), Weekdays::parse_const("mon-tue, thu-fri"));
+/// assert_eq!(parse_phrase("weekends and Mondays").unwrap().days(),           Weekdays::parse_const("mon, sat-sun"));
+/// assert_eq!(parse_phrase("Tue thru Thu").unwrap().days(),                   Weekdays::parse_const("tue-thu"));
+/// assert_eq!(parse_phrase("daily").unwrap().days(),                          Weekdays::ALL_DAYS);
+/// 
+/// let corrected = parse_phrase("Satruday and Sundy").unwrap();
+/// assert_eq!(corrected.days(),              Weekdays::WEEKENDS);
+/// assert_eq!(corrected.corrections().len(), 2);
+/// assert!(corrected.confidence() < 1.0);
+/// 
+/// assert!(parse_phrase("every other Monday").is_err());
+/// ```
+/// 
+pub fn parse_phrase(phrase: &str) -> Result<PhraseMatch, PhraseError> {
+	let mut state       = PhraseState::default();
+	let mut corrections = Vec::new();
+	for token in tokenize(phrase) {
+		let (word, correction) = classify(&token)?;
+		corrections.extend(correction);
+		state.apply(word, token)?;
+	}
+	Ok(PhraseMatch { corrections, days: state.finish()? })
+}
+
+//		classify																
+/// Works out the meaning of a single word, correcting it if misspelt.
+/// 
+/// Plurals of days and named sets of days are matched by removing a trailing
+/// "s". Symbols are given the same meaning as the equivalent words.
+/// 
+/// Only days and named sets of days are corrected. Other words, such as "not",
+/// are short and close to ordinary words, so a word that is closest to one of
+/// them is not understood, rather than being read as "not" or "none".
+/// 
+/// # Parameters
+/// 
+/// * `token` - The word or symbol, in lowercase.
+/// 
+/// # Errors
+/// 
+/// Returns [`PhraseError::UnknownWord`] if the word is not understood, if it
+/// is a misspelling that could be more than one word, or if it is closest to a
+/// word that is not corrected.
+/// 
+fn classify(token: &str) -> Result<(Word, Option<Correction>), PhraseError> {
+	match token {
+		"," | "&" | "+" | "/" => return Ok((Word::And, None)),
+		"-" | "\u{2013}"      => return Ok((Word::Through, None)),
+		_                     => {}
+	}
+	let singular = token.strip_suffix('s').filter(|stem| !stem.is_empty());
+	let found    = VOCABULARY.iter().find(|&&(name, word)| {
+		name == token || Some(name) == singular && matches!(word, Word::Day(_) | Word::Group(_))
+	});
+	if let Some(&(_, word)) = found {
+		return Ok((word, None));
+	}
+	let unknown = || PhraseError::UnknownWord(token.to_owned());
+	if token.chars().count() < MIN_CORRECTION_LENGTH {
+		return Err(unknown());
+	}
+	let mut best: Option<(usize, &'static str, Word)> = None;
+	let mut tied = false;
+	for &(name, word) in &VOCABULARY {
+		let plural   = singular.filter(|_| matches!(word, Word::Day(_) | Word::Group(_)));
+		let distance = plural.map_or(usize::MAX, |stem| edit_distance(stem, name)).min(edit_distance(token, name));
+		match best {
+			Some((closest, _, _)) if distance > closest      => {}
+			Some((closest, _, other)) if distance == closest => tied |= other != word,
+			_                                                => {
+				best = Some((distance, name, word));
+				tied = false;
+			}
+		}
+	}
+	match best {
+		Some((distance, name, word @ (Word::Day(_) | Word::Group(_)))) if !tied && distance <= max_distance(token) => Ok((word, Some(Correction {
+			corrected: name,
+			distance,
+			original:  token.to_owned(),
+		}))),
+		_ => Err(unknown()),
+	}
+}
+
+//		edit_distance															
+/// Returns the number of single-character edits needed to turn one word into
+/// another.
+/// 
+/// Edits are insertions, deletions, substitutions, and swaps of adjacent
+/// characters, which together cover most typing mistakes.
+/// 
+/// # Parameters
+/// 
+/// * `from` - The word as written.
+/// * `to`   - The word to compare against.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Distances are bounded by the word lengths")]
+fn edit_distance(from: &str, to: &str) -> usize {
+	let source: Vec<char>        = from.chars().collect();
+	let target: Vec<char>        = to.chars().collect();
+	let mut earlier: Vec<usize>  = Vec::new();
+	let mut previous: Vec<usize> = (0..=target.len()).collect();
+	for (i, &letter) in source.iter().enumerate() {
+		let mut current = vec![i + 1];
+		for (j, &other) in target.iter().enumerate() {
+			let cost         = usize::from(letter != other);
+			let substitution = previous.get(j).map_or(usize::MAX, |&value| value + cost);
+			let deletion     = previous.get(j + 1).map_or(usize::MAX, |&value| value + 1);
+			let insertion    = current.get(j).map_or(usize::MAX, |&value| value + 1);
+			let swapped      = i > 0 && j > 0
+				&& source.get(i - 1) == Some(&other)
+				&& target.get(j - 1) == Some(&letter);
+			let swap         = if swapped { earlier.get(j - 1).map_or(usize::MAX, |&value| value + 1) } else { usize::MAX };
+			current.push(substitution.min(deletion).min(insertion).min(swap));
+		}
+		earlier  = previous;
+		previous = current;
+	}
+	previous.last().copied().unwrap_or(0)
+}
+
+//		max_distance															
+/// Returns the largest number of edits that can be corrected in a word.
+/// 
+/// Longer words can take more edits before they could be mistaken for a
+/// different word.
+/// 
+/// # Parameters
+/// 
+/// * `word` - The word as written.
+/// 
+fn max_distance(word: &str) -> usize {
+	if word.chars().count() >= 7 { 2 } else { 1 }
+}
+
+//		tokenize																
+/// Splits a phrase into lowercase words and symbols.
+/// 
+/// Apostrophes and full stops are removed, so "Monday's" and "Mon." are read
+/// as "mondays" and "mon". Commas, ampersands, plus signs, slashes, and dashes
+/// are kept as symbols, and any other punctuation separates words.
+/// 
+/// # Parameters
+/// 
+/// * `phrase` - The phrase to split.
+/// 
+fn tokenize(phrase: &str) -> Vec<String> {
+	let mut tokens = Vec::new();
+	let mut word   = String::new();
+	for letter in phrase.chars() {
+		match letter {
+			'\'' | '\u{2019}' | '.'                  => {}
+			_ if letter.is_alphanumeric()            => word.extend(letter.to_lowercase()),
+			',' | '&' | '+' | '/' | '-' | '\u{2013}' => {
+				tokens.extend((!word.is_empty()).then(|| mem::take(&mut word)));
+				tokens.push(letter.to_string());
+			}
+			_                                        => {
+				tokens.extend((!word.is_empty()).then(|| mem::take(&mut word)));
+			}
+		}
+	}
+	tokens.extend((!word.is_empty()).then_some(word));
+	tokens
+}
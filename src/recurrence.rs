@@ -0,0 +1,442 @@
+//! Recurrence models used by Microsoft Graph and Google Calendar.
+//! 
+//! Calendar services describe recurring events in their own JSON formats.
+//! Microsoft Graph uses a
+//! [`recurrencePattern`](https://learn.microsoft.com/en-us/graph/api/resources/recurrencepattern)
+//! object, such as:
+//! 
+//! ```json
+//! {
+//!     "type": "weekly",
+//!     "interval": 1,
+//!     "daysOfWeek": ["monday", "wednesday"],
+//!     "firstDayOfWeek": "sunday"
+//! }
+//! ```
+//! 
+//! Google Calendar uses the
+//! [`recurrence`](https://developers.google.com/calendar/api/v3/reference/events)
+//! property of an event, which is a list of iCalendar lines, such as
+//! `["RRULE:FREQ=WEEKLY;BYDAY=MO,WE", "EXDATE;VALUE=DATE:20240610"]`.
+//! 
+//! The [`GraphRecurrencePattern`] and [`GoogleRecurrence`] structs model the
+//! weekly forms of each, and can be serialised to and deserialised from them.
+//! Both convert to and from [`WeeklyRule`], which keeps the days, interval, and
+//! week start without loss.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/recurrence.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{Weekdays, rrule::WeeklyRule};
+use core::iter;
+use chrono::Weekday;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+
+
+
+//		Constants
+
+/// The prefix for the rule line in a Google Calendar recurrence.
+const RRULE_PREFIX: &str = "RRULE:";
+
+/// The Microsoft Graph pattern type for weekly recurrence.
+const WEEKLY: &str = "weekly";
+
+
+
+//		Structs
+
+//		GoogleRecurrence														
+/// A weekly Google Calendar recurrence.
+/// 
+/// This models the `recurrence` property of a Google Calendar event, which is
+/// a list of iCalendar lines. Exactly one `RRULE` line is expected, and it must
+/// describe a weekly rule, as supported by [`WeeklyRule`]. Any other lines,
+/// such as `EXDATE` or `RDATE`, are kept as they are.
+/// 
+/// When serialised, the rule line is written first, followed by the other
+/// lines in their original order.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, recurrence::GoogleRecurrence};
+/// 
+/// let recurrence: GoogleRecurrence = serde_json::from_str(
+///     r#"["EXDATE;VALUE=DATE:20240610", "RRULE:FREQ=WEEKLY;BYDAY=MO,WE"]"#,
+/// ).unwrap();
+/// 
+/// assert_eq!(recurrence.rule().days(),   Weekdays::parse_const("mon, wed"));
+/// assert_eq!(recurrence.extra_lines(),   ["EXDATE;VALUE=DATE:20240610"]);
+/// assert_eq!(serde_json::to_string(&recurrence).unwrap(),
+///     r#"["RRULE:FREQ=WEEKLY;BYDAY=MO,WE","EXDATE;VALUE=DATE:20240610"]"#,
+/// );
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GoogleRecurrence {
+	/// The lines other than the rule, such as `EXDATE` and `RDATE`.
+	extra_lines: Vec<String>,
+	
+	/// The weekly rule.
+	rule:        WeeklyRule,
+}
+
+//󰭅		GoogleRecurrence														
+impl GoogleRecurrence {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`GoogleRecurrence`] from a weekly rule.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `rule` - The weekly rule.
+	/// 
+	#[must_use]
+	pub const fn new(rule: WeeklyRule) -> Self {
+		Self { extra_lines: Vec::new(), rule }
+	}
+	
+	//		with_extra_lines													
+	/// Sets the lines other than the rule, such as `EXDATE` and `RDATE`.
+	/// 
+	/// Lines are written as given, without checking.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `lines` - The lines to add after the rule.
+	/// 
+	#[must_use]
+	pub fn with_extra_lines(mut self, lines: Vec<String>) -> Self {
+		self.extra_lines = lines;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		extra_lines															
+	/// Returns the lines other than the rule.
+	#[must_use]
+	pub fn extra_lines(&self) -> &[String] {
+		&self.extra_lines
+	}
+	
+	//		rule																
+	/// Returns the weekly rule.
+	#[must_use]
+	pub const fn rule(&self) -> WeeklyRule {
+		self.rule
+	}
+}
+
+//󰭅		Deserialize																
+impl<'de> Deserialize<'de> for GoogleRecurrence {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		let mut rule        = None;
+		let mut extra_lines = Vec::new();
+		for line in Vec::<String>::deserialize(deserializer)? {
+			let is_rule = line.get(..RRULE_PREFIX.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(RRULE_PREFIX));
+			if !is_rule {
+				extra_lines.push(line);
+				continue;
+			}
+			if rule.is_some() {
+				return Err(DeError::custom("multiple recurrence rules are not supported"));
+			}
+			rule = Some(line.parse::<WeeklyRule>().map_err(|err| DeError::custom(format!("invalid recurrence rule: {err}")))?);
+		}
+		let weekly = rule.ok_or_else(|| DeError::custom("no recurrence rule given"))?;
+		Ok(Self { extra_lines, rule: weekly })
+	}
+}
+
+//󰭅		From<WeeklyRule>														
+impl From<WeeklyRule> for GoogleRecurrence {
+	//		from																
+	fn from(rule: WeeklyRule) -> Self {
+		Self::new(rule)
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for GoogleRecurrence {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let rule = format!("{RRULE_PREFIX}{}", self.rule);
+		serializer.collect_seq(iter::once(&rule).chain(&self.extra_lines))
+	}
+}
+
+//		GraphRecurrencePattern													
+/// A weekly Microsoft Graph recurrence pattern.
+/// 
+/// This models a `recurrencePattern` object with a `type` of `weekly`, which
+/// repeats on at least one day, every `interval` weeks, with weeks beginning on
+/// the `firstDayOfWeek`. Following Graph, weeks begin on Sunday unless stated
+/// otherwise. Limits on the number of occurrences or the end date are given by
+/// Graph in a separate `recurrenceRange` object, so are not part of the
+/// pattern.
+/// 
+/// When deserialised, day names are matched without regard to case, and the
+/// `month`, `dayOfMonth`, and `index` properties that Graph includes in its
+/// responses are accepted as long as they have their default values. When
+/// serialised, day names are written in lowercase, and those properties are
+/// left out.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::Weekday;
+/// use weekdays::{Weekdays, recurrence::GraphRecurrencePattern};
+/// 
+/// let pattern = GraphRecurrencePattern::new(Weekdays::parse_const("mon, wed"), 2).unwrap();
+/// let json    = serde_json::to_string(&pattern).unwrap();
+/// 
+/// assert_eq!(json, concat!(
+///     r#"{"type":"weekly","interval":2,"#,
+///     r#""daysOfWeek":["monday","wednesday"],"firstDayOfWeek":"sunday"}"#,
+/// ));
+/// 
+/// let parsed: GraphRecurrencePattern = serde_json::from_str(
+///     r#"{"type":"weekly","interval":2,"daysOfWeek":["Monday","Wednesday"]}"#,
+/// ).unwrap();
+/// 
+/// assert_eq!(parsed, pattern);
+/// assert_eq!(parsed.first_day_of_week(), Weekday::Sun);
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GraphRecurrencePattern {
+	/// The weekly rule, which always has days and never has a limit.
+	rule: WeeklyRule,
+}
+
+//󰭅		GraphRecurrencePattern													
+impl GraphRecurrencePattern {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`GraphRecurrencePattern`], with weeks starting on Sunday.
+	/// 
+	/// Returns [`None`] if no days are given, or if the interval is zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`     - The days on which the pattern repeats.
+	/// * `interval` - The number of weeks between repetitions.
+	/// 
+	#[must_use]
+	pub fn new(days: Weekdays, interval: u32) -> Option<Self> {
+		if days.is_empty() {
+			return None;
+		}
+		WeeklyRule::new(days, interval).map(|rule| Self { rule: rule.with_week_start(Weekday::Sun) })
+	}
+	
+	//		from_rule															
+	/// Creates a new [`GraphRecurrencePattern`] from a weekly rule.
+	/// 
+	/// Returns [`None`] if the rule has no days, or if it is limited by a count
+	/// or an end date, as these cannot be represented by a pattern.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `rule` - The weekly rule to convert.
+	/// 
+	#[must_use]
+	pub fn from_rule(rule: WeeklyRule) -> Option<Self> {
+		let valid = !rule.days().is_empty() && rule.count().is_none() && rule.until().is_none();
+		valid.then_some(Self { rule })
+	}
+	
+	//		with_first_day_of_week												
+	/// Sets the day on which each week begins.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The first day of each week.
+	/// 
+	#[must_use]
+	pub const fn with_first_day_of_week(mut self, day: Weekday) -> Self {
+		self.rule = self.rule.with_week_start(day);
+		self
+	}
+	
+	//		Public methods														
+	
+	//		days																
+	/// Returns the days on which the pattern repeats.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.rule.days()
+	}
+	
+	//		first_day_of_week													
+	/// Returns the day on which each week begins.
+	#[must_use]
+	pub const fn first_day_of_week(&self) -> Weekday {
+		self.rule.week_start()
+	}
+	
+	//		interval															
+	/// Returns the number of weeks between repetitions.
+	#[must_use]
+	pub const fn interval(&self) -> u32 {
+		self.rule.interval()
+	}
+	
+	//		to_rule																
+	/// Converts the pattern to a weekly rule, with no limit.
+	#[must_use]
+	pub const fn to_rule(&self) -> WeeklyRule {
+		self.rule
+	}
+}
+
+//󰭅		Deserialize																
+impl<'de> Deserialize<'de> for GraphRecurrencePattern {
+	//		deserialize															
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		let raw = RawGraphPattern::deserialize(deserializer)?;
+		if !raw.kind.eq_ignore_ascii_case(WEEKLY) {
+			return Err(DeError::custom(format!("unsupported recurrence type: {}", raw.kind)));
+		}
+		if raw.month.is_some_and(|month| month != 0) || raw.day_of_month.is_some_and(|day| day != 0) {
+			return Err(DeError::custom("month and dayOfMonth are not supported in weekly patterns"));
+		}
+		let days  = raw.days_of_week.iter().try_fold(Weekdays::NONE, |days, name| {
+			parse_day(name).map(|day| days | day).ok_or_else(|| DeError::custom(format!("invalid day of week: {name}")))
+		})?;
+		let first = match raw.first_day_of_week {
+			Some(ref name) => parse_day(name)
+				.and_then(|day| day.to_chrono_vec().first().copied())
+				.ok_or_else(|| DeError::custom(format!("invalid first day of week: {name}")))?,
+			None           => Weekday::Sun,
+		};
+		if days.is_empty() {
+			return Err(DeError::custom("no days of the week given"));
+		}
+		Self::new(days, raw.interval)
+			.map(|pattern| pattern.with_first_day_of_week(first))
+			.ok_or_else(|| DeError::custom(format!("invalid interval: {}", raw.interval)))
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for GraphRecurrencePattern {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let first = Weekdays::from(self.first_day_of_week());
+		RawGraphPattern {
+			kind:              WEEKLY.to_owned(),
+			interval:          self.interval(),
+			days_of_week:      format_days(self.days()),
+			first_day_of_week: format_days(first).into_iter().next(),
+			month:             None,
+			day_of_month:      None,
+			index:             None,
+		}.serialize(serializer)
+	}
+}
+
+//󰭅		TryFrom<WeeklyRule>														
+impl TryFrom<WeeklyRule> for GraphRecurrencePattern {
+	type Error = WeeklyRule;
+	
+	//		try_from															
+	fn try_from(rule: WeeklyRule) -> Result<Self, Self::Error> {
+		Self::from_rule(rule).ok_or(rule)
+	}
+}
+
+//		RawGraphPattern															
+/// The unvalidated form of a [`GraphRecurrencePattern`], as it appears in JSON.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RawGraphPattern {
+	/// The recurrence type, which must be `weekly`.
+	#[serde(rename = "type")]
+	kind:              String,
+	
+	/// The number of weeks between repetitions.
+	#[serde(default = "default_interval")]
+	interval:          u32,
+	
+	/// The days of the week.
+	#[serde(default)]
+	days_of_week:      Vec<String>,
+	
+	/// The first day of the week, which is Sunday if not given.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	first_day_of_week: Option<String>,
+	
+	/// The month, which is only used by yearly patterns.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	month:             Option<u32>,
+	
+	/// The day of the month, which is only used by monthly and yearly
+	/// patterns.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	day_of_month:      Option<u32>,
+	
+	/// The week of the month, which is only used by relative patterns, and is
+	/// ignored.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	index:             Option<String>,
+}
+
+
+
+//		Functions
+
+//		default_interval														
+/// Returns the interval used when none is given.
+const fn default_interval() -> u32 {
+	1
+}
+
+//		format_days																
+/// Formats a set of days as a list of Microsoft Graph day names.
+/// 
+/// # Parameters
+/// 
+/// * `days` - The days to format.
+/// 
+fn format_days(days: Weekdays) -> Vec<String> {
+	Weekdays::ALL_DAYS
+		.iter()
+		.zip(Weekdays::NAMES)
+		.filter(|&(day, _)| days.contains(day))
+		.map(|(_, name)| name.to_owned())
+		.collect()
+}
+
+//		parse_day																
+/// Parses a single Microsoft Graph day name, without regard to case.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The day name, such as `monday`.
+/// 
+fn parse_day(name: &str) -> Option<Weekdays> {
+	Weekdays::ALL_DAYS
+		.iter()
+		.zip(Weekdays::NAMES)
+		.find(|&(_, candidate)| candidate.eq_ignore_ascii_case(name))
+		.map(|(day, _)| day)
+}
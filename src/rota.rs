@@ -0,0 +1,720 @@
+//! Rota generation from availability and staffing requirements.
+//! 
+//! The main type provided is the [`RotaSolver`] struct, which assigns people to
+//! days of the week so that each day has the number of people it needs, while
+//! respecting when each [`Person`] is available and how much they can work.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/rota.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	ops::RangeInclusive,
+};
+
+
+
+//		Enums
+
+//		RotaError																
+/// The errors that can occur when generating a rota.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RotaError {
+	/// A day could not be staffed without breaking the limits on how many days,
+	/// or how many consecutive days, people can work.
+	LimitsExceeded {
+		/// The day that could not be staffed.
+		day:      Weekdays,
+		
+		/// The number of people needed on the day.
+		required: usize,
+		
+		/// The week in which the day could not be staffed, counting from one.
+		week:     usize,
+	},
+	
+	/// Fewer people are available on a day than are needed.
+	NotEnoughPeople {
+		/// The day that could not be staffed.
+		day:       Weekdays,
+		
+		/// The number of people needed on the day.
+		required:  usize,
+		
+		/// The number of people available on the day.
+		available: usize,
+	},
+}
+
+//󰭅		Display																	
+impl Display for RotaError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::LimitsExceeded { day, required, week }     => write!(
+				f,
+				"Cannot staff {} in week {week}: {required} required without exceeding the limits on days worked",
+				day_name(day),
+			),
+			Self::NotEnoughPeople { day, required, available } => write!(
+				f,
+				"Cannot staff {}: {required} required but only {available} available",
+				day_name(day),
+			),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for RotaError {}
+
+
+
+//		Structs
+
+//		Person																	
+/// A person who can be assigned to days on a rota.
+/// 
+/// By default, a person can work every day on which they are available, with
+/// no limit on the number of consecutive days.
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Person {
+	/// The days on which the person is available.
+	availability:    Weekdays,
+	
+	/// The most consecutive days the person can work.
+	max_consecutive: u8,
+	
+	/// The most days the person can work in a week.
+	max_days:        u8,
+}
+
+//󰭅		Person																	
+impl Person {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`Person`] who is available on the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `availability` - The days on which the person is available.
+	/// 
+	#[must_use]
+	pub const fn new(availability: Weekdays) -> Self {
+		Self {
+			availability,
+			max_consecutive: 7,
+			max_days:        7,
+		}
+	}
+	
+	//		with_max_consecutive												
+	/// Sets the most consecutive days the person can work.
+	/// 
+	/// When generating rotas for several weeks, runs of days continue from
+	/// Sunday into the following Monday.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The most consecutive days.
+	/// 
+	#[must_use]
+	pub const fn with_max_consecutive(mut self, days: u8) -> Self {
+		self.max_consecutive = days;
+		self
+	}
+	
+	//		with_max_days														
+	/// Sets the most days the person can work in a week.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The most days per week.
+	/// 
+	#[must_use]
+	pub const fn with_max_days(mut self, days: u8) -> Self {
+		self.max_days = days;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		availability														
+	/// Returns the days on which the person is available.
+	#[must_use]
+	pub const fn availability(&self) -> Weekdays {
+		self.availability
+	}
+	
+	//		max_consecutive														
+	/// Returns the most consecutive days the person can work.
+	#[must_use]
+	pub const fn max_consecutive(&self) -> u8 {
+		self.max_consecutive
+	}
+	
+	//		max_days															
+	/// Returns the most days the person can work in a week.
+	#[must_use]
+	pub const fn max_days(&self) -> u8 {
+		self.max_days
+	}
+}
+
+//		Rota																	
+/// The days assigned to each person for one week.
+/// 
+/// People are identified by their position in the list given to the
+/// [`RotaSolver`].
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Rota {
+	/// The days assigned to each person.
+	assignments: Vec<Weekdays>,
+}
+
+//󰭅		Rota																	
+impl Rota {
+	//		Public methods														
+	
+	//		assignments															
+	/// Returns the days assigned to each person, in the order the people were
+	/// given.
+	#[must_use]
+	pub fn assignments(&self) -> &[Weekdays] {
+		&self.assignments
+	}
+	
+	//		days_for															
+	/// Returns the days assigned to the given person.
+	/// 
+	/// If there is no such person, [`None`] is returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `person` - The position of the person.
+	/// 
+	#[must_use]
+	pub fn days_for(&self, person: usize) -> Option<Weekdays> {
+		self.assignments.get(person).copied()
+	}
+	
+	//		staff																
+	/// Returns the people assigned to the given day, by position.
+	/// 
+	/// If more than one day is given, the people assigned to all of them are
+	/// returned.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn staff(&self, day: Weekdays) -> Vec<usize> {
+		self.assignments.iter()
+			.enumerate()
+			.filter(|&(_, days)| !day.is_empty() && days.contains(day))
+			.map(|(person, _)| person)
+			.collect()
+	}
+}
+
+//		RotaSolver																
+/// Generates rotas that meet staffing requirements.
+/// 
+/// Each day needs a number of people, known as its headcount, which defaults
+/// to zero. A person can only be assigned to days on which they are available,
+/// and not to more days per week, or more consecutive days, than their limits
+/// allow. Exactly the required number of people are assigned to each day.
+/// 
+/// The solver searches for an assignment day by day, backtracking when a
+/// choice leads to a day that cannot be staffed, so a rota is found whenever
+/// one exists. Among the people who could work a day, those who have worked
+/// the fewest days so far are preferred, which spreads the work fairly. When
+/// generating several weeks with [`solve_weeks()`](Self::solve_weeks()), this
+/// also takes the earlier weeks into account, so that the work is balanced
+/// over the whole period.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, rota::{Person, RotaSolver}};
+/// 
+/// let people = vec![
+///     Person::new(Weekdays::WEEKDAYS).with_max_days(3),
+///     Person::new(Weekdays::parse_const("mon-wed")),
+///     Person::new(Weekdays::parse_const("wed-fri")).with_max_consecutive(2),
+/// ];
+/// let rota   = RotaSolver::new(people).with_headcount(Weekdays::WEEKDAYS, 1).solve().unwrap();
+/// 
+/// assert_eq!(rota.assignments(), &[
+///     Weekdays::parse_const("mon, thu"),
+///     Weekdays::TUESDAY,
+///     Weekdays::parse_const("wed, fri"),
+/// ]);
+/// assert_eq!(rota.staff(Weekdays::THURSDAY), vec![0]);
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RotaSolver {
+	/// The number of people needed on each day, from Monday to Sunday.
+	headcount: [usize; 7],
+	
+	/// The people who can be assigned.
+	people:    Vec<Person>,
+}
+
+//󰭅		RotaSolver																
+impl RotaSolver {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`RotaSolver`] for the given people, with no days needing
+	/// anyone.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `people` - The people who can be assigned.
+	/// 
+	#[must_use]
+	pub const fn new(people: Vec<Person>) -> Self {
+		Self {
+			headcount: [0; 7],
+			people,
+		}
+	}
+	
+	//		with_headcount														
+	/// Sets the number of people needed on the given days.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days to set the headcount of.
+	/// * `count` - The number of people needed on each of the days.
+	/// 
+	#[must_use]
+	pub fn with_headcount(mut self, days: Weekdays, count: usize) -> Self {
+		for (day, slot) in Weekdays::ALL_DAYS.iter().zip(self.headcount.iter_mut()) {
+			if days.contains(day) {
+				*slot = count;
+			}
+		}
+		self
+	}
+	
+	//		Public methods														
+	
+	//		headcount															
+	/// Returns the number of people needed on the given day.
+	/// 
+	/// If more than one day is given, the earliest day in the week is used. If
+	/// no days are given, the result is zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day to look up.
+	/// 
+	#[must_use]
+	pub fn headcount(&self, day: Weekdays) -> usize {
+		day.first_index().and_then(|index| self.headcount.get(index)).copied().unwrap_or(0)
+	}
+	
+	//		people																
+	/// Returns the people who can be assigned.
+	#[must_use]
+	pub fn people(&self) -> &[Person] {
+		&self.people
+	}
+	
+	//		solve																
+	/// Generates a rota for a single week.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`RotaError::NotEnoughPeople`] if fewer people are available on
+	/// a day than are needed, or [`RotaError::LimitsExceeded`] if there are
+	/// enough people, but no way to staff every day without breaking their
+	/// limits. In the latter case, the day reported is the latest day that the
+	/// search was unable to staff.
+	/// 
+	pub fn solve(&self) -> Result<Rota, RotaError> {
+		self.solve_weeks(1).map(|mut rotas| rotas.pop().unwrap_or_else(|| Rota { assignments: vec![Weekdays::NONE; self.people.len()] }))
+	}
+	
+	//		solve_weeks															
+	/// Generates rotas for several consecutive weeks, balancing the work over
+	/// the whole period.
+	/// 
+	/// Each week is solved in turn, preferring people who have worked the
+	/// fewest days in the weeks so far, and with runs of consecutive days
+	/// carrying over from one week to the next. Earlier weeks are not revisited
+	/// to make later weeks possible.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `weeks` - The number of weeks.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns an error if any week cannot be staffed, as described for
+	/// [`solve()`](Self::solve()).
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, rota::{Person, RotaSolver}};
+	/// 
+	/// // Two people cover the weekend, one person each day
+	/// let people = vec![Person::new(Weekdays::WEEKENDS), Person::new(Weekdays::WEEKENDS)];
+	/// let rotas  = RotaSolver::new(people).with_headcount(Weekdays::WEEKENDS, 1).solve_weeks(2).unwrap();
+	/// 
+	/// let totals: Vec<u8> = (0..2).map(|person| rotas.iter().map(|rota| rota.days_for(person).unwrap().days()).sum()).collect();
+	/// assert_eq!(totals, vec![2, 2]);
+	/// ```
+	/// 
+	pub fn solve_weeks(&self, weeks: usize) -> Result<Vec<Rota>, RotaError> {
+		for (day, &required) in Weekdays::ALL_DAYS.iter().zip(&self.headcount) {
+			let available = self.people.iter().filter(|person| person.availability.contains(day) && person.max_days > 0).count();
+			if available < required {
+				return Err(RotaError::NotEnoughPeople { day, required, available });
+			}
+		}
+		let mut search = Search::new(self);
+		let mut rotas  = Vec::with_capacity(weeks);
+		for week in 1..=weeks {
+			search.start_week();
+			if let Some(index) = search.unstaffable(0) {
+				search.deepest = index;
+			} else if search.staff(0) {
+				search.finish_week();
+				rotas.push(Rota { assignments: search.assignments.clone() });
+				continue;
+			}
+			return Err(RotaError::LimitsExceeded {
+				day:      Weekdays::from_index(search.deepest),
+				required: self.headcount.get(search.deepest).copied().unwrap_or(0),
+				week,
+			});
+		}
+		Ok(rotas)
+	}
+}
+
+//		Search																	
+/// The state of the search for a rota.
+struct Search<'s> {
+	/// The days assigned to each person in the current week.
+	assignments: Vec<Weekdays>,
+	
+	/// The latest day, by position, that the search has tried to staff in the
+	/// current week.
+	deepest:     usize,
+	
+	/// The number of consecutive days each person has worked up to the day
+	/// being staffed.
+	runs:        Vec<u8>,
+	
+	/// The solver being searched for.
+	solver:      &'s RotaSolver,
+	
+	/// The number of days each person worked in earlier weeks.
+	totals:      Vec<usize>,
+}
+
+//󰭅		Search																	
+impl<'s> Search<'s> {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new search for the given solver.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `solver` - The solver being searched for.
+	/// 
+	fn new(solver: &'s RotaSolver) -> Self {
+		let people = solver.people.len();
+		Self {
+			assignments: vec![Weekdays::NONE; people],
+			deepest:     0,
+			runs:        vec![0; people],
+			solver,
+			totals:      vec![0; people],
+		}
+	}
+	
+	//		Public methods														
+	
+	//		finish_week															
+	/// Adds the days assigned in the current week to the running totals.
+	fn finish_week(&mut self) {
+		for (total, days) in self.totals.iter_mut().zip(&self.assignments) {
+			*total = total.saturating_add(usize::from(days.days()));
+		}
+	}
+	
+	//		staff																
+	/// Staffs the day at the given position and all later days, returning
+	/// whether this was possible.
+	/// 
+	/// People who are available on the same remaining days, can work the same
+	/// number of further days, have the same limit on consecutive days, and
+	/// have worked the same number of days in a row, are interchangeable for
+	/// the rest of the week. The choices for each day are therefore made
+	/// between groups of such people, rather than between every combination
+	/// of individuals, so that the same choice is never tried twice.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index` - The position of the day, from zero for Monday.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Totals are bounded by the number of weeks")]
+	fn staff(&mut self, index: usize) -> bool {
+		let Some(&required) = self.solver.headcount.get(index) else {
+			return true;
+		};
+		self.deepest     = self.deepest.max(index);
+		let day          = Weekdays::from_index(index);
+		let mut eligible = self.solver.people.iter()
+			.enumerate()
+			.filter(|&(person, details)| {
+				details.availability.contains(day)
+					&& self.worked(person) < details.max_days
+					&& self.runs.get(person).is_some_and(|&run| run < details.max_consecutive)
+			})
+			.map(|(person, _)| person)
+			.collect::<Vec<_>>();
+		if eligible.len() < required {
+			return false;
+		}
+		eligible.sort_by_key(|&person| (
+			self.totals.get(person).copied().unwrap_or(0) + usize::from(self.worked(person)),
+			person,
+		));
+		let mut groups: Vec<([u8; 4], Vec<usize>)> = Vec::new();
+		for person in eligible {
+			let state = self.state(person, index);
+			if let Some(&mut (_, ref mut members)) = groups.iter_mut().find(|group| group.0 == state) {
+				members.push(person);
+			} else {
+				groups.push((state, vec![person]));
+			}
+		}
+		let members = groups.into_iter().map(|(_, people)| people).collect::<Vec<_>>();
+		self.choose(index, &members, required, &mut Vec::with_capacity(required))
+	}
+	
+	//		start_week															
+	/// Clears the assignments ready for the next week, keeping the runs of
+	/// consecutive days.
+	fn start_week(&mut self) {
+		self.assignments.fill(Weekdays::NONE);
+		self.deepest = 0;
+	}
+	
+	//		Private methods														
+	
+	//		assign																
+	/// Assigns the chosen people to the day at the given position and staffs
+	/// the later days, returning whether this was possible.
+	/// 
+	/// If the later days cannot be staffed, the assignment is undone.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index`  - The position of the day.
+	/// * `picked` - The people chosen for the day.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Day positions and runs are bounded by the week")]
+	fn assign(&mut self, index: usize, picked: &[usize]) -> bool {
+		let day  = Weekdays::from_index(index);
+		let runs = self.runs.clone();
+		for (person, (run, days)) in self.runs.iter_mut().zip(self.assignments.iter_mut()).enumerate() {
+			if picked.contains(&person) {
+				*run  += 1;
+				*days |= day;
+			} else {
+				*run   = 0;
+			}
+		}
+		if let Some(later) = self.unstaffable(index + 1) {
+			self.deepest = self.deepest.max(later);
+		} else if self.staff(index + 1) {
+			return true;
+		}
+		self.runs = runs;
+		for &person in picked {
+			if let Some(days) = self.assignments.get_mut(person) {
+				*days &= !day;
+			}
+		}
+		false
+	}
+	
+	//		capacity															
+	/// Returns the most days the given person could work within a run of
+	/// days, given their availability and limits.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `person` - The position of the person.
+	/// * `days`   - The positions of the days in the run.
+	/// * `run`    - The number of days the person worked in a row just before
+	///   the run.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Runs and counts are bounded by the week")]
+	fn capacity(&self, person: usize, days: RangeInclusive<usize>, run: u8) -> usize {
+		let Some(details) = self.solver.people.get(person) else {
+			return 0;
+		};
+		let mut count   = 0_u8;
+		let mut current = run;
+		//	Working on every day allowed gives the most days under a limit on runs
+		for index in days {
+			if details.availability.contains(Weekdays::from_index(index)) && current < details.max_consecutive {
+				current += 1;
+				count   += 1;
+			} else {
+				current  = 0;
+			}
+		}
+		usize::from(count.min(details.max_days.saturating_sub(self.worked(person))))
+	}
+	
+	//		choose																
+	/// Chooses how many people to take from each group for the day at the
+	/// given position and assigns them, returning whether the rest of the
+	/// week could then be staffed.
+	/// 
+	/// Earlier groups are preferred, and within each group, the people earlier
+	/// in the list are taken first.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `index`  - The position of the day.
+	/// * `groups` - The remaining groups of interchangeable people.
+	/// * `needed` - The number of people still needed.
+	/// * `picked` - The people chosen so far.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Counts are bounded by the group sizes")]
+	fn choose(&mut self, index: usize, groups: &[Vec<usize>], needed: usize, picked: &mut Vec<usize>) -> bool {
+		if needed == 0 {
+			return self.assign(index, picked);
+		}
+		let Some((members, rest)) = groups.split_first() else {
+			return false;
+		};
+		if groups.iter().map(Vec::len).sum::<usize>() < needed {
+			return false;
+		}
+		for count in (0..=members.len().min(needed)).rev() {
+			picked.extend(members.iter().take(count));
+			let found = self.choose(index, rest, needed - count, picked);
+			picked.truncate(picked.len() - count);
+			if found {
+				return true;
+			}
+		}
+		false
+	}
+	
+	//		state																
+	/// Returns the state of the given person as far as the days after the
+	/// given position are concerned.
+	/// 
+	/// People with the same state are interchangeable for the rest of the
+	/// week. The state is made up of the remaining days the person is
+	/// available, the number of further days they can work, their limit on
+	/// consecutive days, and the number of days they have worked in a row.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `person` - The position of the person.
+	/// * `index`  - The position of the day being staffed.
+	/// 
+	fn state(&self, person: usize, index: usize) -> [u8; 4] {
+		let later = Weekdays::ALL_DAYS.iter()
+			.skip(index.saturating_add(1))
+			.fold(Weekdays::NONE, |days, day| days | day);
+		self.solver.people.get(person).map_or([0; 4], |details| [
+			(details.availability & later).0,
+			details.max_days.saturating_sub(self.worked(person)),
+			details.max_consecutive,
+			self.runs.get(person).copied().unwrap_or(0),
+		])
+	}
+	
+	//		unstaffable															
+	/// Returns the position of the first day, from the given position onwards,
+	/// that cannot be staffed whatever is chosen for the days before it.
+	/// 
+	/// Every run of days from the given position onwards is checked, to make
+	/// sure that the people could between them work as many days in the run
+	/// as are needed, without going over their limits on days or consecutive
+	/// days. This allows the search to give up on a choice early, rather than
+	/// finding out that a later day cannot be staffed after trying every
+	/// choice for the days in between.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `from` - The position of the first day to check.
+	/// 
+	fn unstaffable(&self, from: usize) -> Option<usize> {
+		(from..7).find(|&last| {
+			(from..=last).any(|first| {
+				let required = (first..=last).filter_map(|index| self.solver.headcount.get(index)).sum::<usize>();
+				let capacity = (0..self.solver.people.len())
+					.map(|person| {
+						//	Only a run starting now carries on from the current runs
+						let run = if first == from { self.runs.get(person).copied().unwrap_or(0) } else { 0 };
+						self.capacity(person, first..=last, run)
+					})
+					.sum::<usize>();
+				required > capacity
+			})
+		})
+	}
+	
+	//		worked																
+	/// Returns the number of days the given person has worked in the current
+	/// week.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `person` - The position of the person.
+	/// 
+	fn worked(&self, person: usize) -> u8 {
+		self.assignments.get(person).map_or(0, Weekdays::days)
+	}
+}
+
+
+
+//		Functions
+
+//		day_name																
+/// Returns the English name of the given day, or of the earliest day if more
+/// than one is given.
+/// 
+/// # Parameters
+/// 
+/// * `day` - The day to name.
+/// 
+fn day_name(day: Weekdays) -> &'static str {
+	day.first_index().and_then(|index| Weekdays::DISPLAY_NAMES.get(index)).copied().unwrap_or("no day")
+}
@@ -0,0 +1,527 @@
+//! Conversion to and from iCalendar recurrence rules.
+//! 
+//! [RFC 5545](https://www.rfc-editor.org/rfc/rfc5545) describes recurring
+//! events using `RRULE` lines such as `RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR`. The
+//! [`parse_byday()`] and [`to_byday()`] functions convert [`Weekdays`] to and
+//! from the `BYDAY` value on its own.
+//! 
+//! When the `chrono` feature is enabled, the [`WeeklyRule`] struct can parse
+//! and write whole weekly rules, including the interval, week start, and
+//! `COUNT` or `UNTIL` limits, and expand them to concrete dates. Only weekly
+//! rules are supported, and any other parts are rejected with a
+//! [`RruleError`] describing the problem.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/rrule.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+};
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
+#[cfg(feature = "chrono")]
+use core::str::FromStr;
+
+
+
+//		Constants
+
+/// The two-letter day codes used by `BYDAY` and `WKST`, from Monday to Sunday.
+const CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+
+/// Rule parts defined by RFC 5545 that weekly rules do not support.
+#[cfg(feature = "chrono")]
+const UNSUPPORTED_PARTS: [&str; 8] = [
+	"BYHOUR",
+	"BYMINUTE",
+	"BYMONTH",
+	"BYMONTHDAY",
+	"BYSECOND",
+	"BYSETPOS",
+	"BYWEEKNO",
+	"BYYEARDAY",
+];
+
+
+
+//		Enums
+
+//		RruleError																
+/// The errors that can occur when parsing a recurrence rule.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum RruleError {
+	/// Both `COUNT` and `UNTIL` were given, which RFC 5545 does not allow.
+	CountAndUntil,
+	
+	/// The named rule part was given more than once.
+	DuplicatePart(String),
+	
+	/// The `BYDAY` value contained an entry that is not a plain day code. Day
+	/// codes with an ordinal, such as `1MO`, are not valid in weekly rules.
+	InvalidDay(String),
+	
+	/// The rule part was not in `NAME=VALUE` form.
+	InvalidPart(String),
+	
+	/// The named rule part had a value that could not be parsed.
+	InvalidValue {
+		/// The name of the rule part.
+		name:  String,
+		
+		/// The value that could not be parsed.
+		value: String,
+	},
+	
+	/// The rule did not have a `FREQ` part.
+	MissingFrequency,
+	
+	/// The named rule part is not defined by RFC 5545.
+	UnknownPart(String),
+	
+	/// The frequency was not `WEEKLY`.
+	UnsupportedFrequency(String),
+	
+	/// The named rule part is defined by RFC 5545, but is not supported by
+	/// weekly rules.
+	UnsupportedPart(String),
+}
+
+//󰭅		Display																	
+impl Display for RruleError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::CountAndUntil                        => write!(f, "COUNT and UNTIL cannot both be given"),
+			Self::DuplicatePart(ref name)              => write!(f, "Duplicate rule part: {name}"),
+			Self::InvalidDay(ref day)                  => write!(f, "Invalid BYDAY entry: {day}"),
+			Self::InvalidPart(ref part)                => write!(f, "Invalid rule part: {part}"),
+			Self::InvalidValue { ref name, ref value } => write!(f, "Invalid {name} value: {value}"),
+			Self::MissingFrequency                     => write!(f, "Missing FREQ rule part"),
+			Self::UnknownPart(ref name)                => write!(f, "Unknown rule part: {name}"),
+			Self::UnsupportedFrequency(ref freq)       => write!(f, "Unsupported frequency: {freq}"),
+			Self::UnsupportedPart(ref name)            => write!(f, "Unsupported rule part: {name}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for RruleError {}
+
+
+
+//		Structs
+
+//		WeeklyRule																
+/// A weekly recurrence rule, as used in iCalendar `RRULE` lines.
+/// 
+/// The rule repeats on the given days every `interval` weeks, with weeks
+/// beginning on the `week_start` day. It can optionally be limited by a
+/// `count` of occurrences, or by an `until` date, but not both.
+/// 
+/// If no days are given, the rule repeats on the weekday of the start date,
+/// which matches the behaviour of RFC 5545 when `BYDAY` is omitted.
+/// 
+/// Rules can be parsed from strings with or without the `RRULE:` prefix, and
+/// are written back without it by [`Display`]. `UNTIL` values can be dates or
+/// date-times, but only the date is kept, and dates are always written.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveDate;
+/// use weekdays::{Weekdays, rrule::WeeklyRule};
+/// 
+/// let rule: WeeklyRule = "RRULE:FREQ=WEEKLY;BYDAY=MO,FR;INTERVAL=2;COUNT=3".parse().unwrap();
+/// let start            = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// 
+/// assert_eq!(rule.days(),     Weekdays::parse_const("mon, fri"));
+/// assert_eq!(rule.interval(), 2);
+/// assert_eq!(rule.occurrences(start).collect::<Vec<_>>(), vec![
+///     NaiveDate::from_ymd_opt(2024, 1,  1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1,  5).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+/// ]);
+/// assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=3");
+/// ```
+/// 
+#[cfg(feature = "chrono")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WeeklyRule {
+	/// The maximum number of occurrences.
+	count:      Option<u32>,
+	
+	/// The days on which the rule repeats.
+	days:       Weekdays,
+	
+	/// The number of weeks between repetitions. This is never zero.
+	interval:   u32,
+	
+	/// The last date on which the rule can repeat.
+	until:      Option<NaiveDate>,
+	
+	/// The day on which each week begins.
+	week_start: Weekday,
+}
+
+//󰭅		WeeklyRule																
+#[cfg(feature = "chrono")]
+impl WeeklyRule {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`WeeklyRule`] with no limit, and weeks starting on
+	/// Monday.
+	/// 
+	/// Returns [`None`] if the interval is zero.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`     - The days on which the rule repeats.
+	/// * `interval` - The number of weeks between repetitions.
+	/// 
+	#[must_use]
+	pub const fn new(days: Weekdays, interval: u32) -> Option<Self> {
+		if interval == 0 {
+			return None;
+		}
+		Some(Self {
+			count:      None,
+			days,
+			interval,
+			until:      None,
+			week_start: Weekday::Mon,
+		})
+	}
+	
+	//		with_count															
+	/// Limits the rule to the given number of occurrences.
+	/// 
+	/// Any `until` date is removed, as the two cannot be combined.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `count` - The maximum number of occurrences.
+	/// 
+	#[must_use]
+	pub const fn with_count(mut self, count: u32) -> Self {
+		self.count = Some(count);
+		self.until = None;
+		self
+	}
+	
+	//		with_until															
+	/// Limits the rule to dates on or before the given date.
+	/// 
+	/// Any `count` is removed, as the two cannot be combined.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `until` - The last date on which the rule can repeat.
+	/// 
+	#[must_use]
+	pub const fn with_until(mut self, until: NaiveDate) -> Self {
+		self.count = None;
+		self.until = Some(until);
+		self
+	}
+	
+	//		with_week_start														
+	/// Sets the day on which each week begins.
+	/// 
+	/// This only affects rules with an interval greater than one, where it
+	/// decides which days fall in the same week.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The first day of each week.
+	/// 
+	#[must_use]
+	pub const fn with_week_start(mut self, day: Weekday) -> Self {
+		self.week_start = day;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		count																
+	/// Returns the maximum number of occurrences, if limited.
+	#[must_use]
+	pub const fn count(&self) -> Option<u32> {
+		self.count
+	}
+	
+	//		days																
+	/// Returns the days on which the rule repeats.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		interval															
+	/// Returns the number of weeks between repetitions.
+	#[must_use]
+	pub const fn interval(&self) -> u32 {
+		self.interval
+	}
+	
+	//		occurrences															
+	/// Returns an iterator over the dates on which the rule repeats.
+	/// 
+	/// The first week of the rule is the week containing the start date, as
+	/// divided by the week start day. Dates before the start date are never
+	/// included, even if they fall in the first week. As with `DTSTART` in RFC
+	/// 5545, the start date itself is always the first occurrence, and counts
+	/// towards any `count`, even if it is not on one of the days.
+	/// 
+	/// If the rule has neither a `count` nor an `until` date, the iterator only
+	/// ends at the limit of supported dates, so should be bounded by the
+	/// caller.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `start` - The start date of the recurrence, as given by `DTSTART`.
+	/// 
+	pub fn occurrences(&self, start: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+		let rule  = *self;
+		let days  = if self.days.is_empty() { Weekdays::from(start.weekday()) } else { self.days };
+		let first = self.week_start_of(start);
+		start.iter_days()
+			.take_while(move |&date| rule.until.map_or(true, |until| date <= until))
+			.filter(move |&date| date == start || (days.contains(Weekdays::from(date.weekday())) && rule.is_active_week(first, date)))
+			.take(self.count.map_or(usize::MAX, |count| usize::try_from(count).unwrap_or(usize::MAX)))
+	}
+	
+	//		until																
+	/// Returns the last date on which the rule can repeat, if limited.
+	#[must_use]
+	pub const fn until(&self) -> Option<NaiveDate> {
+		self.until
+	}
+	
+	//		week_start															
+	/// Returns the day on which each week begins.
+	#[must_use]
+	pub const fn week_start(&self) -> Weekday {
+		self.week_start
+	}
+	
+	//		Private methods														
+	
+	//		is_active_week														
+	/// Checks if the date falls in a week on which the rule repeats.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `first` - The first day of the first week of the rule.
+	/// * `date`  - The date to check.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Interval is never zero")]
+	#[expect(clippy::integer_division,        reason = "Only whole weeks are wanted")]
+	fn is_active_week(&self, first: NaiveDate, date: NaiveDate) -> bool {
+		date.signed_duration_since(first).num_days() / 7 % i64::from(self.interval) == 0
+	}
+	
+	//		parse_until															
+	/// Parses an `UNTIL` value, which can be a date or a date-time.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `value` - The value to parse.
+	/// 
+	fn parse_until(value: &str) -> Option<NaiveDate> {
+		if value.len() == 8 {
+			NaiveDate::parse_from_str(value, "%Y%m%d").ok()
+		} else {
+			let local = value.strip_suffix('Z').unwrap_or(value);
+			NaiveDateTime::parse_from_str(local, "%Y%m%dT%H%M%S").ok().map(|datetime| datetime.date())
+		}
+	}
+	
+	//		week_start_of														
+	/// Returns the first day of the week containing the given date.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `date` - Any date in the week.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "Values are always less than 7")]
+	fn week_start_of(&self, date: NaiveDate) -> NaiveDate {
+		let offset = (7 + date.weekday().num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+		date.checked_sub_days(Days::new(u64::from(offset))).unwrap_or(date)
+	}
+}
+
+//󰭅		Display																	
+#[cfg(feature = "chrono")]
+impl Display for WeeklyRule {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "FREQ=WEEKLY")?;
+		if self.interval != 1 {
+			write!(f, ";INTERVAL={}", self.interval)?;
+		}
+		if !self.days.is_empty() {
+			write!(f, ";BYDAY={}", to_byday(self.days))?;
+		}
+		if self.week_start != Weekday::Mon {
+			write!(f, ";WKST={}", to_byday(Weekdays::from(self.week_start)))?;
+		}
+		if let Some(count) = self.count {
+			write!(f, ";COUNT={count}")?;
+		}
+		if let Some(until) = self.until {
+			write!(f, ";UNTIL={}", until.format("%Y%m%d"))?;
+		}
+		Ok(())
+	}
+}
+
+//󰭅		FromStr																	
+#[cfg(feature = "chrono")]
+impl FromStr for WeeklyRule {
+	type Err = RruleError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let rule           = s.get(..6)
+			.filter(|prefix| prefix.eq_ignore_ascii_case("RRULE:"))
+			.and_then(|_| s.get(6..))
+			.unwrap_or(s);
+		let mut seen       = Vec::<String>::new();
+		let mut frequency  = false;
+		let mut days       = Weekdays::NONE;
+		let mut interval   = 1;
+		let mut week_start = Weekday::Mon;
+		let mut count      = None;
+		let mut until      = None;
+		for part in rule.split(';') {
+			let Some((raw_name, value)) = part.split_once('=') else {
+				return Err(RruleError::InvalidPart(part.to_owned()));
+			};
+			let name    = raw_name.to_ascii_uppercase();
+			let invalid = || RruleError::InvalidValue { name: name.clone(), value: value.to_owned() };
+			if seen.contains(&name) {
+				return Err(RruleError::DuplicatePart(name));
+			}
+			match name.as_str() {
+				"FREQ"     => {
+					if !value.eq_ignore_ascii_case("WEEKLY") {
+						return Err(RruleError::UnsupportedFrequency(value.to_owned()));
+					}
+					frequency = true;
+				}
+				"BYDAY"    => days       = parse_byday(value)?,
+				"COUNT"    => count      = Some(value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?),
+				"INTERVAL" => interval   = value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?,
+				"UNTIL"    => until      = Some(Self::parse_until(value).ok_or_else(invalid)?),
+				"WKST"     => week_start = parse_code(value)
+					.and_then(|day| day.to_chrono_vec().first().copied())
+					.ok_or_else(invalid)?,
+				other if UNSUPPORTED_PARTS.contains(&other) => return Err(RruleError::UnsupportedPart(name)),
+				_          => return Err(RruleError::UnknownPart(name)),
+			}
+			seen.push(name);
+		}
+		if !frequency {
+			return Err(RruleError::MissingFrequency);
+		}
+		if count.is_some() && until.is_some() {
+			return Err(RruleError::CountAndUntil);
+		}
+		Ok(Self { count, days, interval, until, week_start })
+	}
+}
+
+
+
+//		Functions
+
+//		parse_byday																
+/// Parses a `BYDAY` value into a set of days.
+/// 
+/// The value is a comma-separated list of two-letter day codes, such as
+/// `MO,WE,FR`. Codes are matched without regard to case, and repeated codes
+/// are allowed. Day codes with an ordinal, such as `1MO` or `-1FR`, are only
+/// meaningful in monthly and yearly rules, and are rejected.
+/// 
+/// # Parameters
+/// 
+/// * `value` - The `BYDAY` value, without the `BYDAY=` prefix.
+/// 
+/// # Errors
+/// 
+/// Returns [`RruleError::InvalidDay`] if any entry is not a day code.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, rrule::parse_byday};
+/// 
+/// assert_eq!(parse_byday("MO,WE,FR"), Ok(Weekdays::parse_const("mon, wed, fri")));
+/// assert!(parse_byday("1MO").is_err());
+/// ```
+/// 
+pub fn parse_byday(value: &str) -> Result<Weekdays, RruleError> {
+	value.split(',').try_fold(Weekdays::NONE, |days, code| {
+		parse_code(code).map(|day| days | day).ok_or_else(|| RruleError::InvalidDay(code.to_owned()))
+	})
+}
+
+//		to_byday																
+/// Formats a set of days as a `BYDAY` value.
+/// 
+/// The days are written as a comma-separated list of two-letter day codes,
+/// from Monday to Sunday. If no days are set, the result is empty.
+/// 
+/// # Parameters
+/// 
+/// * `days` - The days to format.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, rrule::to_byday};
+/// 
+/// assert_eq!(to_byday(Weekdays::parse_const("mon, wed, fri")), "MO,WE,FR");
+/// assert_eq!(to_byday(Weekdays::WEEKENDS),                     "SA,SU");
+/// ```
+/// 
+#[must_use]
+pub fn to_byday(days: Weekdays) -> String {
+	Weekdays::ALL_DAYS
+		.iter()
+		.zip(CODES)
+		.filter(|&(day, _)| days.contains(day))
+		.map(|(_, code)| code)
+		.collect::<Vec<_>>()
+		.join(",")
+}
+
+//		parse_code																
+/// Parses a single two-letter day code.
+/// 
+/// # Parameters
+/// 
+/// * `code` - The day code, such as `MO`.
+/// 
+fn parse_code(code: &str) -> Option<Weekdays> {
+	Weekdays::ALL_DAYS
+		.iter()
+		.zip(CODES)
+		.find(|&(_, candidate)| candidate.eq_ignore_ascii_case(code))
+		.map(|(day, _)| day)
+}
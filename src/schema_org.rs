@@ -0,0 +1,498 @@
+//! Conversion to and from schema.org opening hours specifications.
+//! 
+//! Search engines read opening hours from JSON-LD blocks using the
+//! [`OpeningHoursSpecification`](https://schema.org/OpeningHoursSpecification)
+//! type, such as:
+//! 
+//! ```json
+//! {
+//!     "@type": "OpeningHoursSpecification",
+//!     "dayOfWeek": ["https://schema.org/Monday", "https://schema.org/Tuesday"],
+//!     "opens": "09:00",
+//!     "closes": "17:00"
+//! }
+//! ```
+//! 
+//! The [`OpeningHoursSpecification`] struct models a single block, and can be
+//! serialised to and deserialised from this form. The
+//! [`from_opening_hours()`] and [`to_opening_hours()`] functions convert whole
+//! sets of blocks to and from [`OpeningHours`].
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/schema_org.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{MINUTES_PER_DAY, Weekdays, format_clock, opening_hours::OpeningHours};
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+	ops::Range,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+
+
+
+//		Constants
+
+/// The base URL for schema.org terms.
+const BASE_URL: &str = "https://schema.org/";
+
+/// The schema.org name for public holidays, which can be given in place of a
+/// day of the week.
+const HOLIDAYS: &str = "PublicHolidays";
+
+/// The prefixes that can come before a schema.org term.
+const PREFIXES: [&str; 3] = [BASE_URL, "http://schema.org/", "schema:"];
+
+/// The schema.org name for the type.
+const TYPE: &str = "OpeningHoursSpecification";
+
+
+
+//		Enums
+
+//		RawDays																	
+/// The `dayOfWeek` property, which can be a single value or a list.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum RawDays {
+	/// A single day.
+	One(String),
+	
+	/// A list of days.
+	Many(Vec<String>),
+}
+
+//		SchemaOrgError															
+/// The errors that can occur when converting between specifications and
+/// opening hours.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SchemaOrgError {
+	/// The time range cannot be given as opening and closing times, as it is
+	/// empty, out of bounds, or longer than a day.
+	InvalidHours(Range<u16>),
+}
+
+//󰭅		Display																	
+impl Display for SchemaOrgError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::InvalidHours(ref range) => write!(f, "Invalid time range for opening hours: {}-{}", format_clock(range.start), format_clock(range.end)),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for SchemaOrgError {}
+
+
+
+//		Structs
+
+//		OpeningHoursSpecification												
+/// A single schema.org opening hours specification.
+/// 
+/// Each specification gives one time range for a set of days, and optionally
+/// for public holidays too. A day that is open for more than one range needs a
+/// specification for each range. Time ranges are given in minutes from
+/// midnight, in the same way as for [`OpeningHours`], and can run past
+/// midnight into the following day.
+/// 
+/// When serialised, times are written to the minute, such as `09:00`, and days
+/// are written as schema.org URLs. Following the usual conventions, a day that
+/// is open all day is written as opening at `00:00` and closing at `23:59`,
+/// and a day that is closed is written as opening and closing at `00:00`.
+/// 
+/// When deserialised, days can be given as URLs, with the `schema:` prefix,
+/// or as bare names, and either as a single value or a list. Times can include
+/// seconds, which are ignored. Specifications that are only valid for part of
+/// the year, using `validFrom` or `validThrough`, are not supported.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, schema_org::OpeningHoursSpecification};
+/// 
+/// let spec = OpeningHoursSpecification::new(Weekdays::WEEKENDS, Some(600..960)).unwrap();
+/// let json = serde_json::to_string(&spec).unwrap();
+/// 
+/// assert_eq!(json, concat!(
+///     r#"{"@type":"OpeningHoursSpecification","#,
+///     r#""dayOfWeek":["https://schema.org/Saturday","https://schema.org/Sunday"],"#,
+///     r#""opens":"10:00","closes":"16:00"}"#,
+/// ));
+/// 
+/// let parsed: OpeningHoursSpecification = serde_json::from_str(
+///     r#"{"dayOfWeek":["Saturday","Sunday"],"opens":"10:00:00","closes":"16:00:00"}"#,
+/// ).unwrap();
+/// 
+/// assert_eq!(parsed, spec);
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OpeningHoursSpecification {
+	/// The days of the week that the specification applies to.
+	days:            Weekdays,
+	
+	/// The time range, in minutes from midnight, or [`None`] if closed.
+	hours:           Option<Range<u16>>,
+	
+	/// Whether the specification also applies to public holidays.
+	public_holidays: bool,
+}
+
+//󰭅		OpeningHoursSpecification												
+impl OpeningHoursSpecification {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`OpeningHoursSpecification`] for the given days.
+	/// 
+	/// Returns [`None`] if no days are given, or if the time range is empty,
+	/// starts at or after midnight at the end of the day, or is longer than a
+	/// day, as it could not then be given as opening and closing times.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days of the week that the specification applies to.
+	/// * `hours` - The time range, in minutes from midnight, or [`None`] if the
+	///   days are closed.
+	/// 
+	#[must_use]
+	pub fn new(days: Weekdays, hours: Option<Range<u16>>) -> Option<Self> {
+		if days.is_empty() {
+			return None;
+		}
+		Self::build(days, hours, false)
+	}
+	
+	//		for_public_holidays													
+	/// Creates a new [`OpeningHoursSpecification`] for public holidays only.
+	/// 
+	/// Returns [`None`] if the time range is invalid, as described for
+	/// [`new()`](Self::new()).
+	/// 
+	/// # Parameters
+	/// 
+	/// * `hours` - The time range, in minutes from midnight, or [`None`] if
+	///   public holidays are closed.
+	/// 
+	#[must_use]
+	pub fn for_public_holidays(hours: Option<Range<u16>>) -> Option<Self> {
+		Self::build(Weekdays::NONE, hours, true)
+	}
+	
+	//		with_public_holidays												
+	/// Makes the specification apply to public holidays as well as its days.
+	#[must_use]
+	pub const fn with_public_holidays(mut self) -> Self {
+		self.public_holidays = true;
+		self
+	}
+	
+	//		Public methods														
+	
+	//		days																
+	/// Returns the days of the week that the specification applies to.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		hours																
+	/// Returns the time range, in minutes from midnight, or [`None`] if closed.
+	#[must_use]
+	pub fn hours(&self) -> Option<Range<u16>> {
+		self.hours.clone()
+	}
+	
+	//		public_holidays														
+	/// Checks if the specification applies to public holidays.
+	#[must_use]
+	pub const fn public_holidays(&self) -> bool {
+		self.public_holidays
+	}
+	
+	//		Private methods														
+	
+	//		build																
+	/// Creates a new [`OpeningHoursSpecification`] after checking the time
+	/// range.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`            - The days of the week.
+	/// * `hours`           - The time range, if open.
+	/// * `public_holidays` - Whether public holidays are included.
+	/// 
+	fn build(days: Weekdays, hours: Option<Range<u16>>, public_holidays: bool) -> Option<Self> {
+		let valid = hours.as_ref().map_or(true, |range| {
+			!range.is_empty() && range.start < MINUTES_PER_DAY && range.end.saturating_sub(range.start) <= MINUTES_PER_DAY
+		});
+		valid.then_some(Self { days, hours, public_holidays })
+	}
+}
+
+//󰭅		Deserialize																
+impl<'de> Deserialize<'de> for OpeningHoursSpecification {
+	//		deserialize															
+	#[expect(clippy::arithmetic_side_effects, reason = "Times are at most 24:00, so the sum fits")]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+		where D: Deserializer<'de>,
+	{
+		let raw = RawSpecification::deserialize(deserializer)?;
+		if let Some(kind) = raw.kind.filter(|kind| strip_prefix(kind) != TYPE) {
+			return Err(DeError::custom(format!("invalid type: {kind}")));
+		}
+		if raw.valid_from.is_some() || raw.valid_through.is_some() {
+			return Err(DeError::custom("seasonal opening hours are not supported"));
+		}
+		let names = match raw.day_of_week {
+			RawDays::One(name)   => vec![name],
+			RawDays::Many(names) => names,
+		};
+		let (days, public_holidays) = names.iter().try_fold((Weekdays::NONE, false), |(days, holidays), name| {
+			let bare = strip_prefix(name);
+			if bare.eq_ignore_ascii_case(HOLIDAYS) {
+				return Ok((days, true));
+			}
+			Weekdays::DISPLAY_NAMES.iter()
+				.position(|candidate| candidate.eq_ignore_ascii_case(bare))
+				.map(Weekdays::from_index)
+				.map(|day| (days | day, holidays))
+				.ok_or_else(|| DeError::custom(format!("invalid day of week: {name}")))
+		})?;
+		if days.is_empty() && !public_holidays {
+			return Err(DeError::custom("no days of the week given"));
+		}
+		let opens  = parse_time(&raw.opens).ok_or_else(|| DeError::custom(format!("invalid opening time: {}", raw.opens)))?;
+		let closes = parse_time(&raw.closes).ok_or_else(|| DeError::custom(format!("invalid closing time: {}", raw.closes)))?;
+		let hours  = match (opens, closes) {
+			(0, 0)                       => None,
+			(0, 1_439)                   => Some(0..MINUTES_PER_DAY),
+			(start, end) if end <= start => Some(start..end + MINUTES_PER_DAY),
+			(start, end)                 => Some(start..end),
+		};
+		Self::build(days, hours, public_holidays).ok_or_else(|| DeError::custom("invalid opening hours"))
+	}
+}
+
+//󰭅		Serialize																
+impl Serialize for OpeningHoursSpecification {
+	//		serialize															
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let names           = Weekdays::ALL_DAYS.iter()
+			.zip(Weekdays::DISPLAY_NAMES)
+			.filter(|&(day, _)| self.days.contains(day))
+			.map(|(_, name)| name)
+			.chain(self.public_holidays.then_some(HOLIDAYS))
+			.map(|name| format!("{BASE_URL}{name}"))
+			.collect();
+		let (opens, closes) = match self.hours {
+			Some(ref range) if *range == (0..MINUTES_PER_DAY) => ("00:00".to_owned(), "23:59".to_owned()),
+			//	Closing times on the following day, including midnight at the end
+			//	of the day, are given as times on that day
+			Some(ref range)                                   => (
+				format_clock(range.start),
+				format_clock(range.end.checked_sub(MINUTES_PER_DAY).unwrap_or(range.end)),
+			),
+			None                                              => ("00:00".to_owned(), "00:00".to_owned()),
+		};
+		RawSpecification {
+			kind:          Some(TYPE.to_owned()),
+			day_of_week:   RawDays::Many(names),
+			opens,
+			closes,
+			valid_from:    None,
+			valid_through: None,
+		}.serialize(serializer)
+	}
+}
+
+//		RawSpecification														
+/// The unvalidated form of an [`OpeningHoursSpecification`], as it appears in
+/// JSON-LD.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSpecification {
+	/// The schema.org type, which is optional when reading.
+	#[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+	kind:          Option<String>,
+	
+	/// The days of the week, and possibly public holidays.
+	day_of_week:   RawDays,
+	
+	/// The opening time.
+	opens:         String,
+	
+	/// The closing time.
+	closes:        String,
+	
+	/// The first date on which the specification applies.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	valid_from:    Option<String>,
+	
+	/// The last date on which the specification applies.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	valid_through: Option<String>,
+}
+
+
+
+//		Functions
+
+//		from_opening_hours														
+/// Converts opening hours into a list of specifications.
+/// 
+/// Days that share the same hours are grouped together, with one
+/// specification for each time range. Public holidays are added to a group if
+/// they share its hours, or are given their own specifications otherwise.
+/// Closed days are left out, apart from public holidays, which are written as
+/// closed if they were given as closed.
+/// 
+/// # Parameters
+/// 
+/// * `hours` - The opening hours to convert.
+/// 
+/// # Errors
+/// 
+/// Returns [`SchemaOrgError::InvalidHours`] if a time range is longer than a
+/// day, as it cannot then be given as opening and closing times.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, opening_hours::OpeningHours, schema_org::from_opening_hours};
+/// 
+/// let hours: OpeningHours = "Mo-Fr 09:00-17:00; Sa,PH 10:00-14:00".parse().unwrap();
+/// let specs               = from_opening_hours(&hours).unwrap();
+/// 
+/// assert_eq!(specs.len(),     2);
+/// assert_eq!(specs[0].days(), Weekdays::WEEKDAYS);
+/// assert_eq!(specs[1].days(), Weekdays::SATURDAY);
+/// assert!(specs[1].public_holidays());
+/// ```
+/// 
+pub fn from_opening_hours(hours: &OpeningHours) -> Result<Vec<OpeningHoursSpecification>, SchemaOrgError> {
+	let holidays   = hours.holidays();
+	let mut joined = false;
+	let mut specs  = Vec::new();
+	for (days, ranges) in hours.rules() {
+		let shared = holidays == Some(ranges);
+		joined    |= shared;
+		for range in ranges {
+			let spec = OpeningHoursSpecification::build(days, Some(range.clone()), shared);
+			specs.push(spec.ok_or_else(|| SchemaOrgError::InvalidHours(range.clone()))?);
+		}
+	}
+	if let (Some(ranges), false) = (holidays, joined) {
+		for range in ranges {
+			let spec = OpeningHoursSpecification::build(Weekdays::NONE, Some(range.clone()), true);
+			specs.push(spec.ok_or_else(|| SchemaOrgError::InvalidHours(range.clone()))?);
+		}
+		if ranges.is_empty() {
+			//	Closed holidays still need a specification to say so
+			specs.push(OpeningHoursSpecification { days: Weekdays::NONE, hours: None, public_holidays: true });
+		}
+	}
+	Ok(specs)
+}
+
+//		to_opening_hours														
+/// Converts a list of specifications into opening hours.
+/// 
+/// The time ranges for each day are combined, so a day that appears in more
+/// than one specification is open for all of their ranges. Specifications
+/// that are closed add no ranges, except that public holidays are marked as
+/// closed if no other specification opens them.
+/// 
+/// # Parameters
+/// 
+/// * `specs` - The specifications to convert.
+/// 
+/// # Errors
+/// 
+/// Returns [`SchemaOrgError::InvalidHours`] if a time range cannot be used as
+/// opening hours.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, schema_org::{OpeningHoursSpecification, to_opening_hours}};
+/// 
+/// let specs = [
+///     OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(540..720)).unwrap(),
+///     OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(780..1020)).unwrap(),
+///     OpeningHoursSpecification::for_public_holidays(None).unwrap(),
+/// ];
+/// 
+/// assert_eq!(to_opening_hours(&specs).unwrap().to_string(), "Mo-Fr 09:00-12:00,13:00-17:00; PH off");
+/// ```
+/// 
+pub fn to_opening_hours(specs: &[OpeningHoursSpecification]) -> Result<OpeningHours, SchemaOrgError> {
+	let mut opening = OpeningHours::new();
+	for spec in specs {
+		let invalid = || SchemaOrgError::InvalidHours(spec.hours.clone().unwrap_or_default());
+		let added   = |ranges: &[Range<u16>]| ranges.iter().cloned().chain(spec.hours.clone()).collect::<Vec<_>>();
+		for day in &spec.days {
+			let ranges = added(opening.hours(day));
+			opening    = opening.with_hours(day, &ranges).ok_or_else(invalid)?;
+		}
+		if spec.public_holidays {
+			let ranges = added(opening.holidays().unwrap_or_default());
+			opening    = opening.with_holidays(&ranges).ok_or_else(invalid)?;
+		}
+	}
+	Ok(opening)
+}
+
+//		parse_time																
+/// Parses a time such as `08:30` or `08:30:00` into a number of minutes from
+/// midnight, ignoring any seconds.
+/// 
+/// # Parameters
+/// 
+/// * `text` - The time to parse.
+/// 
+#[expect(clippy::arithmetic_side_effects, reason = "Hours and minutes are checked first")]
+fn parse_time(text: &str) -> Option<u16> {
+	let mut parts = text.split(':');
+	let numbers   = parts
+		.by_ref()
+		.take(3)
+		.map(|part| (part.len() == 2).then(|| part.parse::<u16>().ok()).flatten())
+		.collect::<Option<Vec<_>>>()?;
+	if parts.next().is_some() {
+		return None;
+	}
+	let (hour, minute, second) = match *numbers.as_slice() {
+		[hour, minute]         => (hour, minute, 0),
+		[hour, minute, second] => (hour, minute, second),
+		_                      => return None,
+	};
+	//	Midnight at the end of the day can be given as 24:00
+	let valid = minute < 60 && second < 60 && (hour < 24 || (hour == 24 && minute == 0 && second == 0));
+	valid.then(|| hour * 60 + minute)
+}
+
+//		strip_prefix															
+/// Removes any schema.org prefix from a term.
+/// 
+/// # Parameters
+/// 
+/// * `term` - The term, such as `https://schema.org/Monday`.
+/// 
+fn strip_prefix(term: &str) -> &str {
+	PREFIXES.iter().find_map(|prefix| term.strip_prefix(prefix)).unwrap_or(term)
+}
@@ -0,0 +1,315 @@
+//! Bulk statistics over many sets of days.
+//! 
+//! The main type provided is the [`WeekdaysStats`] struct, which accumulates
+//! statistics over large numbers of [`Weekdays`] values.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/stats.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::Weekdays;
+use core::cmp::Reverse;
+
+
+
+//		Structs
+
+//		WeekdaysStats															
+/// An accumulator of statistics over many sets of days.
+/// 
+/// As there are only 128 possible [`Weekdays`] values, this simply counts how
+/// many times each value has been seen. All of the statistics are derived from
+/// those counts, which makes adding values cheap, and means that accumulators
+/// built in parallel can be combined with [`merge()`](Self::merge()).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, stats::WeekdaysStats};
+/// 
+/// let mut stats = WeekdaysStats::new();
+/// stats.add_slice(&[Weekdays::WEEKDAYS, Weekdays::WEEKDAYS, Weekdays::WEEKENDS]);
+/// stats.add(Weekdays::MONDAY);
+/// 
+/// assert_eq!(stats.total(),                       4);
+/// assert_eq!(stats.day_count(Weekdays::MONDAY),   3);
+/// assert_eq!(stats.top_patterns(1),               vec![(Weekdays::WEEKDAYS, 2)]);
+/// assert_eq!(stats.mean_days(),                   Some(3.25));
+/// ```
+/// 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct WeekdaysStats {
+	/// The number of times each set of days has been seen, indexed by the
+	/// bit-mapped value.
+	patterns: [u64; 128],
+}
+
+//󰭅		WeekdaysStats															
+impl WeekdaysStats {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new, empty [`WeekdaysStats`] accumulator.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			patterns: [0; 128],
+		}
+	}
+	
+	//		Public methods														
+	
+	//		add																	
+	/// Adds a set of days to the statistics.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to add.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "A u64 count cannot realistically overflow")]
+	#[expect(clippy::indexing_slicing,        reason = "Weekdays values are always 7-bit")]
+	pub fn add(&mut self, days: Weekdays) {
+		self.patterns[usize::from(days.0)] += 1;
+	}
+	
+	//		add_slice															
+	/// Adds a slice of sets of days to the statistics.
+	/// 
+	/// This is the preferred way to ingest large amounts of data, as it is a
+	/// single tight loop over the slice without bounds checks, which the
+	/// compiler can optimise well.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to add.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "A u64 count cannot realistically overflow")]
+	pub fn add_slice(&mut self, days: &[Weekdays]) {
+		for &Weekdays(pattern) in days {
+			//	Masking makes the bounds check redundant
+			if let Some(count) = self.patterns.get_mut(usize::from(pattern & 0b111_1111)) {
+				*count += 1;
+			}
+		}
+	}
+	
+	//		count																
+	/// Returns the number of times the exact set of days has been seen.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days` - The days to look up.
+	/// 
+	#[expect(clippy::indexing_slicing, reason = "Weekdays values are always 7-bit")]
+	#[must_use]
+	pub const fn count(&self, days: Weekdays) -> u64 {
+		self.patterns[days.0 as usize]
+	}
+	
+	//		day_count															
+	/// Returns the number of sets of days that include the given day.
+	/// 
+	/// If more than one day is given, the result is the number of sets that
+	/// include all of them.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `day` - The day or days to count.
+	/// 
+	#[must_use]
+	pub fn day_count(&self, day: Weekdays) -> u64 {
+		self.entries()
+			.filter(|&(days, _)| days.contains(day))
+			.map(|(_, count)| count)
+			.sum()
+	}
+	
+	//		day_counts															
+	/// Returns the number of sets of days that include each day of the week.
+	/// 
+	/// The counts are in week order, from Monday to Sunday.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, stats::WeekdaysStats};
+	/// 
+	/// let stats = [Weekdays::WEEKDAYS, Weekdays::WEEKENDS, Weekdays::FRIDAY]
+	///     .into_iter()
+	///     .collect::<WeekdaysStats>();
+	/// 
+	/// assert_eq!(stats.day_counts(), [1, 1, 1, 1, 2, 1, 1]);
+	/// ```
+	/// 
+	#[must_use]
+	pub fn day_counts(&self) -> [u64; 7] {
+		let mut counts = [0; 7];
+		for (day, count) in Weekdays::ALL_DAYS.iter().zip(counts.iter_mut()) {
+			*count = self.day_count(day);
+		}
+		counts
+	}
+	
+	//		day_frequencies														
+	/// Returns the proportion of sets of days that include each day of the
+	/// week.
+	/// 
+	/// The proportions are in week order, from Monday to Sunday, and range from
+	/// `0.0` to `1.0`. If nothing has been added, they are all `0.0`.
+	/// 
+	#[expect(clippy::cast_precision_loss, reason = "Precision loss is acceptable for frequencies")]
+	#[must_use]
+	pub fn day_frequencies(&self) -> [f64; 7] {
+		let total = self.total();
+		self.day_counts().map(|count| if total == 0 { 0.0_f64 } else { count as f64 / total as f64 })
+	}
+	
+	//		length_distribution													
+	/// Returns the number of sets of days with each possible number of days.
+	/// 
+	/// The result is indexed by the number of days, from zero to seven, as
+	/// given by [`Weekdays::days()`].
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, stats::WeekdaysStats};
+	/// 
+	/// let stats = [Weekdays::WEEKDAYS, Weekdays::WEEKENDS, Weekdays::FRIDAY]
+	///     .into_iter()
+	///     .collect::<WeekdaysStats>();
+	/// 
+	/// assert_eq!(stats.length_distribution(), [0, 1, 1, 0, 0, 1, 0, 0]);
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "The total of all counts cannot realistically overflow")]
+	#[must_use]
+	pub fn length_distribution(&self) -> [u64; 8] {
+		let mut distribution = [0; 8];
+		for (days, count) in self.entries() {
+			if let Some(total) = distribution.get_mut(usize::from(days.days())) {
+				*total += count;
+			}
+		}
+		distribution
+	}
+	
+	//		mean_days															
+	/// Returns the mean number of days per set of days.
+	/// 
+	/// If nothing has been added, there is no mean, and [`None`] is returned.
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "The total of all days cannot realistically overflow")]
+	#[expect(clippy::cast_precision_loss,     reason = "Precision loss is acceptable for a mean")]
+	#[must_use]
+	pub fn mean_days(&self) -> Option<f64> {
+		let total = self.total();
+		let days  = self.entries().map(|(days, count)| u64::from(days.days()) * count).sum::<u64>();
+		(total > 0).then(|| days as f64 / total as f64)
+	}
+	
+	//		merge																
+	/// Merges the statistics from another accumulator into this one.
+	/// 
+	/// This allows statistics to be gathered in parallel over separate parts of
+	/// a data set, and then combined.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `other` - The statistics to merge in.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use weekdays::{Weekdays, stats::WeekdaysStats};
+	/// 
+	/// let mut first  = [Weekdays::MONDAY,  Weekdays::MONDAY].into_iter().collect::<WeekdaysStats>();
+	/// let     second = [Weekdays::MONDAY,  Weekdays::SUNDAY].into_iter().collect::<WeekdaysStats>();
+	/// first.merge(&second);
+	/// 
+	/// assert_eq!(first.total(),                  4);
+	/// assert_eq!(first.count(Weekdays::MONDAY),  3);
+	/// ```
+	/// 
+	#[expect(clippy::arithmetic_side_effects, reason = "A u64 count cannot realistically overflow")]
+	pub fn merge(&mut self, other: &Self) {
+		for (count, &extra) in self.patterns.iter_mut().zip(other.patterns.iter()) {
+			*count += extra;
+		}
+	}
+	
+	//		top_patterns														
+	/// Returns the most common sets of days.
+	/// 
+	/// Up to `limit` sets of days are returned, along with the number of times
+	/// each was seen, from most to least common. Sets that were never seen are
+	/// not included. Sets seen equally often are given in ascending order of
+	/// [`Weekdays`] value.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `limit` - The maximum number of sets of days to return.
+	/// 
+	#[must_use]
+	pub fn top_patterns(&self, limit: usize) -> Vec<(Weekdays, u64)> {
+		let mut patterns = self.entries().collect::<Vec<_>>();
+		//	The sort is stable, so equal counts stay in ascending order
+		patterns.sort_by_key(|&(_, count)| Reverse(count));
+		patterns.truncate(limit);
+		patterns
+	}
+	
+	//		total																
+	/// Returns the total number of sets of days added.
+	#[must_use]
+	pub fn total(&self) -> u64 {
+		self.patterns.iter().sum()
+	}
+	
+	//		Private methods														
+	
+	//		entries																
+	/// Returns an iterator over the sets of days seen, with their counts.
+	fn entries(&self) -> impl Iterator<Item = (Weekdays, u64)> + '_ {
+		(0_u8..)
+			.zip(self.patterns.iter())
+			.filter(|&(_, &count)| count > 0)
+			.map(|(pattern, &count)| (Weekdays(pattern), count))
+	}
+}
+
+//󰭅		Default																	
+impl Default for WeekdaysStats {
+	//		default																
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+//󰭅		Extend																	
+impl Extend<Weekdays> for WeekdaysStats {
+	//		extend																
+	fn extend<I: IntoIterator<Item = Weekdays>>(&mut self, iter: I) {
+		for days in iter {
+			self.add(days);
+		}
+	}
+}
+
+//󰭅		FromIterator															
+impl FromIterator<Weekdays> for WeekdaysStats {
+	//		from_iter															
+	fn from_iter<I: IntoIterator<Item = Weekdays>>(iter: I) -> Self {
+		let mut stats = Self::new();
+		stats.extend(iter);
+		stats
+	}
+}
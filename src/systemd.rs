@@ -0,0 +1,396 @@
+//! Conversion to and from systemd calendar event specifications.
+//! 
+//! systemd timers are scheduled with `OnCalendar=` settings such as
+//! `Mon..Fri *-*-* 09:00:00`, as described in
+//! [systemd.time(7)](https://www.freedesktop.org/software/systemd/man/latest/systemd.time.html).
+//! The [`parse_day_spec()`] and [`to_day_spec()`] functions convert
+//! [`Weekdays`] to and from the day-of-week part on its own.
+//! 
+//! When the `chrono` feature is enabled, the [`OnCalendar`] struct combines a
+//! set of days with a list of times, and converts them to and from full
+//! specifications.
+
+
+
+//		Modules
+
+#[cfg(test)]
+#[path = "tests/systemd.rs"]
+mod tests;
+
+
+
+//		Packages
+
+use crate::{Weekdays, format_runs};
+use core::{
+	error::Error,
+	fmt::{Display, Formatter},
+	fmt,
+};
+
+#[cfg(feature = "chrono")]
+use chrono::{NaiveTime, Timelike};
+#[cfg(feature = "chrono")]
+use core::str::FromStr;
+
+
+
+//		Constants
+
+/// The abbreviated day names used by systemd, from Monday to Sunday.
+const NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+
+
+//		Enums
+
+//		SystemdError															
+/// The errors that can occur when parsing a calendar event specification.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SystemdError {
+	/// The specification, or an entry in a list, was empty.
+	Empty,
+	
+	/// Several specifications were given with different days, which cannot be
+	/// combined into a single set of days.
+	InconsistentDays,
+	
+	/// The entry was not a valid day name.
+	InvalidDay(String),
+	
+	/// The range ended before it started.
+	InvalidRange(String),
+	
+	/// The time was not a valid list of hours, minutes, and seconds.
+	InvalidTime(String),
+	
+	/// The specification used syntax that has no equivalent as a set of days
+	/// and a list of times, such as specific dates, repetitions, or time zones.
+	UnsupportedSyntax(String),
+}
+
+//󰭅		Display																	
+impl Display for SystemdError {
+	//		fmt																	
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::Empty                        => write!(f, "Empty calendar specification entry"),
+			Self::InconsistentDays             => write!(f, "Calendar specifications have different days"),
+			Self::InvalidDay(ref day)          => write!(f, "Invalid day of the week: {day}"),
+			Self::InvalidRange(ref range)      => write!(f, "Invalid range of days: {range}"),
+			Self::InvalidTime(ref time)        => write!(f, "Invalid time: {time}"),
+			Self::UnsupportedSyntax(ref part)  => write!(f, "Unsupported calendar syntax: {part}"),
+		}
+	}
+}
+
+//󰭅		Error																	
+impl Error for SystemdError {}
+
+
+
+//		Structs
+
+//		OnCalendar																
+/// A set of days and a list of times, as used in `OnCalendar=` settings.
+/// 
+/// The times are kept in order without duplicates, and to the nearest second.
+/// A single specification can only give a list of hours, a list of minutes,
+/// and a list of seconds, and matches every combination of them. Times are
+/// therefore grouped by their minutes and seconds, and each group is written
+/// as a separate specification, which can be given as separate `OnCalendar=`
+/// lines in the timer unit.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use chrono::NaiveTime;
+/// use weekdays::{Weekdays, systemd::OnCalendar};
+/// 
+/// let times    = vec![NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap()];
+/// let calendar = OnCalendar::new(Weekdays::WEEKDAYS, times).unwrap();
+/// 
+/// assert_eq!(calendar.to_specs(), vec!["Mon..Fri *-*-* 09,17:00:00"]);
+/// assert_eq!("Mon..Fri 09,17:00".parse(), Ok(calendar));
+/// ```
+/// 
+#[cfg(feature = "chrono")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OnCalendar {
+	/// The days on which the event occurs. This is never empty.
+	days:  Weekdays,
+	
+	/// The times at which the event occurs, in order. This is never empty.
+	times: Vec<NaiveTime>,
+}
+
+//󰭅		OnCalendar																
+#[cfg(feature = "chrono")]
+impl OnCalendar {
+	//		Constructors														
+	
+	//		new																	
+	/// Creates a new [`OnCalendar`] from a set of days and a list of times.
+	/// 
+	/// Fractions of a second are dropped from the times, and the times are
+	/// sorted, with duplicates removed.
+	/// 
+	/// Returns [`None`] if no days or no times are given.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `days`  - The days on which the event occurs.
+	/// * `times` - The times at which the event occurs.
+	/// 
+	#[must_use]
+	pub fn new(days: Weekdays, times: Vec<NaiveTime>) -> Option<Self> {
+		if days.is_empty() || times.is_empty() {
+			return None;
+		}
+		let mut whole = times.into_iter().map(|time| time.with_nanosecond(0).unwrap_or(time)).collect::<Vec<_>>();
+		whole.sort_unstable();
+		whole.dedup();
+		Some(Self { days, times: whole })
+	}
+	
+	//		from_specs															
+	/// Parses several specifications into a single [`OnCalendar`].
+	/// 
+	/// This is the inverse of [`to_specs()`](Self::to_specs()). All of the
+	/// specifications must have the same days, and the times are combined.
+	/// 
+	/// # Parameters
+	/// 
+	/// * `specs` - The specifications to parse.
+	/// 
+	/// # Errors
+	/// 
+	/// Returns [`SystemdError::Empty`] if no specifications are given, or
+	/// [`SystemdError::InconsistentDays`] if they have different days, as well
+	/// as any error from parsing each specification.
+	/// 
+	pub fn from_specs<'a, I>(specs: I) -> Result<Self, SystemdError>
+		where I: IntoIterator<Item = &'a str>,
+	{
+		let mut days  = None;
+		let mut times = Vec::new();
+		for spec in specs {
+			let parsed = spec.parse::<Self>()?;
+			if days.is_some_and(|existing| existing != parsed.days) {
+				return Err(SystemdError::InconsistentDays);
+			}
+			days = Some(parsed.days);
+			times.extend(parsed.times);
+		}
+		days.and_then(|found| Self::new(found, times)).ok_or(SystemdError::Empty)
+	}
+	
+	//		Public methods														
+	
+	//		days																
+	/// Returns the days on which the event occurs.
+	#[must_use]
+	pub const fn days(&self) -> Weekdays {
+		self.days
+	}
+	
+	//		times																
+	/// Returns the times at which the event occurs, in order.
+	#[must_use]
+	pub fn times(&self) -> &[NaiveTime] {
+		&self.times
+	}
+	
+	//		to_specs															
+	/// Writes the event as one or more specifications.
+	/// 
+	/// Times that share the same minutes and seconds are written together as
+	/// a list of hours, so the common case of times on the hour needs only one
+	/// specification. The day-of-week part is left out if every day is set.
+	/// 
+	#[must_use]
+	pub fn to_specs(&self) -> Vec<String> {
+		let mut groups = Vec::<((u32, u32), Vec<u32>)>::new();
+		for time in &self.times {
+			let key = (time.minute(), time.second());
+			match groups.iter_mut().find(|&&mut (existing, _)| existing == key) {
+				Some(&mut (_, ref mut hours)) => hours.push(time.hour()),
+				None                          => groups.push((key, vec![time.hour()])),
+			}
+		}
+		let prefix = if self.days == Weekdays::ALL_DAYS { String::new() } else { format!("{} ", to_day_spec(self.days)) };
+		groups.iter()
+			.map(|&((minute, second), ref hours)| {
+				let list = hours.iter().map(|hour| format!("{hour:02}")).collect::<Vec<_>>().join(",");
+				format!("{prefix}*-*-* {list}:{minute:02}:{second:02}")
+			})
+			.collect()
+	}
+}
+
+//󰭅		FromStr																	
+#[cfg(feature = "chrono")]
+impl FromStr for OnCalendar {
+	type Err = SystemdError;
+	
+	//		from_str															
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.split_whitespace().peekable();
+		let days      = match parts.peek() {
+			Some(part) if part.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+				let spec = parts.next().unwrap_or_default();
+				parse_day_spec(spec)?
+			}
+			Some(_)                                                          => Weekdays::ALL_DAYS,
+			None                                                             => return Err(SystemdError::Empty),
+		};
+		match parts.peek() {
+			Some(&"*-*-*")                     => drop(parts.next()),
+			Some(date) if date.contains('-')   => return Err(SystemdError::UnsupportedSyntax((*date).to_owned())),
+			_                                  => {}
+		}
+		let times = match parts.next() {
+			Some(time) => parse_times(time)?,
+			None       => vec![NaiveTime::default()],
+		};
+		if let Some(extra) = parts.next() {
+			return Err(SystemdError::UnsupportedSyntax(extra.to_owned()));
+		}
+		Self::new(days, times).ok_or(SystemdError::Empty)
+	}
+}
+
+
+
+//		Functions
+
+//		parse_day_spec															
+/// Parses the day-of-week part of a calendar event specification.
+/// 
+/// The part is a comma-separated list of day names or ranges of day names,
+/// such as `Mon..Fri` or `Sat,Sun`. Both abbreviated and full names are
+/// understood, without regard to case, and ranges can also use `-`.
+/// 
+/// # Parameters
+/// 
+/// * `spec` - The day-of-week part.
+/// 
+/// # Errors
+/// 
+/// Returns a [`SystemdError`] describing the first entry that could not be
+/// parsed.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, systemd::parse_day_spec};
+/// 
+/// assert_eq!(parse_day_spec("Mon..Fri"),       Ok(Weekdays::WEEKDAYS));
+/// assert_eq!(parse_day_spec("saturday,Sun"),   Ok(Weekdays::WEEKENDS));
+/// assert_eq!(parse_day_spec("Mon,Wed..Thu"),   Ok(Weekdays::parse_const("mon, wed-thu")));
+/// ```
+/// 
+pub fn parse_day_spec(spec: &str) -> Result<Weekdays, SystemdError> {
+	spec.split(',').try_fold(Weekdays::NONE, |days, entry| {
+		let range = entry.split_once("..").or_else(|| entry.split_once('-'));
+		let day   = match range {
+			Some((from, to)) => {
+				let (start, end) = (parse_name(from)?, parse_name(to)?);
+				if end.first_index() < start.first_index() {
+					return Err(SystemdError::InvalidRange(entry.to_owned()));
+				}
+				Weekdays::range(start, end)
+			}
+			None             => parse_name(entry)?,
+		};
+		Ok(days | day)
+	})
+}
+
+//		to_day_spec																
+/// Formats a set of days as the day-of-week part of a calendar event
+/// specification.
+/// 
+/// Following systemd's own formatting, runs of three or more days are written
+/// as ranges, and other days are listed individually. If no days are set, the
+/// result is empty.
+/// 
+/// # Parameters
+/// 
+/// * `days` - The days to format.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use weekdays::{Weekdays, systemd::to_day_spec};
+/// 
+/// assert_eq!(to_day_spec(Weekdays::WEEKDAYS),                  "Mon..Fri");
+/// assert_eq!(to_day_spec(Weekdays::WEEKENDS),                  "Sat,Sun");
+/// assert_eq!(to_day_spec(Weekdays::parse_const("mon, wed-fri")), "Mon,Wed..Fri");
+/// ```
+/// 
+#[must_use]
+pub fn to_day_spec(days: Weekdays) -> String {
+	format_runs(days, NAMES, "..")
+}
+
+//		parse_name																
+/// Parses a single day name, which can be abbreviated or in full.
+/// 
+/// The name must match exactly, without surrounding whitespace.
+/// 
+/// # Parameters
+/// 
+/// * `name` - The name to parse.
+/// 
+/// # Errors
+/// 
+/// Returns [`SystemdError::InvalidDay`] if the name is not recognised, or
+/// [`SystemdError::Empty`] if it is empty.
+/// 
+fn parse_name(name: &str) -> Result<Weekdays, SystemdError> {
+	if name.is_empty() {
+		return Err(SystemdError::Empty);
+	}
+	Weekdays::from_name(name).ok_or_else(|| SystemdError::InvalidDay(name.to_owned()))
+}
+
+//		parse_times																
+/// Parses the time part of a calendar event specification into a list of
+/// times.
+/// 
+/// Each of the hours, minutes, and optional seconds can be a single number or
+/// a comma-separated list of numbers, and every combination is returned.
+/// 
+/// # Parameters
+/// 
+/// * `spec` - The time part.
+/// 
+/// # Errors
+/// 
+/// Returns [`SystemdError::InvalidTime`] if the time is not valid, or
+/// [`SystemdError::UnsupportedSyntax`] if it uses wildcards, ranges, or
+/// repetitions.
+/// 
+#[cfg(feature = "chrono")]
+fn parse_times(spec: &str) -> Result<Vec<NaiveTime>, SystemdError> {
+	if spec.contains(['*', '/', '~']) || spec.contains("..") {
+		return Err(SystemdError::UnsupportedSyntax(spec.to_owned()));
+	}
+	let invalid    = || SystemdError::InvalidTime(spec.to_owned());
+	let components = spec
+		.split(':')
+		.map(|component| component.split(',').map(|value| value.parse::<u32>().ok().ok_or_else(invalid)).collect())
+		.collect::<Result<Vec<Vec<u32>>, _>>()?;
+	let (hours, minutes, seconds) = match *components.as_slice() {
+		[ref hours, ref minutes]              => (hours, minutes, &vec![0]),
+		[ref hours, ref minutes, ref seconds] => (hours, minutes, seconds),
+		_                                     => return Err(invalid()),
+	};
+	hours.iter()
+		.flat_map(|&hour| minutes.iter().flat_map(move |&minute| seconds.iter().map(move |&second| (hour, minute, second))))
+		.map(|(hour, minute, second)| NaiveTime::from_hms_opt(hour, minute, second).ok_or_else(invalid))
+		.collect()
+}
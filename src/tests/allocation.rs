@@ -0,0 +1,152 @@
+//		Packages
+
+use super::*;
+
+/// Returns the amounts allocated to each day, in week order.
+fn amounts(map: &WeekdaysMap<u64>) -> Vec<(Weekdays, u64)> {
+	map.iter().map(|(day, &amount)| (day, amount)).collect()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		Allocator new														
+	#[test]
+	fn allocator__new() {
+		let allocator = Allocator::new(Weekdays::WEEKENDS);
+		assert_eq!(allocator.days(),                     Weekdays::WEEKENDS);
+		assert_eq!(allocator.weight(Weekdays::SATURDAY), 1);
+		assert_eq!(allocator.weight(Weekdays::MONDAY),   0);
+		assert_eq!(allocator.capacity(Weekdays::SUNDAY), None);
+	}
+	
+	//		Allocator with_capacity												
+	#[test]
+	fn allocator__with_capacity() {
+		let allocator = Allocator::new(Weekdays::ALL_DAYS).with_capacity(Weekdays::WEEKENDS, 3);
+		assert_eq!(allocator.capacity(Weekdays::SATURDAY), Some(3));
+		assert_eq!(allocator.capacity(Weekdays::FRIDAY),   None);
+	}
+	
+	//		Allocator with_weight												
+	#[test]
+	fn allocator__with_weight() {
+		let allocator = Allocator::new(Weekdays::ALL_DAYS).with_weight(Weekdays::parse_const("mon, fri"), 4);
+		assert_eq!(allocator.weight(Weekdays::MONDAY),  4);
+		assert_eq!(allocator.weight(Weekdays::FRIDAY),  4);
+		assert_eq!(allocator.weight(Weekdays::TUESDAY), 1);
+		assert_eq!(allocator.weight(Weekdays::NONE),    0);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		Allocator allocate													
+	#[test]
+	fn allocator__allocate__even() {
+		let result = Allocator::new(Weekdays::WEEKDAYS).allocate(12).unwrap();
+		assert_eq!(amounts(&result), vec![
+			(Weekdays::MONDAY,    3),
+			(Weekdays::TUESDAY,   3),
+			(Weekdays::WEDNESDAY, 2),
+			(Weekdays::THURSDAY,  2),
+			(Weekdays::FRIDAY,    2),
+		]);
+	}
+	#[test]
+	fn allocator__allocate__weights() {
+		let allocator = Allocator::new(Weekdays::parse_const("mon, wed, sat"))
+			.with_weight(Weekdays::MONDAY,   3)
+			.with_weight(Weekdays::SATURDAY, 0);
+		let result    = allocator.allocate(10).unwrap();
+		//	Saturday has no weight, but is still listed
+		assert_eq!(amounts(&result), vec![(Weekdays::MONDAY, 8), (Weekdays::WEDNESDAY, 2), (Weekdays::SATURDAY, 0)]);
+	}
+	#[test]
+	fn allocator__allocate__remainders() {
+		//	Shares of 3.5, 1.75 and 1.75 give the two extra units to the largest remainders
+		let allocator = Allocator::new(Weekdays::parse_const("mon-wed")).with_weight(Weekdays::MONDAY, 2);
+		let result    = allocator.allocate(7).unwrap();
+		assert_eq!(amounts(&result), vec![(Weekdays::MONDAY, 3), (Weekdays::TUESDAY, 2), (Weekdays::WEDNESDAY, 2)]);
+	}
+	#[test]
+	fn allocator__allocate__capacity() {
+		let allocator = Allocator::new(Weekdays::WEEKDAYS)
+			.with_capacity(Weekdays::FRIDAY,   1)
+			.with_capacity(Weekdays::THURSDAY, 0);
+		let result    = allocator.allocate(20).unwrap();
+		assert_eq!(amounts(&result), vec![
+			(Weekdays::MONDAY,    7),
+			(Weekdays::TUESDAY,   6),
+			(Weekdays::WEDNESDAY, 6),
+			(Weekdays::THURSDAY,  0),
+			(Weekdays::FRIDAY,    1),
+		]);
+	}
+	#[test]
+	fn allocator__allocate__full_capacity() {
+		let allocator = Allocator::new(Weekdays::parse_const("mon-wed")).with_capacity(Weekdays::ALL_DAYS, 2);
+		assert_eq!(allocator.allocate(6).unwrap().values().copied().collect::<Vec<_>>(), vec![2, 2, 2]);
+		assert_eq!(allocator.allocate(7), Err(AllocationError::InsufficientCapacity { required: 7, available: 6 }));
+	}
+	#[test]
+	fn allocator__allocate__total_preserved() {
+		let allocator = Allocator::new(Weekdays::ALL_DAYS)
+			.with_weight(Weekdays::MONDAY, 7)
+			.with_weight(Weekdays::FRIDAY, 3)
+			.with_capacity(Weekdays::SUNDAY, 5);
+		for quantity in [0, 1, 13, 99, 1_000, 123_457] {
+			assert_eq!(allocator.allocate(quantity).unwrap().values().sum::<u64>(), quantity);
+		}
+	}
+	#[test]
+	fn allocator__allocate__no_days() {
+		assert_eq!(Allocator::new(Weekdays::NONE).allocate(5),                                        Err(AllocationError::NoDays));
+		assert_eq!(Allocator::new(Weekdays::MONDAY).with_weight(Weekdays::MONDAY, 0).allocate(5),     Err(AllocationError::NoDays));
+	}
+	
+	//		Allocator allocate_items											
+	#[test]
+	fn allocator__allocate_items() {
+		let allocator = Allocator::new(Weekdays::parse_const("mon-tue")).with_weight(Weekdays::MONDAY, 2);
+		let result    = allocator.allocate_items([("a", 1), ("b", 1), ("c", 1), ("d", 1), ("e", 1), ("f", 1)]).unwrap();
+		assert_eq!(result.get(Weekdays::MONDAY),  Some(&vec!["a", "b", "d", "e"]));
+		assert_eq!(result.get(Weekdays::TUESDAY), Some(&vec!["c", "f"]));
+	}
+	#[test]
+	fn allocator__allocate_items__capacity() {
+		let allocator = Allocator::new(Weekdays::parse_const("mon-tue")).with_capacity(Weekdays::MONDAY, 2);
+		let result    = allocator.allocate_items([("small", 1), ("large", 5), ("medium", 2)]).unwrap();
+		assert_eq!(result.get(Weekdays::MONDAY),  Some(&vec!["medium"]));
+		assert_eq!(result.get(Weekdays::TUESDAY), Some(&vec!["small", "large"]));
+	}
+	#[test]
+	fn allocator__allocate_items__unplaceable() {
+		let allocator = Allocator::new(Weekdays::WEEKENDS).with_capacity(Weekdays::WEEKENDS, 4);
+		assert_eq!(allocator.allocate_items([("ok", 3), ("too big", 5)]), Err(AllocationError::Unplaceable { index: 1, weight: 5 }));
+	}
+	#[test]
+	fn allocator__allocate_items__empty() {
+		let result = Allocator::new(Weekdays::WEEKENDS).allocate_items(Vec::<(u8, u64)>::new()).unwrap();
+		assert_eq!(result.keys(), Weekdays::WEEKENDS);
+		assert!(result.values().all(Vec::is_empty));
+		assert_eq!(Allocator::new(Weekdays::NONE).allocate_items([(1, 1)]), Err(AllocationError::NoDays));
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		AllocationError Display												
+	#[test]
+	fn allocation_error__display() {
+		assert_eq!(AllocationError::NoDays.to_string(),                                              "No days to allocate to");
+		assert_eq!(AllocationError::InsufficientCapacity { required: 7, available: 6 }.to_string(), "Cannot allocate 7 with a capacity of 6");
+		assert_eq!(AllocationError::Unplaceable { index: 1, weight: 5 }.to_string(),                "Item 1 with weight 5 does not fit on any day");
+	}
+}
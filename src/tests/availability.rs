@@ -0,0 +1,139 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new__empty() {
+		let availability = Availability::new(&[]);
+		assert_eq!(availability.participants(), 0);
+		assert_eq!(availability.counts(),       [0; 7]);
+	}
+	#[test]
+	fn new__small() {
+		let availability = Availability::new(&[
+			Weekdays::new(0b11100_00),
+			Weekdays::new(0b01110_00),
+			Weekdays::new(0b00111_00),
+			Weekdays::new(0b00000_11),
+		]);
+		assert_eq!(availability.participants(), 4);
+		assert_eq!(availability.counts(),       [1, 2, 3, 2, 1, 1, 1]);
+	}
+	#[test]
+	fn new__large() {
+		let participants = (0..1_000_u32)
+			.map(|index| Weekdays::new((index % 128) as u8))
+			.collect::<Vec<_>>();
+		let availability = Availability::new(&participants);
+		let expected     = Weekdays::ALL_DAYS
+			.iter()
+			.map(|day| participants.iter().filter(|days| days.contains(day)).count())
+			.collect::<Vec<_>>();
+		assert_eq!(availability.participants(),   1_000);
+		assert_eq!(availability.counts().to_vec(), expected);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	/// Creates a summary from the given bit-mapped sets of days.
+	fn availability(participants: &[u8]) -> Availability {
+		Availability::new(&participants.iter().copied().map(Weekdays::new).collect::<Vec<_>>())
+	}
+	
+	//		all																	
+	#[test]
+	fn all() {
+		assert_eq!(availability(&[]).all(),                                     Weekdays::ALL_DAYS);
+		assert_eq!(availability(&[0b11100_00, 0b01110_00]).all(),              Weekdays::new(0b01100_00));
+		assert_eq!(availability(&[0b11100_00, 0b01110_00, 0b00000_11]).all(), Weekdays::NONE);
+	}
+	
+	//		any																	
+	#[test]
+	fn any() {
+		assert_eq!(availability(&[]).any(),                                     Weekdays::NONE);
+		assert_eq!(availability(&[0b11100_00, 0b01110_00]).any(),              Weekdays::new(0b11110_00));
+		assert_eq!(availability(&[0b11100_00, 0b01110_00, 0b00000_11]).any(), Weekdays::new(0b11110_11));
+	}
+	
+	//		at_least															
+	#[test]
+	fn at_least() {
+		let availability = availability(&[0b11100_00, 0b01110_00, 0b00111_00, 0b00000_11]);
+		assert_eq!(availability.at_least(0), Weekdays::ALL_DAYS);
+		assert_eq!(availability.at_least(1), Weekdays::new(0b11111_11));
+		assert_eq!(availability.at_least(2), Weekdays::new(0b01110_00));
+		assert_eq!(availability.at_least(3), Weekdays::new(0b00100_00));
+		assert_eq!(availability.at_least(4), Weekdays::NONE);
+		assert_eq!(availability.at_least(5), Weekdays::NONE);
+	}
+	
+	//		attendance															
+	#[test]
+	fn attendance() {
+		let availability = availability(&[0b11100_00, 0b01110_00, 0b00111_00, 0b00000_11]);
+		assert_eq!(availability.attendance(Weekdays::MONDAY),      1);
+		assert_eq!(availability.attendance(Weekdays::WEDNESDAY),   3);
+		assert_eq!(availability.attendance(Weekdays::new(0b01100_00)), 2);
+		assert_eq!(availability.attendance(Weekdays::WEEKENDS),    1);
+		assert_eq!(availability.attendance(Weekdays::ALL_DAYS),    0);
+		assert_eq!(availability.attendance(Weekdays::NONE),        4);
+	}
+	
+	//		best																
+	#[test]
+	fn best() {
+		let availability = availability(&[0b10000_00, 0b10000_00, 0b10001_00, 0b00000_10, 0b00001_00]);
+		assert_eq!(availability.best(0),  Weekdays::NONE);
+		assert_eq!(availability.best(1),  Weekdays::MONDAY);
+		assert_eq!(availability.best(2),  Weekdays::new(0b10001_00));
+		assert_eq!(availability.best(3),  Weekdays::new(0b10001_10));
+		assert_eq!(availability.best(7),  Weekdays::ALL_DAYS);
+		assert_eq!(availability.best(10), Weekdays::ALL_DAYS);
+	}
+	#[test]
+	fn best__ties() {
+		//	Equal coverage and attendance falls back to the earliest days
+		let availability = availability(&[0b00000_01, 0b00001_00]);
+		assert_eq!(availability.best(1), Weekdays::FRIDAY);
+		assert_eq!(Availability::new(&[]).best(2), Weekdays::new(0b11000_00));
+	}
+	
+	//		coverage															
+	#[test]
+	fn coverage() {
+		let availability = availability(&[0b11100_00, 0b01110_00, 0b00111_00, 0b00000_11]);
+		assert_eq!(availability.coverage(Weekdays::NONE),           0);
+		assert_eq!(availability.coverage(Weekdays::MONDAY),         1);
+		assert_eq!(availability.coverage(Weekdays::new(0b10001_00)), 2);
+		assert_eq!(availability.coverage(Weekdays::new(0b10000_01)), 2);
+		assert_eq!(availability.coverage(Weekdays::ALL_DAYS),       4);
+	}
+	
+	//		ranked																
+	#[test]
+	fn ranked() {
+		let availability = availability(&[0b11100_00, 0b01110_00, 0b00111_00, 0b00000_11]);
+		assert_eq!(availability.ranked(), vec![
+			(Weekdays::WEDNESDAY, 3),
+			(Weekdays::TUESDAY,   2),
+			(Weekdays::THURSDAY,  2),
+			(Weekdays::MONDAY,    1),
+			(Weekdays::FRIDAY,    1),
+			(Weekdays::SATURDAY,  1),
+			(Weekdays::SUNDAY,    1),
+		]);
+	}
+}
@@ -0,0 +1,362 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+#[cfg(feature = "chrono")]
+use crate::date;
+#[cfg(feature = "serde")]
+use claims::assert_ok_eq;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		CalendarMask::new													
+	#[test]
+	fn calendar_mask__new() {
+		let mask = CalendarMask::new(Weekdays::WEEKENDS, MonthDays::LAST, Months::DECEMBER);
+		assert_eq!(mask.weekdays(),   Weekdays::WEEKENDS);
+		assert_eq!(mask.month_days(), MonthDays::LAST);
+		assert_eq!(mask.months(),     Months::DECEMBER);
+	}
+	
+	//		MonthDays::from_day													
+	#[test]
+	fn month_days__from_day() {
+		assert_eq!(MonthDays::from_day(0),  None);
+		assert_eq!(MonthDays::from_day(1),  Some(MonthDays::new(0x8000_0000)));
+		assert_eq!(MonthDays::from_day(15), Some(MonthDays::new(0x0002_0000)));
+		assert_eq!(MonthDays::from_day(31), Some(MonthDays::new(0x0000_0002)));
+		assert_eq!(MonthDays::from_day(32), None);
+	}
+	
+	//		MonthDays::new														
+	#[test]
+	fn month_days__new() {
+		assert_eq!(MonthDays::new(0).0,          0);
+		assert_eq!(MonthDays::new(u32::MAX).0,   u32::MAX);
+		assert_eq!(MonthDays::new(0x8000_0001), MonthDays::FIRST | MonthDays::LAST);
+	}
+	
+	//		Months::from_month													
+	#[test]
+	fn months__from_month() {
+		assert_eq!(Months::from_month(0),  None);
+		assert_eq!(Months::from_month(1),  Some(Months::JANUARY));
+		assert_eq!(Months::from_month(7),  Some(Months::JULY));
+		assert_eq!(Months::from_month(12), Some(Months::DECEMBER));
+		assert_eq!(Months::from_month(13), None);
+	}
+	
+	//		Months::new															
+	#[test]
+	fn months__new() {
+		assert_eq!(Months::new(0b0000_0000_0000).0, 0b0000_0000_0000);
+		assert_eq!(Months::new(0b1001_0010_0100).0, 0b1001_0010_0100);
+		assert_eq!(Months::new(0b1111_1111_1111_1111).0, 0b1111_1111_1111);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		CalendarMask::matches												
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn calendar_mask__matches() {
+		let mask = CalendarMask::new(Weekdays::FRIDAY, MonthDays::LAST, Months::ALL_MONTHS);
+		assert!( mask.matches(date(2024, 5, 31)));
+		assert!(!mask.matches(date(2024, 5, 24)));
+		assert!(!mask.matches(date(2024, 6, 30)));
+		assert!(!mask.matches(date(2024, 8, 30)));
+		assert!( CalendarMask::ANY.matches(date(2024, 2, 29)));
+		assert!(!CalendarMask::new(Weekdays::NONE, MonthDays::ALL_DAYS, Months::ALL_MONTHS).matches(date(2024, 2, 29)));
+	}
+	
+	//		MonthDays::contains													
+	#[test]
+	fn month_days__contains() {
+		let days = MonthDays::FIRST | MonthDays::from_day(15).unwrap();
+		assert!( days.contains(MonthDays::FIRST));
+		assert!( days.contains(MonthDays::from_day(15).unwrap()));
+		assert!( days.contains(days));
+		assert!(!days.contains(MonthDays::LAST));
+		assert!( days.contains(MonthDays::NONE));
+	}
+	
+	//		MonthDays::contains_date											
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn month_days__contains_date() {
+		let days = MonthDays::FIRST | MonthDays::from_day(30).unwrap() | MonthDays::LAST;
+		assert!( days.contains_date(date(2024, 1,  1)));
+		assert!( days.contains_date(date(2024, 1, 30)));
+		assert!( days.contains_date(date(2024, 1, 31)));
+		assert!( days.contains_date(date(2024, 2, 29)));
+		assert!( days.contains_date(date(2023, 2, 28)));
+		assert!(!days.contains_date(date(2024, 2, 28)));
+		assert!(!days.contains_date(date(2024, 3, 15)));
+		assert!( MonthDays::LAST.contains_date(NaiveDate::MAX));
+	}
+	
+	//		MonthDays::days														
+	#[test]
+	fn month_days__days() {
+		assert_eq!(MonthDays::NONE.days(),                     0);
+		assert_eq!(MonthDays::ALL_DAYS.days(),                31);
+		assert_eq!((MonthDays::FIRST | MonthDays::LAST).days(), 2);
+	}
+	
+	//		MonthDays::is_empty													
+	#[test]
+	fn month_days__is_empty() {
+		assert!( MonthDays::NONE.is_empty());
+		assert!(!MonthDays::LAST.is_empty());
+	}
+	
+	//		MonthDays::iter														
+	#[test]
+	fn month_days__iter() {
+		let days     = MonthDays::FIRST | MonthDays::from_day(31).unwrap() | MonthDays::LAST;
+		let mut iter = days.iter();
+		assert_eq!(iter.next(), Some(MonthDays::FIRST));
+		assert_eq!(iter.next(), MonthDays::from_day(31));
+		assert_eq!(iter.next(), Some(MonthDays::LAST));
+		assert_eq!(iter.next(), None);
+		assert_eq!(MonthDays::ALL_DAYS.iter().count(), 31);
+	}
+	
+	//		MonthDays::to_vec													
+	#[test]
+	fn month_days__to_vec() {
+		assert_eq!((MonthDays::LAST | MonthDays::FIRST).to_vec(), vec![MonthDays::FIRST, MonthDays::LAST]);
+		assert_eq!(MonthDays::NONE.to_vec(), vec![]);
+	}
+	
+	//		MonthDays::union													
+	#[test]
+	fn month_days__union() {
+		const DAYS: MonthDays = MonthDays::FIRST.union(MonthDays::LAST);
+		assert_eq!(DAYS, MonthDays::new(0x8000_0001));
+	}
+	
+	//		Months::contains													
+	#[test]
+	fn months__contains() {
+		let months = Months::JANUARY | Months::JULY;
+		assert!( months.contains(Months::JANUARY));
+		assert!( months.contains(Months::JULY));
+		assert!(!months.contains(Months::JUNE));
+		assert!(!months.contains(Months::ALL_MONTHS));
+	}
+	
+	//		Months::contains_date												
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn months__contains_date() {
+		let months = Months::JANUARY | Months::DECEMBER;
+		assert!( months.contains_date(date(2024,  1, 15)));
+		assert!( months.contains_date(date(2024, 12, 31)));
+		assert!(!months.contains_date(date(2024,  6,  1)));
+	}
+	
+	//		Months::is_empty													
+	#[test]
+	fn months__is_empty() {
+		assert!( Months::NONE.is_empty());
+		assert!(!Months::MAY.is_empty());
+	}
+	
+	//		Months::iter														
+	#[test]
+	fn months__iter() {
+		let mut iter = (Months::DECEMBER | Months::MARCH).iter();
+		assert_eq!(iter.next(), Some(Months::MARCH));
+		assert_eq!(iter.next(), Some(Months::DECEMBER));
+		assert_eq!(iter.next(), None);
+		assert_eq!(Months::ALL_MONTHS.iter().count(), 12);
+	}
+	
+	//		Months::months														
+	#[test]
+	fn months__months() {
+		assert_eq!(Months::NONE.months(),        0);
+		assert_eq!(Months::ALL_MONTHS.months(), 12);
+		assert_eq!(Months::new(0b1001_0010_0100).months(), 4);
+	}
+	
+	//		Months::to_chrono_vec												
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn months__to_chrono_vec() {
+		assert_eq!((Months::FEBRUARY | Months::NOVEMBER).to_chrono_vec(), vec![Month::February, Month::November]);
+		assert_eq!(Months::NONE.to_chrono_vec(), vec![]);
+	}
+	
+	//		Months::to_vec														
+	#[test]
+	fn months__to_vec() {
+		assert_eq!((Months::APRIL | Months::JANUARY).to_vec(), vec![Months::JANUARY, Months::APRIL]);
+	}
+	
+	//		Months::union														
+	#[test]
+	fn months__union() {
+		const MONTHS: Months = Months::JUNE.union(Months::JULY).union(Months::AUGUST);
+		assert_eq!(MONTHS, Months::new(0b0000_0111_0000));
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		MonthDays operators													
+	#[test]
+	fn month_days__operators() {
+		let first = MonthDays::FIRST;
+		let last  = MonthDays::LAST;
+		assert_eq!(first + last,            MonthDays::new(0x8000_0001));
+		assert_eq!(first | last,            MonthDays::new(0x8000_0001));
+		assert_eq!((first | last) & last,   last);
+		assert_eq!((first | last) ^ last,   first);
+		assert_eq!((first | last) - last,   first);
+		assert_eq!(!MonthDays::NONE,        MonthDays::new(u32::MAX));
+		assert_eq!(!MonthDays::ALL_DAYS,    last);
+		let mut days = first;
+		days += last;
+		assert_eq!(days, MonthDays::new(0x8000_0001));
+		days -= first;
+		assert_eq!(days, last);
+		days |= first;
+		days &= first;
+		assert_eq!(days, first);
+		days ^= last;
+		assert_eq!(days, MonthDays::new(0x8000_0001));
+	}
+	
+	//		MonthDays Debug														
+	#[test]
+	fn month_days__debug() {
+		assert_eq!(format!("{:?}", MonthDays::NONE), "MonthDays(0000000_0000000_0000000_0000000_000_0)");
+		assert_eq!(format!("{:?}", MonthDays::LAST), "MonthDays(0000000_0000000_0000000_0000000_000_1)");
+	}
+	
+	//		MonthDays Display													
+	#[test]
+	fn month_days__display() {
+		assert_eq!(format!("{}", MonthDays::FIRST),    "1000000_0000000_0000000_0000000_000_0");
+		assert_eq!(format!("{}", MonthDays::ALL_DAYS), "1111111_1111111_1111111_1111111_111_0");
+		assert_eq!(format!("{}", MonthDays::from_day(8).unwrap() | MonthDays::from_day(31).unwrap()), "0000000_1000000_0000000_0000000_001_0");
+	}
+	
+	//		MonthDays IntoIterator												
+	#[test]
+	fn month_days__into_iterator() {
+		let days = MonthDays::FIRST | MonthDays::LAST;
+		assert_eq!((&days).into_iter().count(), 2);
+		assert_eq!(days.into_iter().collect::<Vec<_>>(), vec![MonthDays::FIRST, MonthDays::LAST]);
+	}
+	
+	//		Months operators													
+	#[test]
+	fn months__operators() {
+		let jan = Months::JANUARY;
+		let dec = Months::DECEMBER;
+		assert_eq!(jan + dec,           Months::new(0b1000_0000_0001));
+		assert_eq!(jan | dec,           Months::new(0b1000_0000_0001));
+		assert_eq!((jan | dec) & dec,   dec);
+		assert_eq!((jan | dec) ^ dec,   jan);
+		assert_eq!((jan | dec) - dec,   jan);
+		assert_eq!(!Months::NONE,       Months::ALL_MONTHS);
+		assert_eq!(!jan,                Months::new(0b0111_1111_1111));
+		let mut months = jan;
+		months += dec;
+		months -= jan;
+		assert_eq!(months, dec);
+		months |= jan;
+		months &= jan;
+		months ^= dec;
+		assert_eq!(months, Months::new(0b1000_0000_0001));
+	}
+	
+	//		Months Debug														
+	#[test]
+	fn months__debug() {
+		assert_eq!(format!("{:?}", Months::NONE),       "Months(000_000_000_000)");
+		assert_eq!(format!("{:?}", Months::ALL_MONTHS), "Months(111_111_111_111)");
+	}
+	
+	//		Months Display														
+	#[test]
+	fn months__display() {
+		assert_eq!(format!("{}", Months::JANUARY | Months::APRIL | Months::JULY | Months::OCTOBER), "100_100_100_100");
+		assert_eq!(format!("{}", Months::DECEMBER), "000_000_000_001");
+	}
+	
+	//		Months IntoIterator													
+	#[test]
+	fn months__into_iterator() {
+		let months = Months::MAY | Months::JUNE;
+		assert_eq!((&months).into_iter().count(), 2);
+		assert_eq!(months.into_iter().collect::<Vec<_>>(), vec![Months::MAY, Months::JUNE]);
+	}
+	
+	//		Serde																
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde() {
+		assert_eq!(serde_json::to_string(&MonthDays::new(0x8000_0001)).unwrap(), "2147483649");
+		assert_eq!(serde_json::to_string(&Months::JANUARY).unwrap(),             "2048");
+		assert_ok_eq!(serde_json::from_str::<MonthDays>("2147483649"), MonthDays::new(0x8000_0001));
+		assert_ok_eq!(serde_json::from_str::<Months>("65535"),         Months::ALL_MONTHS);
+		let mask = CalendarMask::new(Weekdays::MONDAY, MonthDays::LAST, Months::JANUARY);
+		let json = r#"{"weekdays":64,"month_days":1,"months":2048}"#;
+		assert_eq!(serde_json::to_string(&mask).unwrap(), json);
+		assert_ok_eq!(serde_json::from_str::<CalendarMask>(json), mask);
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod conversions__chrono {
+	use super::*;
+	
+	//		From: Month -> Months												
+	#[test]
+	fn from_month() {
+		assert_eq!(Months::from(Month::January),   Months::new(0b1000_0000_0000));
+		assert_eq!(Months::from(Month::June),      Months::new(0b0000_0100_0000));
+		assert_eq!(Months::from(Month::December),  Months::new(0b0000_0000_0001));
+	}
+}
+
+#[cfg(feature = "postgres")]
+mod conversions__postgres {
+	use super::*;
+	
+	//		FromSql																
+	#[test]
+	fn from_sql() {
+		assert_eq!(MonthDays::from_sql(&Type::BIT, &[0x80, 0, 0, 1]).unwrap(), MonthDays::new(0x8000_0001));
+		assert_eq!(Months::from_sql(&Type::BIT, &[0b1000, 0b0000_0001]).unwrap(), Months::new(0b1000_0000_0001));
+		assert_eq!(MonthDays::from_sql(&Type::BIT, &[1]).unwrap_err().to_string(), "Invalid length for MonthDays: 1");
+		assert_eq!(Months::from_sql(&Type::INT4, &[0, 1]).unwrap_err().to_string(), "Invalid type for Months: int4");
+		assert!( <MonthDays as FromSql>::accepts(&Type::BIT));
+		assert!(!<Months as FromSql>::accepts(&Type::INT4));
+	}
+	
+	//		ToSql																
+	#[test]
+	fn to_sql() {
+		let mut bytes = BytesMut::new();
+		assert!(matches!(MonthDays::new(0x8000_0001).to_sql(&Type::BIT, &mut bytes).unwrap(), IsNull::No));
+		assert!(matches!(Months::DECEMBER.to_sql(&Type::BIT, &mut bytes).unwrap(), IsNull::No));
+		assert_eq!(bytes.as_ref(), &[0x80, 0, 0, 1, 0, 1]);
+		assert!(Months::DECEMBER.to_sql(&Type::TEXT, &mut bytes).is_err());
+		assert!( <Months as ToSql>::accepts(&Type::BIT));
+		assert!(!<MonthDays as ToSql>::accepts(&Type::TEXT));
+	}
+}
@@ -0,0 +1,199 @@
+//		Packages
+
+use super::*;
+
+/// Creates a reservation, panicking if it is invalid.
+fn reservation(days: &str, window: Range<u16>) -> Reservation {
+	Reservation::new(Weekdays::parse_const(days), window).unwrap()
+}
+
+/// Generates pseudo-random reservations from a seed, so that results are
+/// repeatable.
+fn generate(seed: u64, count: usize) -> Vec<Reservation> {
+	let mut state = seed;
+	let mut next  = move |limit: u64| {
+		state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+		(state >> 33) % limit
+	};
+	(0..count).map(|_| {
+		let days   = Weekdays::new(u8::try_from(next(127) + 1).unwrap());
+		let start  = u16::try_from(next(1_440)).unwrap();
+		let length = u16::try_from(next(240) + 1).unwrap();
+		Reservation::new(days, start..start + length).unwrap()
+	}).collect()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		ConflictIndex new													
+	#[test]
+	fn conflict_index__new() {
+		let index = ConflictIndex::<u32>::new();
+		assert!(index.is_empty());
+		assert_eq!(index.len(), 0);
+		assert_eq!(index,       ConflictIndex::default());
+	}
+	
+	//		Reservation new														
+	#[test]
+	#[expect(clippy::reversed_empty_ranges, reason = "Testing reversed ranges")]
+	fn reservation__new() {
+		let booking = reservation("mon, fri", 540..600);
+		assert_eq!(booking.days(),   Weekdays::parse_const("mon, fri"));
+		assert_eq!(booking.window(), 540..600);
+		assert!(Reservation::new(Weekdays::MONDAY, 1_320..2_880).is_some());
+		assert_eq!(Reservation::new(Weekdays::MONDAY, 600..600),     None);
+		assert_eq!(Reservation::new(Weekdays::MONDAY, 600..540),     None);
+		assert_eq!(Reservation::new(Weekdays::MONDAY, 1_440..1_500), None);
+		assert_eq!(Reservation::new(Weekdays::MONDAY, 1_320..2_881), None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		ConflictIndex conflicts												
+	#[test]
+	fn conflict_index__conflicts() {
+		let index: ConflictIndex<u32> = [
+			(1, reservation("mon-fri", 540..600)),
+			(2, reservation("mon",     570..630)),
+			(3, reservation("sat-sun", 540..600)),
+			(4, reservation("tue",     600..660)),
+		].into_iter().collect();
+		assert_eq!(index.conflicts(&reservation("mon-tue", 590..610)), vec![
+			(1, Weekdays::parse_const("mon-tue")),
+			(2, Weekdays::MONDAY),
+			(4, Weekdays::TUESDAY),
+		]);
+		//	Touching windows do not clash
+		assert_eq!(index.conflicts(&reservation("sun", 600..700)), vec![]);
+		assert_eq!(index.conflicts(&reservation("wed", 0..540)),   vec![]);
+	}
+	#[test]
+	fn conflict_index__conflicts__overnight() {
+		let index: ConflictIndex<&str> = [
+			("late",    reservation("fri", 1_320..1_560)),
+			("sunday",  reservation("sun", 1_380..1_500)),
+			("morning", reservation("mon", 0..120)),
+		].into_iter().collect();
+		assert_eq!(index.conflicts(&reservation("sat", 60..90)), vec![("late", Weekdays::SATURDAY)]);
+		assert_eq!(index.conflicts(&reservation("mon", 30..45)), vec![("morning", Weekdays::MONDAY), ("sunday", Weekdays::MONDAY)]);
+		assert_eq!(index.conflicts(&reservation("fri", 1_430..1_445)), vec![("late", Weekdays::parse_const("fri-sat"))]);
+	}
+	#[test]
+	fn conflict_index__conflicts__long() {
+		//	Monday has two parts of the same reservation starting at midnight
+		let mut index: ConflictIndex<&str> = [
+			("all day", reservation("sun-mon", 0..1_500)),
+			("short",   reservation("mon",     1_400..1_410)),
+		].into_iter().collect();
+		assert_eq!(index.conflicts(&reservation("mon", 1_405..1_406)), vec![
+			("all day", Weekdays::MONDAY),
+			("short",   Weekdays::MONDAY),
+		]);
+		assert_eq!(index.conflicts(&reservation("tue", 30..90)), vec![("all day", Weekdays::TUESDAY)]);
+		_ = index.remove(&"all day");
+		assert_eq!(index.conflicts(&reservation("mon", 0..1_440)), vec![("short", Weekdays::MONDAY)]);
+		_ = index.remove(&"short");
+		assert_eq!(index, ConflictIndex::new());
+	}
+	#[test]
+	fn conflict_index__conflicts__brute_force() {
+		let entries = generate(42, 2_000);
+		let index: ConflictIndex<usize> = entries.iter().cloned().enumerate().collect();
+		for request in generate(7, 200) {
+			let expected: Vec<_> = entries.iter()
+				.enumerate()
+				.map(|(key, entry)| (key, entry.conflicts(&request)))
+				.filter(|&(_, days)| !days.is_empty())
+				.collect();
+			assert_eq!(index.conflicts(&request), expected);
+		}
+	}
+	
+	#[test]
+	fn conflict_index__conflicts__one_long() {
+		//	One reservation covering the whole week among many short ones
+		let mut entries = generate(11, 5_000);
+		entries.push(reservation("mon-sun", 0..1_440));
+		let index: ConflictIndex<usize> = entries.iter().cloned().enumerate().collect();
+		for request in generate(5, 200) {
+			let expected: Vec<_> = entries.iter()
+				.enumerate()
+				.map(|(key, entry)| (key, entry.conflicts(&request)))
+				.filter(|&(_, days)| !days.is_empty())
+				.collect();
+			assert_eq!(index.conflicts(&request), expected);
+			assert!(expected.iter().any(|&(key, _)| key == 5_000));
+		}
+	}
+	
+	//		DaySegments overlapping												
+	#[test]
+	fn day_segments__overlapping() {
+		let mut segments = DaySegments::new();
+		segments.insert(0, &(0..1_440));
+		for key in 1..1_000_u16 {
+			segments.insert(key, &(key..key + 10));
+		}
+		//	Only the parts that overlap are visited, despite the long one
+		let mut found: Vec<_> = segments.overlapping(&(500..502)).copied().collect();
+		found.sort_unstable();
+		assert_eq!(found, [0, 491, 492, 493, 494, 495, 496, 497, 498, 499, 500, 501]);
+		assert_eq!(segments.overlapping(&(1_440..1_440)).count(), 0);
+		for key in 1..1_000_u16 {
+			segments.remove(&key, &(key..key + 10));
+		}
+		assert_eq!(segments.overlapping(&(500..502)).copied().collect::<Vec<_>>(), [0]);
+		segments.remove(&0, &(0..1_440));
+		assert_eq!(segments, DaySegments::new());
+	}
+	
+	//		ConflictIndex insert												
+	#[test]
+	fn conflict_index__insert() {
+		let mut index = ConflictIndex::new();
+		assert_eq!(index.insert("a", reservation("mon", 540..600)), None);
+		assert_eq!(index.insert("a", reservation("tue", 540..600)), Some(reservation("mon", 540..600)));
+		assert_eq!(index.len(), 1);
+		//	The replaced reservation no longer clashes
+		assert_eq!(index.conflicts(&reservation("mon", 540..600)), vec![]);
+		assert_eq!(index.conflicts(&reservation("tue", 540..600)), vec![("a", Weekdays::TUESDAY)]);
+		assert!(index.contains_key(&"a"));
+		assert_eq!(index.get(&"a"), Some(&reservation("tue", 540..600)));
+	}
+	
+	//		ConflictIndex remove												
+	#[test]
+	fn conflict_index__remove() {
+		let mut index: ConflictIndex<usize> = generate(3, 100).into_iter().enumerate().collect();
+		for key in (0..100).step_by(2) {
+			assert!(index.remove(&key).is_some());
+		}
+		assert_eq!(index.remove(&0), None);
+		assert_eq!(index.len(),      50);
+		let everything = reservation("mon-sun", 0..1_440);
+		assert!(index.conflicts(&everything).iter().all(|&(key, _)| key % 2 == 1));
+		assert_eq!(index.iter().count(), 50);
+		for key in (1..100).step_by(2) {
+			assert!(index.remove(&key).is_some());
+		}
+		assert_eq!(index, ConflictIndex::new());
+	}
+	
+	//		Reservation conflicts												
+	#[test]
+	fn reservation__conflicts() {
+		let booking = reservation("mon-wed", 600..720);
+		assert_eq!(booking.conflicts(&reservation("tue-thu", 700..800)),   Weekdays::parse_const("tue-wed"));
+		assert_eq!(booking.conflicts(&reservation("mon-sun", 720..800)),   Weekdays::NONE);
+		assert_eq!(booking.conflicts(&reservation("sun",     1_380..1_500)), Weekdays::NONE);
+		assert_eq!(reservation("sun", 1_380..1_500).conflicts(&reservation("mon", 0..30)), Weekdays::MONDAY);
+	}
+}
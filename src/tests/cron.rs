@@ -0,0 +1,102 @@
+//		Packages
+
+use super::*;
+
+
+
+//		Tests
+
+mod functions {
+	use super::*;
+	
+	//		parse_dow															
+	#[test]
+	fn parse_dow__vixie() {
+		let vixie = CronDialect::Vixie;
+		assert_eq!(parse_dow("0",       vixie), Ok(Weekdays::SUNDAY));
+		assert_eq!(parse_dow("7",       vixie), Ok(Weekdays::SUNDAY));
+		assert_eq!(parse_dow("1",       vixie), Ok(Weekdays::MONDAY));
+		assert_eq!(parse_dow("1-5",     vixie), Ok(Weekdays::WEEKDAYS));
+		assert_eq!(parse_dow("5-7",     vixie), Ok(Weekdays::parse_const("fri-sun")));
+		assert_eq!(parse_dow("0-7",     vixie), Ok(Weekdays::ALL_DAYS));
+		assert_eq!(parse_dow("mon,Wed", vixie), Ok(Weekdays::parse_const("mon, wed")));
+		assert_eq!(parse_dow("FRI-SUN", vixie), Ok(Weekdays::parse_const("fri-sun")));
+		assert_eq!(parse_dow("*",       vixie), Ok(Weekdays::ALL_DAYS));
+		assert_eq!(parse_dow("?",       vixie), Ok(Weekdays::ALL_DAYS));
+	}
+	#[test]
+	fn parse_dow__quartz() {
+		let quartz = CronDialect::Quartz;
+		assert_eq!(parse_dow("1",       quartz), Ok(Weekdays::SUNDAY));
+		assert_eq!(parse_dow("7",       quartz), Ok(Weekdays::SATURDAY));
+		assert_eq!(parse_dow("MON-FRI", quartz), Ok(Weekdays::WEEKDAYS));
+		assert_eq!(parse_dow("FRI-MON", quartz), Ok(Weekdays::parse_const("fri-mon")));
+		assert_eq!(parse_dow("7-1",     quartz), Ok(Weekdays::WEEKENDS));
+		assert_eq!(parse_dow("?",       quartz), Ok(Weekdays::ALL_DAYS));
+	}
+	#[test]
+	fn parse_dow__steps() {
+		assert_eq!(parse_dow("1-5/2", CronDialect::Vixie),  Ok(Weekdays::parse_const("mon, wed, fri")));
+		assert_eq!(parse_dow("*/2",   CronDialect::Vixie),  Ok(Weekdays::parse_const("tue, thu, sat, sun")));
+		assert_eq!(parse_dow("1/3",   CronDialect::Vixie),  Ok(Weekdays::parse_const("mon, thu, sun")));
+		assert_eq!(parse_dow("*/3",   CronDialect::Quartz), Ok(Weekdays::parse_const("wed, sat, sun")));
+		assert_eq!(parse_dow("6-2/2", CronDialect::Quartz), Ok(Weekdays::parse_const("fri, sun")));
+	}
+	#[test]
+	fn parse_dow__errors() {
+		let vixie  = CronDialect::Vixie;
+		let quartz = CronDialect::Quartz;
+		assert_eq!(parse_dow("",      vixie),  Err(CronError::Empty));
+		assert_eq!(parse_dow("1,,2",  vixie),  Err(CronError::Empty));
+		assert_eq!(parse_dow("8",     vixie),  Err(CronError::InvalidValue("8".to_owned())));
+		assert_eq!(parse_dow("0",     quartz), Err(CronError::InvalidValue("0".to_owned())));
+		assert_eq!(parse_dow("FOO",   vixie),  Err(CronError::InvalidValue("FOO".to_owned())));
+		assert_eq!(parse_dow("5-1",   vixie),  Err(CronError::InvalidRange("5-1".to_owned())));
+		assert_eq!(parse_dow("*/0",   vixie),  Err(CronError::InvalidStep("0".to_owned())));
+		assert_eq!(parse_dow("1-5/x", vixie),  Err(CronError::InvalidStep("x".to_owned())));
+		assert_eq!(parse_dow("6L",    quartz), Err(CronError::UnsupportedSyntax("6L".to_owned())));
+		assert_eq!(parse_dow("2#1",   quartz), Err(CronError::UnsupportedSyntax("2#1".to_owned())));
+	}
+	
+	//		to_dow																
+	#[test]
+	fn to_dow() {
+		let vixie  = CronDialect::Vixie;
+		let quartz = CronDialect::Quartz;
+		assert_eq!(super::to_dow(Weekdays::WEEKDAYS,                          vixie).as_deref(),  Some("1-5"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("fri-sun"),            vixie).as_deref(),  Some("5-7"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("sun, mon"),           vixie).as_deref(),  Some("0-1"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("mon, wed"),           vixie).as_deref(),  Some("1,3"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("tue, thu, sat, sun"), vixie).as_deref(),  Some("*/2"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("mon, wed, fri, sun"), vixie).as_deref(),  Some("1/2"));
+		assert_eq!(super::to_dow(Weekdays::SUNDAY,                            quartz).as_deref(), Some("1"));
+		assert_eq!(super::to_dow(Weekdays::parse_const("fri-mon"),            quartz).as_deref(), Some("6-2"));
+		assert_eq!(super::to_dow(Weekdays::ALL_DAYS,                          vixie).as_deref(),  Some("*"));
+		assert_eq!(super::to_dow(Weekdays::NONE,                              quartz),            None);
+	}
+	#[test]
+	fn to_dow__round_trip() {
+		for dialect in [CronDialect::Vixie, CronDialect::Quartz] {
+			for bits in 1..=0b111_1111 {
+				let days  = Weekdays::new(bits);
+				let field = super::to_dow(days, dialect).unwrap();
+				assert_eq!(parse_dow(&field, dialect), Ok(days), "{field}");
+				assert!(field.len() <= format_runs(
+					(1..=7).filter(|&value| dialect.day(value).is_some_and(|day| days.contains(day)))
+				).len());
+			}
+		}
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		CronError Display													
+	#[test]
+	fn cron_error__display() {
+		assert_eq!(CronError::Empty.to_string(),                             "Empty day-of-week entry");
+		assert_eq!(CronError::InvalidRange("5-1".to_owned()).to_string(),     "Invalid day-of-week range: 5-1");
+		assert_eq!(CronError::UnsupportedSyntax("6L".to_owned()).to_string(), "Unsupported day-of-week syntax: 6L");
+	}
+}
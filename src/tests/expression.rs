@@ -0,0 +1,184 @@
+//		Packages
+
+use super::*;
+use crate::parse;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		NamedSets with_set													
+	#[test]
+	fn named_sets__with_set() {
+		let sets = NamedSets::new()
+			.with_set("gym_days", Weekdays::parse_const("mon, wed")).unwrap()
+			.with_set("_On_Call2", Weekdays::WEEKENDS).unwrap();
+		assert_eq!(sets.get("gym_days"),  Some(Weekdays::parse_const("mon, wed")));
+		assert_eq!(sets.get("GYM_DAYS"),  Some(Weekdays::parse_const("mon, wed")));
+		assert_eq!(sets.get("_on_call2"), Some(Weekdays::WEEKENDS));
+	}
+	#[test]
+	fn named_sets__with_set__invalid() {
+		assert_eq!(NamedSets::new().with_set("",         Weekdays::MONDAY), None);
+		assert_eq!(NamedSets::new().with_set("2days",    Weekdays::MONDAY), None);
+		assert_eq!(NamedSets::new().with_set("gym days", Weekdays::MONDAY), None);
+		assert_eq!(NamedSets::new().with_set("gym-days", Weekdays::MONDAY), None);
+		assert_eq!(NamedSets::new().with_set("Weekends", Weekdays::MONDAY), None);
+		assert_eq!(NamedSets::new().with_set("mon",      Weekdays::TUESDAY), None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		Expression evaluate													
+	#[test]
+	fn expression__evaluate() {
+		let sets = NamedSets::new();
+		assert_eq!(parse::<Expression>("(weekdays | sat) - wed").evaluate(&sets),  Ok(Weekdays::parse_const("mon-tue, thu-sat")));
+		assert_eq!(parse::<Expression>("!weekends & (mon | fri)").evaluate(&sets), Ok(Weekdays::parse_const("mon, fri")));
+		assert_eq!(parse::<Expression>("weekdays ^ (fri | sat)").evaluate(&sets),  Ok(Weekdays::parse_const("mon-thu, sat")));
+		assert_eq!(parse::<Expression>("!none").evaluate(&sets),                   Ok(Weekdays::ALL_DAYS));
+		assert_eq!(parse::<Expression>("!!Monday").evaluate(&sets),                Ok(Weekdays::MONDAY));
+		assert_eq!(parse::<Expression>("all - weekdays - sat").evaluate(&sets),    Ok(Weekdays::SUNDAY));
+	}
+	#[test]
+	fn expression__evaluate__precedence() {
+		let sets = NamedSets::new();
+		//	Subtraction binds more tightly than intersection, which binds more
+		//	tightly than symmetric difference, which binds more tightly than union
+		assert_eq!(parse::<Expression>("mon | weekdays - mon").evaluate(&sets),    parse::<Expression>("mon | (weekdays - mon)").evaluate(&sets));
+		assert_eq!(parse::<Expression>("weekdays & all - mon").evaluate(&sets),    Ok(Weekdays::parse_const("tue-fri")));
+		assert_eq!(parse::<Expression>("mon ^ weekdays & tue").evaluate(&sets),    Ok(Weekdays::parse_const("mon-tue")));
+		assert_eq!(parse::<Expression>("mon | tue ^ tue").evaluate(&sets),         Ok(Weekdays::MONDAY));
+		assert_eq!(parse::<Expression>("!mon & weekdays").evaluate(&sets),         Ok(Weekdays::parse_const("tue-fri")));
+		assert_eq!(parse::<Expression>("weekdays - (mon - mon)").evaluate(&sets),  Ok(Weekdays::WEEKDAYS));
+	}
+	#[test]
+	fn expression__evaluate__named_sets() {
+		let sets = NamedSets::new().with_set("gym_days", Weekdays::parse_const("mon, wed, sat")).unwrap();
+		assert_eq!(parse::<Expression>("gym_days - weekends").evaluate(&sets), Ok(Weekdays::parse_const("mon, wed")));
+		assert_eq!(parse::<Expression>("gym_days - weekends").evaluate(&NamedSets::new()), Err(ExpressionError::UnknownName {
+			name: "gym_days".to_owned(),
+			span: 0..8,
+		}));
+		assert_eq!(parse::<Expression>("mon | (Swim_Days)").evaluate(&sets), Err(ExpressionError::UnknownName {
+			name: "swim_days".to_owned(),
+			span: 7..16,
+		}));
+	}
+	
+	//		Expression names													
+	#[test]
+	fn expression__names() {
+		assert_eq!(parse::<Expression>("(Gym_Days | sat) - gym_days & !swim").names(), vec!["gym_days", "sat", "swim"]);
+		assert_eq!(parse::<Expression>("mon").names(),                                vec!["mon"]);
+	}
+	
+	//		ExpressionError span												
+	#[test]
+	fn expression_error__span() {
+		assert_eq!(ExpressionError::UnexpectedEnd { span: 4..4 }.span(),                            4..4);
+		assert_eq!(ExpressionError::InvalidCharacter { character: '*', span: 2..3 }.span(),         2..3);
+		assert_eq!(ExpressionError::UnknownName { name: "gym".to_owned(), span: 0..3 }.span(),      0..3);
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		evaluate															
+	#[test]
+	fn evaluate() {
+		let sets = NamedSets::new().with_set("gym_days", Weekdays::parse_const("mon, wed")).unwrap();
+		assert_eq!(super::evaluate("gym_days | fri", &sets), Ok(Weekdays::parse_const("mon, wed, fri")));
+		assert_eq!(super::evaluate("gym_days |",     &sets), Err(ExpressionError::UnexpectedEnd { span: 10..10 }));
+	}
+}
+
+mod traits {
+	use super::*;
+	use std::collections::HashSet;
+	
+	//		Expression Display													
+	#[test]
+	fn expression__display() {
+		assert_eq!(parse::<Expression>("(weekdays|sat)-wed").to_string(),         "(weekdays | sat) - wed");
+		assert_eq!(parse::<Expression>("!Weekends&(MON|fri)").to_string(),        "!weekends & (mon | fri)");
+		assert_eq!(parse::<Expression>("((mon))").to_string(),                    "mon");
+		assert_eq!(parse::<Expression>("(mon | tue) | wed").to_string(),          "mon | tue | wed");
+		assert_eq!(parse::<Expression>("mon | (tue | wed)").to_string(),          "mon | (tue | wed)");
+		assert_eq!(parse::<Expression>("mon - (tue - wed)").to_string(),          "mon - (tue - wed)");
+		assert_eq!(parse::<Expression>("(mon - tue) - wed").to_string(),          "mon - tue - wed");
+		assert_eq!(parse::<Expression>("mon | (tue & wed)").to_string(),          "mon | tue & wed");
+		assert_eq!(parse::<Expression>("(mon | tue) & wed").to_string(),          "(mon | tue) & wed");
+		assert_eq!(parse::<Expression>("!(mon ^ tue)").to_string(),               "!(mon ^ tue)");
+		assert_eq!(parse::<Expression>("!(!mon)").to_string(),                    "!!mon");
+		assert_eq!(parse::<Expression>("  gym_days\t-\nweekends ").to_string(),   "gym_days - weekends");
+	}
+	#[test]
+	fn expression__display__round_trip() {
+		let sets = NamedSets::new();
+		for source in [
+			"(weekdays | sat) - wed",
+			"!weekends & (mon | fri)",
+			"mon ^ (tue | wed) & !(thu - fri)",
+			"(mon | tue) - (wed ^ thu) | !(sat & sun)",
+			"all - (weekdays - (mon | tue))",
+		] {
+			let expression: Expression = parse(source);
+			let canonical  = expression.to_string();
+			assert_eq!(canonical, source);
+			assert_eq!(parse::<Expression>(&canonical).to_string(),  canonical);
+			assert_eq!(parse::<Expression>(&canonical).evaluate(&sets), expression.evaluate(&sets));
+		}
+	}
+	
+	//		Expression PartialEq												
+	#[test]
+	fn expression__eq() {
+		//	Spans are ignored, so spacing makes no difference
+		let spaced: Expression = parse("mon | tue");
+		assert_eq!(parse::<Expression>("mon|tue"), spaced);
+		assert_ne!(parse::<Expression>("tue|mon"), spaced);
+		assert_eq!(HashSet::from([parse::<Expression>("mon|tue"), spaced, parse::<Expression>("( mon )|tue")]).len(), 1);
+	}
+	
+	//		Expression FromStr													
+	#[test]
+	fn expression__from_str__errors() {
+		assert_eq!("".parse::<Expression>(),            Err(ExpressionError::UnexpectedEnd { span: 0..0 }));
+		assert_eq!("  ".parse::<Expression>(),          Err(ExpressionError::UnexpectedEnd { span: 2..2 }));
+		assert_eq!("mon |".parse::<Expression>(),       Err(ExpressionError::UnexpectedEnd { span: 5..5 }));
+		assert_eq!("mon * tue".parse::<Expression>(),   Err(ExpressionError::InvalidCharacter { character: '*', span: 4..5 }));
+		assert_eq!("mon é".parse::<Expression>(),       Err(ExpressionError::InvalidCharacter { character: 'é', span: 4..6 }));
+		assert_eq!("(mon | tue".parse::<Expression>(),  Err(ExpressionError::UnclosedParenthesis { span: 0..1 }));
+		assert_eq!("mon tue".parse::<Expression>(),     Err(ExpressionError::UnexpectedToken { token: "tue".to_owned(), span: 4..7 }));
+		assert_eq!("mon)".parse::<Expression>(),        Err(ExpressionError::UnexpectedToken { token: ")".to_owned(),   span: 3..4 }));
+		assert_eq!("| mon".parse::<Expression>(),       Err(ExpressionError::UnexpectedToken { token: "|".to_owned(),   span: 0..1 }));
+		assert_eq!("mon & & tue".parse::<Expression>(), Err(ExpressionError::UnexpectedToken { token: "&".to_owned(),   span: 6..7 }));
+		assert_eq!("()".parse::<Expression>(),          Err(ExpressionError::UnexpectedToken { token: ")".to_owned(),   span: 1..2 }));
+	}
+	#[test]
+	fn expression__from_str__too_deep() {
+		let nested = |depth: usize| format!("{}mon{}", "(".repeat(depth), ")".repeat(depth));
+		assert_eq!(nested(255).parse::<Expression>(),                           Ok(parse::<Expression>("mon")));
+		assert_eq!(nested(200_000).parse::<Expression>(),                       Err(ExpressionError::TooDeep { span: 256..257 }));
+		assert_eq!(format!("{}mon", "!".repeat(300)).parse::<Expression>(),     Err(ExpressionError::TooDeep { span: 256..257 }));
+		assert_eq!(format!("{}mon", "mon|".repeat(300)).parse::<Expression>(), Err(ExpressionError::TooDeep { span: 1024..1027 }));
+	}
+	
+	//		ExpressionError Display												
+	#[test]
+	fn expression_error__display() {
+		assert_eq!(
+			ExpressionError::UnknownName { name: "gym_days".to_owned(), span: 0..8 }.to_string(),
+			"Unknown name at 0..8: gym_days",
+		);
+		assert_eq!(ExpressionError::UnexpectedEnd { span: 5..5 }.to_string(), "Unexpected end of expression at 5");
+		assert_eq!(ExpressionError::TooDeep { span: 256..257 }.to_string(),  "Expression nested more than 256 levels deep at 256..257");
+	}
+}
@@ -0,0 +1,219 @@
+//		Packages
+
+use super::*;
+use crate::date;
+
+/// The `calendar.txt` contents used in the tests.
+const CALENDAR: &str = "\
+service_id,monday,tuesday,wednesday,thursday,friday,saturday,sunday,start_date,end_date
+weekday,1,1,1,1,1,0,0,20240101,20241231
+weekend,0,0,0,0,0,1,1,20240101,20241231
+";
+
+/// The `calendar_dates.txt` contents used in the tests.
+const CALENDAR_DATES: &str = "\
+service_id,date,exception_type
+weekday,20241225,2
+weekend,20241225,1
+special,20240704,1
+";
+
+/// Reads the test calendars and exceptions into a schedule.
+fn schedule() -> ServiceSchedule {
+	ServiceSchedule::new(
+		read_calendar(CALENDAR.as_bytes()).unwrap().map(Result::unwrap),
+		read_calendar_dates(CALENDAR_DATES.as_bytes()).unwrap().map(Result::unwrap),
+	).unwrap()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		ServiceCalendar::new												
+	#[test]
+	fn service_calendar__new() {
+		let calendar = ServiceCalendar::new("a", Weekdays::WEEKENDS, date(2024, 1, 1), date(2024, 1, 1)).unwrap();
+		assert_eq!(calendar.service_id(), "a");
+		assert_eq!(calendar.days(),       Weekdays::WEEKENDS);
+		assert_eq!(calendar.start_date(), date(2024, 1, 1));
+		assert_eq!(calendar.end_date(),   date(2024, 1, 1));
+		assert_eq!(ServiceCalendar::new("",  Weekdays::WEEKENDS, date(2024, 1, 1), date(2024, 1, 2)), None);
+		assert_eq!(ServiceCalendar::new("a", Weekdays::WEEKENDS, date(2024, 1, 2), date(2024, 1, 1)), None);
+	}
+	
+	//		CalendarDate::new													
+	#[test]
+	fn calendar_date__new() {
+		let exception = CalendarDate::new("a", date(2024, 1, 1), ExceptionType::Removed);
+		assert_eq!(exception.service_id(),     "a");
+		assert_eq!(exception.date(),           date(2024, 1, 1));
+		assert_eq!(exception.exception_type(), ExceptionType::Removed);
+	}
+	
+	//		ServiceSchedule::new												
+	#[test]
+	fn service_schedule__new() {
+		let calendar  = |id: &str| ServiceCalendar::new(id, Weekdays::WEEKDAYS, date(2024, 1, 1), date(2024, 12, 31)).unwrap();
+		let exception = |id: &str| CalendarDate::new(id, date(2024, 12, 25), ExceptionType::Removed);
+		assert!(ServiceSchedule::new([calendar("a"), calendar("b")], [exception("a"), exception("b")]).is_ok());
+		assert_eq!(
+			ServiceSchedule::new([calendar("a"), calendar("b"), calendar("a")], []),
+			Err(GtfsError::DuplicateService("a".to_owned())),
+		);
+		assert_eq!(
+			ServiceSchedule::new([calendar("a")], [exception("a"), exception("b"), exception("a")]),
+			Err(GtfsError::DuplicateDate { service_id: "a".to_owned(), date: date(2024, 12, 25) }),
+		);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		ServiceCalendar::is_active											
+	#[test]
+	fn service_calendar__is_active() {
+		let calendar = ServiceCalendar::new("a", Weekdays::WEEKDAYS, date(2024, 1, 2), date(2024, 1, 31)).unwrap();
+		assert!(!calendar.is_active(date(2024, 1,  1)));
+		assert!( calendar.is_active(date(2024, 1,  2)));
+		assert!(!calendar.is_active(date(2024, 1,  6)));
+		assert!( calendar.is_active(date(2024, 1, 31)));
+		assert!(!calendar.is_active(date(2024, 2,  1)));
+	}
+	
+	//		ServiceSchedule::active_services									
+	#[test]
+	fn service_schedule__active_services() {
+		let schedule = schedule();
+		assert_eq!(schedule.active_services(date(2024,  3,  1)), vec!["weekday"]);
+		assert_eq!(schedule.active_services(date(2024,  3,  2)), vec!["weekend"]);
+		assert_eq!(schedule.active_services(date(2024, 12, 25)), vec!["weekend"]);
+		assert_eq!(schedule.active_services(date(2024,  7,  4)), vec!["special", "weekday"]);
+		assert_eq!(schedule.active_services(date(2025,  1,  1)), Vec::<&str>::new());
+	}
+	
+	//		ServiceSchedule::is_active											
+	#[test]
+	fn service_schedule__is_active() {
+		let schedule = schedule();
+		assert!( schedule.is_active("weekday", date(2024, 12, 24)));
+		assert!(!schedule.is_active("weekday", date(2024, 12, 25)));
+		assert!( schedule.is_active("weekend", date(2024, 12, 25)));
+		assert!( schedule.is_active("special", date(2024,  7,  4)));
+		assert!(!schedule.is_active("special", date(2024,  7,  5)));
+		assert!(!schedule.is_active("missing", date(2024,  7,  4)));
+	}
+	
+	//		ServiceSchedule::service_dates										
+	#[test]
+	fn service_schedule__service_dates() {
+		let schedule = schedule();
+		assert_eq!(schedule.service_dates("special"),       vec![date(2024, 7, 4)]);
+		assert_eq!(schedule.service_dates("weekend").len(), 105);
+		assert_eq!(schedule.service_dates("weekday").len(), 261);
+		assert!(!schedule.service_dates("weekday").contains(&date(2024, 12, 25)));
+		assert_eq!(schedule.service_dates("missing"),       vec![]);
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		read_calendar														
+	#[test]
+	fn read_calendar() {
+		let calendars = super::read_calendar(CALENDAR.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+		assert_eq!(calendars, vec![
+			ServiceCalendar::new("weekday", Weekdays::WEEKDAYS, date(2024, 1, 1), date(2024, 12, 31)).unwrap(),
+			ServiceCalendar::new("weekend", Weekdays::WEEKENDS, date(2024, 1, 1), date(2024, 12, 31)).unwrap(),
+		]);
+	}
+	#[test]
+	fn read_calendar__column_order() {
+		let source    = "\u{feff}end_date, start_date, sunday, saturday, friday, thursday, wednesday, tuesday, monday, service_id, extra\n\
+		                 20240131, 20240101, 1, 0, 0, 0, 0, 0, 1, mon_sun, x\n";
+		let calendars = super::read_calendar(source.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+		assert_eq!(calendars, vec![
+			ServiceCalendar::new("mon_sun", Weekdays::parse_const("mon, sun"), date(2024, 1, 1), date(2024, 1, 31)).unwrap(),
+		]);
+	}
+	#[test]
+	fn read_calendar__errors() {
+		let header = "service_id,monday,tuesday,wednesday,thursday,friday,saturday,sunday,start_date,end_date\n";
+		let first  = |row: &str| super::read_calendar(format!("{header}{row}\n").as_bytes()).unwrap().next().unwrap();
+		assert_eq!(
+			super::read_calendar(&b"service_id,monday\n"[..]).err(),
+			Some(GtfsError::MissingColumn("tuesday".to_owned())),
+		);
+		assert_eq!(first("a,1,1,1,1,1,0,2,20240101,20241231"), Err(GtfsError::InvalidValue { column: "sunday".to_owned(),     value: "2".to_owned() }));
+		assert_eq!(first("a,1,1,1,1,1,0,0,2024-01-01,20241231"), Err(GtfsError::InvalidValue { column: "start_date".to_owned(), value: "2024-01-01".to_owned() }));
+		assert_eq!(first("a,1,1,1,1,1,0,0,20240101,20241332"), Err(GtfsError::InvalidValue { column: "end_date".to_owned(),   value: "20241332".to_owned() }));
+		assert_eq!(first(",1,1,1,1,1,0,0,20240101,20241231"), Err(GtfsError::InvalidValue { column: "service_id".to_owned(), value: String::new() }));
+		assert_eq!(first("a,1,1,1,1,1,0,0,20241231,20240101"), Err(GtfsError::InvalidDateRange("a".to_owned())));
+		assert!(matches!(first("a,1,1"), Err(GtfsError::Csv(_))));
+	}
+	
+	//		read_calendar_dates													
+	#[test]
+	fn read_calendar_dates() {
+		let exceptions = super::read_calendar_dates(CALENDAR_DATES.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+		assert_eq!(exceptions, vec![
+			CalendarDate::new("weekday", date(2024, 12, 25), ExceptionType::Removed),
+			CalendarDate::new("weekend", date(2024, 12, 25), ExceptionType::Added),
+			CalendarDate::new("special", date(2024,  7,  4), ExceptionType::Added),
+		]);
+	}
+	#[test]
+	fn read_calendar_dates__errors() {
+		let first = |row: &str| super::read_calendar_dates(format!("service_id,date,exception_type\n{row}\n").as_bytes()).unwrap().next().unwrap();
+		assert_eq!(first("a,20240101,3"), Err(GtfsError::InvalidValue { column: "exception_type".to_owned(), value: "3".to_owned() }));
+		assert_eq!(first("a,2024011,1"),  Err(GtfsError::InvalidValue { column: "date".to_owned(),           value: "2024011".to_owned() }));
+		assert_eq!(first(",20240101,1"),  Err(GtfsError::InvalidValue { column: "service_id".to_owned(),     value: String::new() }));
+		assert_eq!(
+			super::read_calendar_dates(&b"service_id,date\n"[..]).err(),
+			Some(GtfsError::MissingColumn("exception_type".to_owned())),
+		);
+	}
+	
+	//		write_calendar														
+	#[test]
+	fn write_calendar() {
+		let calendars = super::read_calendar(CALENDAR.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+		let mut out   = Vec::new();
+		super::write_calendar(&mut out, &calendars).unwrap();
+		assert_eq!(String::from_utf8(out).unwrap(), CALENDAR);
+	}
+	
+	//		write_calendar_dates												
+	#[test]
+	fn write_calendar_dates() {
+		let exceptions = super::read_calendar_dates(CALENDAR_DATES.as_bytes()).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+		let mut out    = Vec::new();
+		super::write_calendar_dates(&mut out, &exceptions).unwrap();
+		assert_eq!(String::from_utf8(out).unwrap(), CALENDAR_DATES);
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		GtfsError Display													
+	#[test]
+	fn gtfs_error__display() {
+		assert_eq!(GtfsError::MissingColumn("monday".to_owned()).to_string(),   "Missing column: monday");
+		assert_eq!(GtfsError::InvalidDateRange("a".to_owned()).to_string(),     "Invalid date range for service: a");
+		assert_eq!(GtfsError::DuplicateService("a".to_owned()).to_string(),     "Duplicate calendar for service: a");
+		assert_eq!(
+			GtfsError::DuplicateDate { service_id: "a".to_owned(), date: date(2024, 12, 25) }.to_string(),
+			"Duplicate exception for service a on 2024-12-25",
+		);
+		assert_eq!(
+			GtfsError::InvalidValue { column: "sunday".to_owned(), value: "2".to_owned() }.to_string(),
+			"Invalid value for sunday: 2",
+		);
+	}
+}
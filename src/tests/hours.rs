@@ -0,0 +1,265 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+#[cfg(feature = "serde")]
+use claims::{assert_err, assert_ok_eq};
+
+/// Creates the usual office hours, 09:00 to 17:00 on weekdays.
+fn office() -> WeekHours {
+	WeekHours::from_days(Weekdays::WEEKDAYS, 9..17)
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		assert!(WeekHours::new().is_empty());
+		assert_eq!(WeekHours::new(), WeekHours::NONE);
+		assert_eq!(WeekHours::default(), WeekHours::NONE);
+	}
+	
+	//		from_bytes															
+	#[test]
+	fn from_bytes() {
+		let mut bytes = vec![0_u8; 21];
+		bytes[0]      = 0b1000_0000;
+		bytes[20]     = 0b0000_0001;
+		let slots     = WeekHours::from_bytes(&bytes).unwrap();
+		assert!(slots.contains_slot(Weekdays::MONDAY, 0));
+		assert!(slots.contains_slot(Weekdays::SUNDAY, 23));
+		assert_eq!(slots.count(), 2);
+		assert_eq!(WeekHours::from_bytes(&[0; 20]),        None);
+		assert_eq!(WeekHalfHours::from_bytes(&[0; 21]),    None);
+		assert_eq!(WeekQuarterHours::from_bytes(&[0xFF; 84]), Some(WeekQuarterHours::ALL));
+	}
+	
+	//		from_days															
+	#[test]
+	fn from_days() {
+		let slots = office();
+		assert_eq!(slots.count(),       40);
+		assert_eq!(slots.to_weekdays(), Weekdays::WEEKDAYS);
+		assert!( slots.contains_slot(Weekdays::FRIDAY,   9));
+		assert!( slots.contains_slot(Weekdays::FRIDAY,  16));
+		assert!(!slots.contains_slot(Weekdays::FRIDAY,  17));
+		assert!(!slots.contains_slot(Weekdays::SATURDAY, 9));
+	}
+	#[test]
+	fn from_days__widths() {
+		let half = WeekHalfHours::from_days(Weekdays::MONDAY, 9..10);
+		assert_eq!(half.iter().collect::<Vec<_>>(), vec![(Weekdays::MONDAY, 18), (Weekdays::MONDAY, 19)]);
+		let quarter = WeekQuarterHours::from_days(Weekdays::MONDAY, 23..30);
+		assert_eq!(quarter.count(), 4);
+		assert!(quarter.contains_slot(Weekdays::MONDAY, 95));
+	}
+	#[expect(clippy::reversed_empty_ranges, reason = "Testing reversed ranges")]
+	#[test]
+	fn from_days__empty() {
+		assert!(WeekHours::from_days(Weekdays::WEEKDAYS, 17..9).is_empty());
+		assert!(WeekHours::from_days(Weekdays::NONE,     0..24).is_empty());
+		assert_eq!(WeekHours::from_days(Weekdays::ALL_DAYS, 0..24), WeekHours::ALL);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		clear_slot															
+	#[test]
+	fn clear_slot() {
+		let mut slots = office();
+		slots.clear_slot(Weekdays::parse_const("mon, wed"), 12);
+		assert!(!slots.contains_slot(Weekdays::MONDAY,    12));
+		assert!( slots.contains_slot(Weekdays::TUESDAY,   12));
+		assert!(!slots.contains_slot(Weekdays::WEDNESDAY, 12));
+		slots.clear_slot(Weekdays::ALL_DAYS, 24);
+		assert_eq!(slots.count(), 38);
+	}
+	
+	//		contains_slot														
+	#[test]
+	fn contains_slot() {
+		let slots = office();
+		assert!( slots.contains_slot(Weekdays::WEEKDAYS, 10));
+		assert!(!slots.contains_slot(Weekdays::ALL_DAYS, 10));
+		assert!(!slots.contains_slot(Weekdays::NONE,     10));
+		assert!(!slots.contains_slot(Weekdays::MONDAY,   24));
+	}
+	
+	//		count																
+	#[test]
+	fn count() {
+		assert_eq!(WeekHours::ALL.count(),        168);
+		assert_eq!(WeekHalfHours::ALL.count(),    336);
+		assert_eq!(WeekQuarterHours::ALL.count(), 672);
+		assert_eq!(WeekHours::NONE.count(),       0);
+	}
+	
+	//		is_available_at														
+	#[test]
+	fn is_available_at() {
+		let slots = office();
+		assert!( slots.is_available_at(Weekdays::MONDAY,    9));
+		assert!(!slots.is_available_at(Weekdays::MONDAY,    8));
+		assert!(!slots.is_available_at(Weekdays::MONDAY,   17));
+		assert!(!slots.is_available_at(Weekdays::SUNDAY,   12));
+		assert!( slots.is_available_at(Weekdays::WEEKDAYS, 12));
+		assert!(!slots.is_available_at(Weekdays::MONDAY,   33));
+	}
+	#[test]
+	fn is_available_at__half_hours() {
+		let mut slots = WeekHalfHours::new();
+		slots.set_slot(Weekdays::TUESDAY, 19);
+		assert!(!slots.is_available_at(Weekdays::TUESDAY, 9));
+		slots.set_slot(Weekdays::TUESDAY, 18);
+		assert!( slots.is_available_at(Weekdays::TUESDAY, 9));
+	}
+	
+	//		iter																
+	#[test]
+	fn iter() {
+		let mut slots = WeekHours::new();
+		slots.set_slot(Weekdays::SUNDAY,  0);
+		slots.set_slot(Weekdays::MONDAY, 23);
+		slots.set_slot(Weekdays::MONDAY,  1);
+		assert_eq!(slots.iter().collect::<Vec<_>>(), vec![
+			(Weekdays::MONDAY,  1),
+			(Weekdays::MONDAY, 23),
+			(Weekdays::SUNDAY,  0),
+		]);
+		assert_eq!(WeekHours::NONE.iter().count(), 0);
+	}
+	
+	//		set_slot															
+	#[test]
+	fn set_slot() {
+		let mut slots = WeekHours::new();
+		slots.set_slot(Weekdays::WEEKENDS, 8);
+		slots.set_slot(Weekdays::WEEKENDS, 99);
+		assert_eq!(slots.count(),       2);
+		assert_eq!(slots.to_weekdays(), Weekdays::WEEKENDS);
+	}
+	
+	//		to_bytes															
+	#[test]
+	fn to_bytes() {
+		let bytes = WeekHours::from_days(Weekdays::MONDAY, 0..1).to_bytes();
+		assert_eq!(bytes.len(), 21);
+		assert_eq!(bytes[0],    0b1000_0000);
+		assert_eq!(WeekHalfHours::ALL.to_bytes(), vec![0xFF; 42]);
+		assert_eq!(WeekHours::from_bytes(&office().to_bytes()), Some(office()));
+	}
+	
+	//		to_weekdays															
+	#[test]
+	fn to_weekdays() {
+		assert_eq!(WeekHours::ALL.to_weekdays(),  Weekdays::ALL_DAYS);
+		assert_eq!(WeekHours::NONE.to_weekdays(), Weekdays::NONE);
+		assert_eq!(Weekdays::from(office()),      Weekdays::WEEKDAYS);
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		BitAnd																
+	#[test]
+	fn bitand() {
+		let evening = WeekHours::from_days(Weekdays::ALL_DAYS, 16..20);
+		assert_eq!(office() & evening, WeekHours::from_days(Weekdays::WEEKDAYS, 16..17));
+	}
+	
+	//		BitOr																
+	#[test]
+	fn bitor() {
+		let weekend = WeekHours::from_days(Weekdays::WEEKENDS, 9..17);
+		assert_eq!(office() | weekend, WeekHours::from_days(Weekdays::ALL_DAYS, 9..17));
+	}
+	
+	//		BitXor																
+	#[test]
+	fn bitxor() {
+		let late = WeekHours::from_days(Weekdays::WEEKDAYS, 10..18);
+		let xor  = office() ^ late;
+		assert_eq!(xor.count(), 10);
+		assert!(xor.is_available_at(Weekdays::MONDAY,  9));
+		assert!(xor.is_available_at(Weekdays::MONDAY, 17));
+	}
+	
+	//		Deserialize															
+	#[cfg(feature = "serde")]
+	#[test]
+	fn deserialize() {
+		let hex = format!("{:x}", office());
+		assert_ok_eq!(serde_json::from_str::<WeekHours>(&format!(r#""{hex}""#)), office());
+		assert_err!(serde_json::from_str::<WeekHours>(r#""ff""#));
+		assert_err!(serde_json::from_str::<WeekHours>(r#""zz""#));
+		assert_err!(serde_json::from_str::<WeekHours>("12"));
+	}
+	
+	//		LowerHex															
+	#[test]
+	fn lower_hex() {
+		let hex = format!("{:x}", WeekHours::from_days(Weekdays::MONDAY, 0..4));
+		assert_eq!(hex.len(), 42);
+		assert!(hex.starts_with("f00000000000"));
+	}
+	
+	//		Not																	
+	#[test]
+	fn not() {
+		assert_eq!(!WeekHours::NONE,     WeekHours::ALL);
+		assert_eq!(!WeekQuarterHours::ALL, WeekQuarterHours::NONE);
+		assert_eq!((!office()).count(),  128);
+	}
+	
+	//		Serialize															
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialize() {
+		let json = serde_json::to_string(&WeekHours::from_days(Weekdays::SUNDAY, 23..24)).unwrap();
+		assert_eq!(json, format!(r#""{}01""#, "0".repeat(40)));
+	}
+	
+	//		Sub																	
+	#[test]
+	fn sub() {
+		let lunch = WeekHours::from_days(Weekdays::ALL_DAYS, 12..13);
+		let mut slots = office();
+		slots -= lunch;
+		assert_eq!(slots, office() - lunch);
+		slots += lunch;
+		assert_eq!(slots, office() + lunch);
+		assert_eq!(slots.count(), 42);
+	}
+}
+
+#[cfg(feature = "postgres")]
+mod conversions__postgres {
+	use super::*;
+	
+	//		FromSql																
+	#[test]
+	fn from_sql() {
+		assert_eq!(WeekHours::from_sql(&Type::BIT, &office().to_bytes()).unwrap(), office());
+		assert_eq!(WeekHours::from_sql(&Type::BIT, &[0; 3]).unwrap_err().to_string(), "Invalid length for WeekSlots: 3");
+		assert_eq!(WeekHours::from_sql(&Type::INT4, &[0; 21]).unwrap_err().to_string(), "Invalid type for WeekSlots: int4");
+	}
+	
+	//		ToSql																
+	#[test]
+	fn to_sql() {
+		let mut bytes = BytesMut::new();
+		assert!(office().to_sql(&Type::BIT, &mut bytes).is_ok());
+		assert_eq!(bytes.to_vec(), office().to_bytes());
+		assert!(office().to_sql(&Type::INT4, &mut bytes).is_err());
+	}
+}
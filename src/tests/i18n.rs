@@ -0,0 +1,230 @@
+//		Packages
+
+use super::*;
+use crate::parse;
+
+/// The name widths, in order.
+const WIDTHS: [NameWidth; 3] = [NameWidth::Wide, NameWidth::Abbreviated, NameWidth::Narrow];
+
+/// The name contexts, in order.
+const CONTEXTS: [NameContext; 2] = [NameContext::Format, NameContext::StandAlone];
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		DayNames new														
+	#[test]
+	fn day_names__new() {
+		let names = DayNames::new(Language::English.into());
+		assert_eq!(names.name(Weekdays::MONDAY), Some("Mon"));
+	}
+	
+	//		DayNames with_context												
+	#[test]
+	fn day_names__with_context() {
+		let names = DayNames::new(Language::Polish.into()).with_width(NameWidth::Narrow);
+		assert_eq!(names.name(Weekdays::WEDNESDAY),                                     Some("ś"));
+		assert_eq!(names.with_context(NameContext::StandAlone).name(Weekdays::WEDNESDAY), Some("Ś"));
+	}
+	
+	//		DayNames with_width													
+	#[test]
+	fn day_names__with_width() {
+		let names = DayNames::new(Language::Spanish.into());
+		assert_eq!(names.with_width(NameWidth::Wide).name(Weekdays::WEDNESDAY),        Some("miércoles"));
+		assert_eq!(names.with_width(NameWidth::Abbreviated).name(Weekdays::WEDNESDAY), Some("mié"));
+		assert_eq!(names.with_width(NameWidth::Narrow).name(Weekdays::WEDNESDAY),      Some("X"));
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		DayNames format														
+	#[test]
+	fn day_names__format() {
+		let days = Weekdays::parse_const("mon, tue");
+		assert_eq!(DayNames::new(Language::French.into()).format(days),   "lun., mar.");
+		assert_eq!(DayNames::new(Language::Japanese.into()).format(days), "月, 火");
+		assert_eq!(DayNames::new(Language::German.into()).format(days),   "Mo., Di.");
+		assert_eq!(DayNames::new(Language::Russian.into()).format(days),  "пн, вт");
+	}
+	#[test]
+	fn day_names__format__week_order() {
+		let days = Weekdays::parse_const("mon, sat-sun");
+		assert_eq!(DayNames::new(Language::English.into()).format(days),    "Sun, Mon, Sat");
+		assert_eq!(DayNames::new(Language::Portuguese.into()).format(days), "dom., seg., sáb.");
+		assert_eq!(DayNames::new(Language::Dutch.into()).format(days),      "ma, za, zo");
+		assert_eq!(DayNames::new(Language::Chinese.into()).format(days),    "周一, 周六, 周日");
+	}
+	#[test]
+	fn day_names__format__empty() {
+		assert_eq!(DayNames::new(Language::English.into()).format(Weekdays::NONE), "");
+	}
+	
+	//		DayNames name														
+	#[test]
+	fn day_names__name() {
+		let names = DayNames::new(Language::Korean.into()).with_width(NameWidth::Wide);
+		assert_eq!(names.name(Weekdays::SUNDAY),   Some("일요일"));
+		assert_eq!(names.name(Weekdays::WEEKENDS), Some("토요일"));
+		assert_eq!(names.name(Weekdays::NONE),     None);
+	}
+	
+	//		DayNames parse														
+	#[test]
+	fn day_names__parse() {
+		let french = DayNames::new(Language::French.into());
+		assert_eq!(french.parse("lundi, mardi"),     Ok(Weekdays::parse_const("mon, tue")));
+		assert_eq!(french.parse("LUN. mar"),         Ok(Weekdays::parse_const("mon, tue")));
+		assert_eq!(french.parse("J, dimanche"),      Ok(Weekdays::parse_const("thu, sun")));
+		assert_eq!(french.parse(""),                 Ok(Weekdays::NONE));
+		assert_eq!(french.parse(" , "),              Ok(Weekdays::NONE));
+	}
+	#[test]
+	fn day_names__parse__separators() {
+		let japanese = DayNames::new(Language::Japanese.into());
+		assert_eq!(japanese.parse("月、火曜日"),        Ok(Weekdays::parse_const("mon, tue")));
+		let chinese  = DayNames::new(Language::Chinese.into());
+		assert_eq!(chinese.parse("星期一，周三 五"),    Ok(Weekdays::parse_const("mon, wed, fri")));
+	}
+	#[test]
+	fn day_names__parse__contexts() {
+		let german = DayNames::new(Language::German.into());
+		assert_eq!(german.parse("Mo, Di."),          Ok(Weekdays::parse_const("mon, tue")));
+		let polish = DayNames::new(Language::Polish.into());
+		assert_eq!(polish.parse("ś Czw."),           Ok(Weekdays::parse_const("wed, thu")));
+	}
+	#[test]
+	fn day_names__parse__ambiguous() {
+		assert_eq!(DayNames::new(Language::French.into()).parse("M"),  Err(I18nError::AmbiguousDay("M".to_owned())));
+		assert_eq!(DayNames::new(Language::English.into()).parse("s"), Err(I18nError::AmbiguousDay("s".to_owned())));
+	}
+	#[test]
+	fn day_names__parse__unknown() {
+		assert_eq!(DayNames::new(Language::French.into()).parse("lundi, Monday"), Err(I18nError::UnknownDay("Monday".to_owned())));
+	}
+	#[test]
+	fn day_names__parse__round_trip() {
+		let days = Weekdays::parse_const("mon, wed-thu, sun");
+		for language in Language::ALL {
+			let locale = Locale::from(language);
+			for context in CONTEXTS {
+				for width in WIDTHS {
+					let names = DayNames::new(locale).with_context(context).with_width(width);
+					//	Narrow names are not unique in most languages
+					if width == NameWidth::Narrow {
+						continue;
+					}
+					assert_eq!(names.parse(&names.format(days)), Ok(days), "{locale} {context:?} {width:?}");
+					assert_eq!(names.parse(&names.format(Weekdays::ALL_DAYS)), Ok(Weekdays::ALL_DAYS));
+				}
+			}
+		}
+	}
+	#[test]
+	fn day_names__parse__narrow_unique() {
+		for language in [Language::Chinese, Language::Japanese, Language::Korean] {
+			let names = DayNames::new(language.into()).with_width(NameWidth::Narrow);
+			assert_eq!(names.parse(&names.format(Weekdays::ALL_DAYS)), Ok(Weekdays::ALL_DAYS));
+		}
+	}
+	
+	//		Locale first_day													
+	#[test]
+	fn locale__first_day() {
+		assert_eq!(Locale::from(Language::English).first_day(),  Weekdays::SUNDAY);
+		assert_eq!(Locale::from(Language::Japanese).first_day(), Weekdays::SUNDAY);
+		assert_eq!(Locale::from(Language::German).first_day(),   Weekdays::MONDAY);
+		assert_eq!(Locale::from(Language::Chinese).first_day(),  Weekdays::MONDAY);
+	}
+	#[test]
+	fn locale__first_day__region() {
+		let first_day = |tag: &str| parse::<Locale>(tag).first_day();
+		assert_eq!(first_day("en-GB"),      Weekdays::MONDAY);
+		assert_eq!(first_day("en-AU"),      Weekdays::MONDAY);
+		assert_eq!(first_day("en-US"),      Weekdays::SUNDAY);
+		assert_eq!(first_day("fr-CA"),      Weekdays::SUNDAY);
+		assert_eq!(first_day("zh-Hant-TW"), Weekdays::SUNDAY);
+		assert_eq!(first_day("pt-BR"),      Weekdays::SUNDAY);
+		assert_eq!(first_day("es-MX"),      Weekdays::SUNDAY);
+		assert_eq!(first_day("fr-DZ"),      Weekdays::SATURDAY);
+		assert_eq!(first_day("en-MV"),      Weekdays::FRIDAY);
+		assert_eq!(first_day("es-419"),     Weekdays::MONDAY);
+	}
+	
+	//		Locale language														
+	#[test]
+	fn locale__language() {
+		assert_eq!(Locale::new(Language::French).language(), Language::French);
+	}
+	
+	//		Locale region														
+	#[test]
+	fn locale__region() {
+		assert_eq!(Locale::new(Language::French).region(),      None);
+		assert_eq!(parse::<Locale>("fr-ca").region(), Some("CA"));
+	}
+	
+	//		Locale week_order													
+	#[test]
+	fn locale__week_order() {
+		assert_eq!(Locale::from(Language::Korean).week_order(), [
+			Weekdays::SUNDAY,
+			Weekdays::MONDAY,
+			Weekdays::TUESDAY,
+			Weekdays::WEDNESDAY,
+			Weekdays::THURSDAY,
+			Weekdays::FRIDAY,
+			Weekdays::SATURDAY,
+		]);
+		assert_eq!(Locale::from(Language::Italian).week_order()[6],     Weekdays::SUNDAY);
+		assert_eq!(parse::<Locale>("ko-DE").week_order()[0], Weekdays::MONDAY);
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		I18nError Display													
+	#[test]
+	fn i18n_error__display() {
+		assert_eq!(I18nError::AmbiguousDay("M".to_owned()).to_string(),    "Ambiguous day name: M");
+		assert_eq!(I18nError::UnknownDay("Mond".to_owned()).to_string(),   "Unknown day name: Mond");
+		assert_eq!(I18nError::UnknownLocale("xx".to_owned()).to_string(),  "Unknown locale: xx");
+	}
+	
+	//		Language Display													
+	#[test]
+	fn language__display() {
+		for language in Language::ALL {
+			assert_eq!(language.to_string(), language.tag());
+		}
+	}
+	
+	//		Locale Display														
+	#[test]
+	fn locale__display() {
+		assert_eq!(Locale::from(Language::French).to_string(),          "fr");
+		assert_eq!(parse::<Locale>("fr_ca.UTF-8").to_string(), "fr-CA");
+	}
+	
+	//		Locale FromStr														
+	#[test]
+	fn locale__from_str() {
+		let french = |region: Option<[u8; 2]>| Locale { language: Language::French, region };
+		assert_eq!("fr".parse::<Locale>(),               Ok(french(None)));
+		assert_eq!("FR-ca".parse::<Locale>(),            Ok(french(Some(*b"CA"))));
+		assert_eq!("fr_FR.UTF-8@euro".parse::<Locale>(), Ok(french(Some(*b"FR"))));
+		assert_eq!("fr-Latn-BE-x-ab".parse::<Locale>(),  Ok(french(Some(*b"BE"))));
+		assert_eq!("fr-x-ab".parse::<Locale>(),          Ok(french(None)));
+		assert_eq!("fr-029".parse::<Locale>(),           Ok(french(None)));
+		assert_eq!("zh-Hans-CN".parse::<Locale>(),       Ok(Locale { language: Language::Chinese, region: Some(*b"CN") }));
+		assert_eq!("xx".parse::<Locale>(),               Err(I18nError::UnknownLocale("xx".to_owned())));
+		assert_eq!("".parse::<Locale>(),                 Err(I18nError::UnknownLocale(String::new())));
+	}
+}
@@ -0,0 +1,239 @@
+//		Packages
+
+use super::*;
+use crate::date;
+use chrono::{NaiveTime, TimeZone};
+
+/// Creates a date and time from the given date and hour.
+fn at(date: NaiveDate, hour: u32) -> NaiveDateTime {
+	date.and_time(NaiveTime::from_hms_opt(hour, 0, 0).unwrap())
+}
+
+/// Creates a delivery event on Mondays and Thursdays at 09:00.
+fn delivery() -> IcsEvent {
+	IcsEvent::new(
+		"delivery-42@example.com",
+		"Delivery",
+		Weekdays::parse_const("mon, thu"),
+		at(date(2024, 1, 2), 9),
+		TimeDelta::minutes(90),
+		stamp(),
+	).unwrap()
+}
+
+/// The `VTIMEZONE` component for London used in the tests.
+const LONDON: &str = "\
+BEGIN:VTIMEZONE
+TZID:Europe/London
+BEGIN:DAYLIGHT
+TZOFFSETFROM:+0000
+TZOFFSETTO:+0100
+TZNAME:BST
+DTSTART:19700329T010000
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU
+END:DAYLIGHT
+BEGIN:STANDARD
+TZOFFSETFROM:+0100
+TZOFFSETTO:+0000
+TZNAME:GMT
+DTSTART:19701025T020000
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU
+END:STANDARD
+END:VTIMEZONE
+";
+
+/// Creates the London time zone.
+fn london() -> IcsTimeZone {
+	IcsTimeZone::new("Europe/London", LONDON).unwrap()
+}
+
+/// Returns the time at which the test events were created.
+fn stamp() -> DateTime<Utc> {
+	Utc.with_ymd_and_hms(2023, 12, 1, 12, 0, 0).unwrap()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		IcsTimeZone new														
+	#[test]
+	fn ics_time_zone__new() {
+		let folded = LONDON.replace('\n', "\r\n").replace("TZID:Europe/London", "TZID:Europe/\r\n London");
+		assert_eq!(IcsTimeZone::new("Europe/London", &folded),                                            Some(london()));
+		assert_eq!(IcsTimeZone::new("Europe/Paris",  LONDON),                                             None);
+		assert_eq!(IcsTimeZone::new("Europe/London", LONDON.trim_end().trim_end_matches("END:VTIMEZONE")), None);
+		assert_eq!(IcsTimeZone::new("Europe/London", &format!(" {LONDON}")),                              None);
+		assert_eq!(IcsTimeZone::new("",              "BEGIN:VTIMEZONE\nTZID:\nEND:VTIMEZONE"),             None);
+		assert_eq!(IcsTimeZone::new("a;b",           "BEGIN:VTIMEZONE\nTZID:a;b\nEND:VTIMEZONE"),          None);
+	}
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let event = delivery();
+		assert_eq!(event.days(),             Weekdays::parse_const("mon, thu"));
+		assert_eq!(event.first_occurrence(), Some(date(2024, 1, 4)));
+	}
+	#[test]
+	fn new__invalid() {
+		assert_eq!(IcsEvent::new("a", "b", Weekdays::NONE,   at(date(2024, 1, 1), 9), TimeDelta::hours(1),  stamp()), None);
+		assert_eq!(IcsEvent::new("a", "b", Weekdays::MONDAY, at(date(2024, 1, 1), 9), TimeDelta::hours(-1), stamp()), None);
+	}
+	
+	//		with_exceptions														
+	#[test]
+	fn with_exceptions() {
+		let ics = delivery()
+			.with_exceptions([date(2024, 1, 11), date(2024, 1, 8)])
+			.with_exceptions([date(2024, 1, 8)])
+			.to_ics();
+		assert!(ics.contains("\r\nEXDATE:20240108T090000,20240111T090000\r\n"));
+	}
+	
+	//		with_time_zone														
+	#[test]
+	fn with_time_zone() {
+		let ics = delivery().with_time_zone(london()).with_exceptions([date(2024, 1, 8)]).to_ics();
+		assert!(ics.contains("\r\nDTSTAMP:20231201T120000Z\r\n"));
+		assert!(ics.contains("\r\nDTSTART;TZID=Europe/London:20240104T090000\r\n"));
+		assert!(ics.contains("\r\nEXDATE;TZID=Europe/London:20240108T090000\r\n"));
+		assert!(ics.contains(&format!("CALSCALE:GREGORIAN\r\n{}BEGIN:VEVENT\r\n", LONDON.replace('\n', "\r\n"))));
+	}
+	
+	//		with_utc_times														
+	#[test]
+	fn with_utc_times() {
+		let ics = delivery().with_utc_times().with_exceptions([date(2024, 1, 8)]).to_ics();
+		assert!(ics.contains("\r\nDTSTAMP:20231201T120000Z\r\n"));
+		assert!(ics.contains("\r\nDTSTART:20240104T090000Z\r\n"));
+		assert!(ics.contains("\r\nEXDATE:20240108T090000Z\r\n"));
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		IcsTimeZone id														
+	#[test]
+	fn ics_time_zone__id() {
+		assert_eq!(london().id(), "Europe/London");
+	}
+	
+	//		first_occurrence													
+	#[test]
+	fn first_occurrence() {
+		assert_eq!(delivery().with_end_date(date(2024, 1, 4)).first_occurrence(), Some(date(2024, 1, 4)));
+		assert_eq!(delivery().with_end_date(date(2024, 1, 3)).first_occurrence(), None);
+	}
+	
+	//		to_ics																
+	#[test]
+	fn to_ics() {
+		let ics = delivery()
+			.with_end_date(date(2024, 1, 31))
+			.with_exceptions([date(2024, 1, 15)])
+			.with_description("Ring the bell; leave by the door, if out\nThanks")
+			.with_location("Unit 4\\5")
+			.to_ics();
+		assert_eq!(ics, [
+			"BEGIN:VCALENDAR",
+			"VERSION:2.0",
+			"PRODID:-//weekdays//NONSGML weekdays//EN",
+			"CALSCALE:GREGORIAN",
+			"BEGIN:VEVENT",
+			"UID:delivery-42@example.com",
+			"DTSTAMP:20231201T120000Z",
+			"DTSTART:20240104T090000",
+			"DURATION:PT1H30M",
+			"RRULE:FREQ=WEEKLY;BYDAY=MO,TH;COUNT=8",
+			"EXDATE:20240115T090000",
+			"SUMMARY:Delivery",
+			"DESCRIPTION:Ring the bell\\; leave by the door\\, if out\\nThanks",
+			"LOCATION:Unit 4\\\\5",
+			"END:VEVENT",
+			"END:VCALENDAR",
+			"",
+		].join("\r\n"));
+	}
+	#[test]
+	fn to_ics__floating() {
+		let ics = delivery().to_ics();
+		assert!(ics.contains("\r\nDTSTAMP:20231201T120000Z\r\n"));
+		assert!(ics.contains("\r\nDTSTART:20240104T090000\r\n"));
+		assert!(ics.contains("\r\nRRULE:FREQ=WEEKLY;BYDAY=MO,TH\r\n"));
+	}
+	#[test]
+	fn to_ics__no_occurrences() {
+		let ics = delivery().with_end_date(date(2024, 1, 1)).to_ics();
+		assert!(!ics.contains("VEVENT"));
+		assert!(ics.ends_with("CALSCALE:GREGORIAN\r\nEND:VCALENDAR\r\n"));
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		escape_text															
+	#[test]
+	fn escape_text() {
+		assert_eq!(super::escape_text("a,b;c\\d\r\ne"), "a\\,b\\;c\\\\d\\ne");
+		assert_eq!(super::escape_text("plain"),         "plain");
+	}
+	
+	//		fold_line															
+	#[test]
+	fn fold_line() {
+		let short = "x".repeat(75);
+		assert_eq!(super::fold_line(&short), short);
+		let long   = "x".repeat(160);
+		let folded = super::fold_line(&long);
+		assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+		assert_eq!(folded.split("\r\n").map(str::len).collect::<Vec<_>>(), vec![75, 75, 12]);
+		assert_eq!(folded.replace("\r\n ", ""), long);
+	}
+	#[test]
+	fn fold_line__multibyte() {
+		let line   = format!("{}é€", "x".repeat(73));
+		let folded = super::fold_line(&line);
+		assert_eq!(folded, format!("{}é\r\n €", "x".repeat(73)));
+	}
+	
+	//		format_duration														
+	#[test]
+	fn format_duration() {
+		assert_eq!(super::format_duration(TimeDelta::zero()),                           "PT0S");
+		assert_eq!(super::format_duration(TimeDelta::minutes(90)),                      "PT1H30M");
+		assert_eq!(super::format_duration(TimeDelta::seconds(45)),                      "PT45S");
+		assert_eq!(super::format_duration(TimeDelta::days(2)),                          "P2D");
+		assert_eq!(super::format_duration(TimeDelta::days(1) + TimeDelta::seconds(61)), "P1DT1M1S");
+	}
+	#[test]
+	fn format_duration__hours_and_seconds() {
+		assert_eq!(super::format_duration(TimeDelta::seconds(3_630)),                     "PT1H0M30S");
+		assert_eq!(super::format_duration(TimeDelta::days(1) + TimeDelta::seconds(3_601)), "P1DT1H0M1S");
+		assert_eq!(super::format_duration(TimeDelta::hours(2)),                           "PT2H");
+	}
+	
+	//		write_calendar														
+	#[test]
+	fn write_calendar() {
+		let other = IcsEvent::new("b", "Class", Weekdays::SUNDAY, at(date(2024, 1, 1), 10), TimeDelta::hours(1), stamp()).unwrap();
+		let ics   = super::write_calendar(&[delivery(), other]);
+		assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+		assert!(ics.find("UID:delivery-42").unwrap() < ics.find("UID:b").unwrap());
+		assert_eq!(super::write_calendar(&[]).lines().count(), 5);
+	}
+	#[test]
+	fn write_calendar__time_zones() {
+		let other = IcsEvent::new("b", "Class", Weekdays::SUNDAY, at(date(2024, 1, 1), 10), TimeDelta::hours(1), stamp()).unwrap();
+		let ics   = super::write_calendar(&[delivery().with_time_zone(london()), other.with_time_zone(london())]);
+		assert_eq!(ics.matches("BEGIN:VTIMEZONE").count(), 1);
+		assert!(ics.find("END:VTIMEZONE").unwrap() < ics.find("BEGIN:VEVENT").unwrap());
+		let ended = delivery().with_end_date(date(2024, 1, 1)).with_time_zone(london());
+		assert!(!super::write_calendar(&[ended]).contains("VTIMEZONE"));
+	}
+}
@@ -0,0 +1,167 @@
+//		Packages
+
+use super::*;
+use crate::parse;
+use chrono::{DateTime, Days, Utc};
+
+/// Parses a list of ISO 8601 dates, panicking if any is invalid.
+fn dates(list: &[&str]) -> Vec<NaiveDate> {
+	list.iter().map(|date| parse(date)).collect()
+}
+
+/// Returns the dates on which the given days fall over a number of weeks,
+/// starting from Monday 1 January 2024, with one set of days for each week of
+/// a repeating cycle.
+fn history(cycle: &[Weekdays], weeks: u64) -> Vec<NaiveDate> {
+	let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+	(0..weeks * 7)
+		.map(|offset| start + Days::new(offset))
+		.filter(|date| {
+			let week = usize::try_from(date.signed_duration_since(start).num_days().div_euclid(7)).unwrap();
+			cycle[week % cycle.len()].contains(Weekdays::from(date.weekday()))
+		})
+		.collect()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		PatternInference with_max_period									
+	#[test]
+	fn pattern_inference__with_max_period() {
+		let fortnightly = history(&[Weekdays::MONDAY, Weekdays::NONE], 8);
+		let inference   = PatternInference::new().with_max_period(1).unwrap();
+		let inferred    = inference.infer(fortnightly).unwrap();
+		assert_eq!(inferred.period(), 1);
+		assert_eq!(inferred.days(),   Weekdays::MONDAY);
+		assert_eq!(PatternInference::new().with_max_period(0), None);
+	}
+	
+	//		PatternInference with_min_support									
+	#[test]
+	fn pattern_inference__with_min_support() {
+		let mut orders = history(&[Weekdays::TUESDAY], 10);
+		orders.extend(dates(&["2024-01-05", "2024-01-12", "2024-01-19"]));
+		assert_eq!(PatternInference::new().infer(orders.clone()).unwrap().days(), Weekdays::TUESDAY);
+		let relaxed    = PatternInference::new().with_min_support(0.3).unwrap();
+		assert_eq!(relaxed.infer(orders).unwrap().days(),                         Weekdays::parse_const("tue, fri"));
+		assert_eq!(PatternInference::new().with_min_support(0.0),                 None);
+		assert_eq!(PatternInference::new().with_min_support(1.5),                 None);
+		assert_eq!(PatternInference::new().with_min_support(f64::NAN),            None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		PatternInference infer												
+	#[test]
+	fn pattern_inference__infer__weekly() {
+		let inferred = PatternInference::new().infer(history(&[Weekdays::parse_const("tue, fri")], 12)).unwrap();
+		assert_eq!(inferred.days(),         Weekdays::parse_const("tue, fri"));
+		assert_eq!(inferred.period(),       1);
+		assert_eq!(inferred.weeks(),        12);
+		assert_eq!(inferred.observations(), 24);
+		assert!((inferred.accuracy() - 1.0).abs() < f64::EPSILON);
+		assert_eq!(inferred.pattern().anchor(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+	}
+	#[test]
+	fn pattern_inference__infer__fortnightly() {
+		let cycle    = [Weekdays::WEDNESDAY, Weekdays::NONE];
+		let inferred = PatternInference::new().infer(history(&cycle, 12)).unwrap();
+		assert_eq!(inferred.period(),          2);
+		assert_eq!(inferred.pattern().weeks(), &cycle);
+		assert_eq!(inferred.days(),            Weekdays::WEDNESDAY);
+		//	The history ends with the last observed week
+		assert_eq!(inferred.weeks(),           11);
+		assert_eq!(inferred.confidence(Weekdays::WEDNESDAY), Some(6.0 / 11.0));
+	}
+	#[test]
+	fn pattern_inference__infer__multi_week() {
+		let cycle    = [Weekdays::MONDAY, Weekdays::MONDAY, Weekdays::FRIDAY];
+		let inferred = PatternInference::new().infer(history(&cycle, 12)).unwrap();
+		assert_eq!(inferred.period(),          3);
+		assert_eq!(inferred.pattern().weeks(), &cycle);
+	}
+	#[test]
+	fn pattern_inference__infer__noise() {
+		//	A single extra order should not be mistaken for a cycle
+		let mut orders = history(&[Weekdays::THURSDAY], 12);
+		orders.extend(dates(&["2024-01-09"]));
+		let inferred   = PatternInference::new().infer(orders).unwrap();
+		assert_eq!(inferred.period(), 1);
+		assert_eq!(inferred.days(),   Weekdays::THURSDAY);
+		assert!(inferred.accuracy() < 1.0);
+	}
+	#[test]
+	fn pattern_inference__infer__halved_errors() {
+		//	A fortnightly Tuesday on two of three weeks halves the errors, which
+		//	is not enough to prefer the longer period
+		let mut orders = history(&[Weekdays::THURSDAY], 6);
+		orders.extend(dates(&["2024-01-02", "2024-01-16"]));
+		let inferred   = PatternInference::new().infer(orders.clone()).unwrap();
+		assert_eq!(inferred.period(), 1);
+		assert_eq!(inferred.days(),   Weekdays::THURSDAY);
+		//	On all three weeks, there are no errors left
+		orders.extend(dates(&["2024-01-30"]));
+		let fortnightly = PatternInference::new().infer(orders).unwrap();
+		assert_eq!(fortnightly.period(), 2);
+	}
+	#[test]
+	fn pattern_inference__infer__duplicates() {
+		let orders   = dates(&["2024-01-02", "2024-01-02", "2024-01-09", "2024-01-02"]);
+		let inferred = PatternInference::new().infer(orders).unwrap();
+		assert_eq!(inferred.observations(), 2);
+		assert_eq!(inferred.weeks(),        2);
+		assert_eq!(inferred.days(),         Weekdays::TUESDAY);
+	}
+	#[test]
+	fn pattern_inference__infer__timestamps() {
+		let orders: Vec<DateTime<Utc>> = [
+			"2024-03-04T09:15:00Z",
+			"2024-03-11T17:40:00Z",
+			"2024-03-18T08:05:00Z",
+		].iter().map(|stamp| parse(stamp)).collect();
+		assert_eq!(PatternInference::new().infer(orders).unwrap().days(), Weekdays::MONDAY);
+	}
+	#[test]
+	fn pattern_inference__infer__empty() {
+		assert_eq!(PatternInference::new().infer(Vec::<NaiveDate>::new()), None);
+	}
+	
+	//		InferredPattern confidence											
+	#[test]
+	fn inferred_pattern__confidence() {
+		let orders   = dates(&["2024-01-01", "2024-01-02", "2024-01-08", "2024-01-15", "2024-01-16", "2024-01-22"]);
+		let inferred = PatternInference::new().infer(orders).unwrap();
+		assert_eq!(inferred.confidence(Weekdays::MONDAY),                  Some(1.0));
+		assert_eq!(inferred.confidence(Weekdays::TUESDAY),                 Some(0.5));
+		assert_eq!(inferred.confidence(Weekdays::parse_const("mon-tue")),  Some(0.5));
+		assert_eq!(inferred.confidence(Weekdays::NONE),                    None);
+		assert!(inferred.confidences().iter().zip([1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0]).all(|(a, b)| (a - b).abs() < f64::EPSILON));
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		infer_pattern														
+	#[test]
+	fn infer_pattern() {
+		let orders = history(&[Weekdays::WEEKENDS], 4);
+		assert_eq!(super::infer_pattern(orders.clone()), PatternInference::new().infer(orders));
+	}
+	
+	//		week_number															
+	#[test]
+	fn week_number() {
+		let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+		assert_eq!(super::week_number(&monday),                    super::week_number(&(monday + Days::new(6))));
+		assert_eq!(super::week_number(&monday) + 1,                super::week_number(&(monday + Days::new(7))));
+		assert_eq!(super::week_number(&NaiveDate::from_ymd_opt(1, 1, 1).unwrap()), 0);
+	}
+}
@@ -0,0 +1,53 @@
+//		Packages
+
+use super::*;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let layout = CustomLayout::new([8, 9, 10, 11, 12, 13, 14]).unwrap();
+		assert_eq!(layout.positions(), [8, 9, 10, 11, 12, 13, 14]);
+		assert!(CustomLayout::new([31, 0, 1, 2, 3, 4, 5]).is_some());
+	}
+	#[test]
+	fn new__invalid() {
+		assert_eq!(CustomLayout::new([0, 1, 2, 3, 4, 5, 5]),   None);
+		assert_eq!(CustomLayout::new([32, 1, 2, 3, 4, 5, 6]),  None);
+		assert_eq!(CustomLayout::new([0, 1, 2, 3, 4, 5, 255]), None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		mask																
+	#[test]
+	fn mask() {
+		assert_eq!(BitLayout::Native.mask(),         0b111_1111);
+		assert_eq!(BitLayout::ReversedIso.mask(),    0b111_1111);
+		assert_eq!(BitLayout::JavaOrdinal.mask(),    0b111_1111);
+		assert_eq!(BitLayout::JavaScript.mask(),     0b111_1111);
+		assert_eq!(BitLayout::MySql.mask(),          0b1111_1110);
+		assert_eq!(BitLayout::WindowsSunday1.mask(), 0b111_1111);
+		let custom = BitLayout::Custom(CustomLayout::new([31, 20, 3, 9, 0, 14, 7]).unwrap());
+		assert_eq!(custom.mask(), 0x8010_4289);
+	}
+	
+	//		positions															
+	#[test]
+	fn positions() {
+		assert_eq!(BitLayout::Native.positions(),         [6, 5, 4, 3, 2, 1, 0]);
+		assert_eq!(BitLayout::ReversedIso.positions(),    [0, 1, 2, 3, 4, 5, 6]);
+		assert_eq!(BitLayout::JavaOrdinal.positions(),    [0, 1, 2, 3, 4, 5, 6]);
+		assert_eq!(BitLayout::JavaScript.positions(),     [1, 2, 3, 4, 5, 6, 0]);
+		assert_eq!(BitLayout::MySql.positions(),          [2, 3, 4, 5, 6, 7, 1]);
+		assert_eq!(BitLayout::WindowsSunday1.positions(), [1, 2, 3, 4, 5, 6, 0]);
+	}
+}
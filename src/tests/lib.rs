@@ -3,6 +3,7 @@
 //		Packages
 
 use super::*;
+use crate::layout::CustomLayout;
 #[cfg(feature = "postgres")]
 use claims::assert_err;
 #[cfg(feature = "serde")]
@@ -22,6 +23,43 @@ mod feature_based_tests {
 mod constructors {
 	use super::*;
 	
+	//		from_layout															
+	#[test]
+	fn from_layout() {
+		assert_eq!(Weekdays::from_layout(0b111_1100,   BitLayout::Native),         Some(Weekdays::WEEKDAYS));
+		assert_eq!(Weekdays::from_layout(0b001_1111,   BitLayout::ReversedIso),    Some(Weekdays::WEEKDAYS));
+		assert_eq!(Weekdays::from_layout(0b110_0000,   BitLayout::JavaOrdinal),    Some(Weekdays::WEEKENDS));
+		assert_eq!(Weekdays::from_layout(0b100_0001,   BitLayout::JavaScript),     Some(Weekdays::WEEKENDS));
+		assert_eq!(Weekdays::from_layout(0b1000_0010,  BitLayout::MySql),          Some(Weekdays::WEEKENDS));
+		assert_eq!(Weekdays::from_layout(0b011_1110,   BitLayout::WindowsSunday1), Some(Weekdays::WEEKDAYS));
+		assert_eq!(Weekdays::from_layout(0,            BitLayout::WindowsSunday1), Some(Weekdays::NONE));
+	}
+	#[test]
+	fn from_layout__invalid() {
+		assert_eq!(Weekdays::from_layout(0b1000_0000,  BitLayout::Native),         None);
+		assert_eq!(Weekdays::from_layout(0b000_0001,   BitLayout::MySql),          None);
+		assert_eq!(Weekdays::from_layout(u32::MAX,     BitLayout::ReversedIso),    None);
+	}
+	#[test]
+	fn from_layout__round_trip() {
+		let custom  = BitLayout::Custom(CustomLayout::new([31, 20, 3, 9, 0, 14, 7]).unwrap());
+		let layouts = [
+			BitLayout::Native,
+			BitLayout::ReversedIso,
+			BitLayout::JavaOrdinal,
+			BitLayout::JavaScript,
+			BitLayout::MySql,
+			BitLayout::WindowsSunday1,
+			custom,
+		];
+		for layout in layouts {
+			for bits in 0..=0b111_1111 {
+				let days = Weekdays::new(bits);
+				assert_eq!(Weekdays::from_layout(days.to_layout(layout), layout), Some(days));
+			}
+		}
+	}
+	
 	//		new																	
 	#[test]
 	fn new__valid() {
@@ -37,6 +75,69 @@ mod constructors {
 		assert_eq!(Weekdays::new(0b1000_0001).0, 0b0000_0001);
 		assert_eq!(Weekdays::new(0b1111_1111).0, 0b0111_1111);
 	}
+	
+	//		parse_const															
+	#[test]
+	fn parse_const__names() {
+		assert_eq!(Weekdays::parse_const("mon"),       Weekdays::MONDAY);
+		assert_eq!(Weekdays::parse_const("Tue"),       Weekdays::TUESDAY);
+		assert_eq!(Weekdays::parse_const("WED"),       Weekdays::WEDNESDAY);
+		assert_eq!(Weekdays::parse_const("thursday"),  Weekdays::THURSDAY);
+		assert_eq!(Weekdays::parse_const("Friday"),    Weekdays::FRIDAY);
+		assert_eq!(Weekdays::parse_const(" sat "),     Weekdays::SATURDAY);
+		assert_eq!(Weekdays::parse_const("SUNDAY"),    Weekdays::SUNDAY);
+	}
+	#[test]
+	fn parse_const__lists_and_ranges() {
+		assert_eq!(Weekdays::parse_const(""),                   Weekdays::NONE);
+		assert_eq!(Weekdays::parse_const("  "),                 Weekdays::NONE);
+		assert_eq!(Weekdays::parse_const("mon-fri"),            Weekdays::WEEKDAYS);
+		assert_eq!(Weekdays::parse_const("sat,sun"),            Weekdays::WEEKENDS);
+		assert_eq!(Weekdays::parse_const("Mon - Sun"),          Weekdays::ALL_DAYS);
+		assert_eq!(Weekdays::parse_const("fri-mon"),            Weekdays::new(0b10001_11));
+		assert_eq!(Weekdays::parse_const("Mon, Wed-Fri, Sun"),  Weekdays::new(0b10111_01));
+		assert_eq!(Weekdays::parse_const("tue,tue,tuesday"),    Weekdays::TUESDAY);
+	}
+	#[test]
+	fn parse_const__const() {
+		const DAYS: Weekdays = Weekdays::parse_const("tue, thu-sat");
+		assert_eq!(DAYS, Weekdays::new(0b01011_10));
+	}
+	#[test]
+	#[should_panic(expected = "Invalid weekdays specification")]
+	fn parse_const__invalid_name() {
+		let _ = Weekdays::parse_const("mon,fry");
+	}
+	#[test]
+	#[should_panic(expected = "Invalid weekdays specification")]
+	fn parse_const__invalid_abbreviation() {
+		let _ = Weekdays::parse_const("mond");
+	}
+	#[test]
+	#[should_panic(expected = "Invalid weekdays specification")]
+	fn parse_const__invalid_empty_entry() {
+		let _ = Weekdays::parse_const("mon,,tue");
+	}
+	#[test]
+	#[should_panic(expected = "Invalid weekdays specification")]
+	fn parse_const__invalid_range() {
+		let _ = Weekdays::parse_const("mon-tue-wed");
+	}
+	
+	//		range																
+	#[test]
+	fn range() {
+		assert_eq!(Weekdays::range(Weekdays::MONDAY,   Weekdays::FRIDAY),   Weekdays::WEEKDAYS);
+		assert_eq!(Weekdays::range(Weekdays::SATURDAY, Weekdays::SUNDAY),   Weekdays::WEEKENDS);
+		assert_eq!(Weekdays::range(Weekdays::MONDAY,   Weekdays::SUNDAY),   Weekdays::ALL_DAYS);
+		assert_eq!(Weekdays::range(Weekdays::THURSDAY, Weekdays::THURSDAY), Weekdays::THURSDAY);
+		assert_eq!(Weekdays::range(Weekdays::SUNDAY,   Weekdays::MONDAY),   Weekdays::new(0b10000_01));
+		assert_eq!(Weekdays::range(Weekdays::FRIDAY,   Weekdays::TUESDAY),  Weekdays::new(0b11001_11));
+		assert_eq!(Weekdays::range(Weekdays::TUESDAY,  Weekdays::MONDAY),   Weekdays::ALL_DAYS);
+		assert_eq!(Weekdays::range(Weekdays::WEEKENDS, Weekdays::SUNDAY),   Weekdays::WEEKENDS);
+		assert_eq!(Weekdays::range(Weekdays::NONE,     Weekdays::SUNDAY),   Weekdays::NONE);
+		assert_eq!(Weekdays::range(Weekdays::MONDAY,   Weekdays::NONE),     Weekdays::NONE);
+	}
 }
 
 mod public_methods {
@@ -64,6 +165,42 @@ mod public_methods {
 		assert_eq!(Weekdays::new(0b11111_11).days(), 7);
 	}
 	
+	//		gaps																
+	#[test]
+	fn gaps() {
+		assert_eq!(Weekdays::new(0b00000_00).gaps().collect::<Vec<_>>(), vec![(Weekdays::MONDAY, 7)]);
+		assert_eq!(Weekdays::new(0b11111_11).gaps().collect::<Vec<_>>(), vec![]);
+		assert_eq!(Weekdays::new(0b11111_00).gaps().collect::<Vec<_>>(), vec![(Weekdays::SATURDAY, 2)]);
+		assert_eq!(Weekdays::new(0b00111_00).gaps().collect::<Vec<_>>(), vec![(Weekdays::SATURDAY, 4)]);
+		assert_eq!(Weekdays::new(0b10101_01).gaps().collect::<Vec<_>>(), vec![
+			(Weekdays::TUESDAY,  1),
+			(Weekdays::THURSDAY, 1),
+			(Weekdays::SATURDAY, 1),
+		]);
+	}
+	
+	//		hamming_distance													
+	#[test]
+	fn hamming_distance() {
+		assert_eq!(Weekdays::new(0b00000_00).hamming_distance(Weekdays::new(0b00000_00)), 0);
+		assert_eq!(Weekdays::new(0b11111_11).hamming_distance(Weekdays::new(0b11111_11)), 0);
+		assert_eq!(Weekdays::new(0b11111_00).hamming_distance(Weekdays::new(0b00000_11)), 7);
+		assert_eq!(Weekdays::new(0b11100_00).hamming_distance(Weekdays::new(0b01110_00)), 2);
+		assert_eq!(Weekdays::new(0b10000_00).hamming_distance(Weekdays::new(0b00000_00)), 1);
+	}
+	
+	//		is_contiguous														
+	#[test]
+	fn is_contiguous() {
+		assert!(!Weekdays::new(0b00000_00).is_contiguous());
+		assert!( Weekdays::new(0b00000_01).is_contiguous());
+		assert!( Weekdays::new(0b11111_00).is_contiguous());
+		assert!( Weekdays::new(0b10000_11).is_contiguous());
+		assert!( Weekdays::new(0b11111_11).is_contiguous());
+		assert!(!Weekdays::new(0b10100_00).is_contiguous());
+		assert!(!Weekdays::new(0b10000_10).is_contiguous());
+	}
+	
 	//		is_empty															
 	#[test]
 	fn is_empty() {
@@ -102,6 +239,18 @@ mod public_methods {
 		assert!( Weekdays::WEEKENDS .is_weekend());
 	}
 	
+	//		jaccard_similarity													
+	#[test]
+	fn jaccard_similarity() {
+		let similarity = |a: u8, b: u8| Weekdays::new(a).jaccard_similarity(Weekdays::new(b));
+		assert!((similarity(0b00000_00, 0b00000_00) - 1.0 ).abs() < f64::EPSILON);
+		assert!((similarity(0b11111_11, 0b11111_11) - 1.0 ).abs() < f64::EPSILON);
+		assert!((similarity(0b11111_00, 0b00000_11) - 0.0 ).abs() < f64::EPSILON);
+		assert!((similarity(0b11100_00, 0b01110_00) - 0.5 ).abs() < f64::EPSILON);
+		assert!((similarity(0b11000_00, 0b01000_00) - 0.5 ).abs() < f64::EPSILON);
+		assert!((similarity(0b10000_00, 0b11110_00) - 0.25).abs() < f64::EPSILON);
+	}
+	
 	//		iter																
 	#[test]
 	fn iter() {
@@ -113,6 +262,129 @@ mod public_methods {
 		assert_eq!(iter.next(), None);
 	}
 	
+	//		longest_run															
+	#[test]
+	fn longest_run() {
+		assert_eq!(Weekdays::new(0b00000_00).longest_run(), 0);
+		assert_eq!(Weekdays::new(0b00010_00).longest_run(), 1);
+		assert_eq!(Weekdays::new(0b11100_10).longest_run(), 3);
+		assert_eq!(Weekdays::new(0b10010_11).longest_run(), 3);
+		assert_eq!(Weekdays::new(0b11011_11).longest_run(), 6);
+		assert_eq!(Weekdays::new(0b11111_11).longest_run(), 7);
+	}
+	
+	//		max_gap																
+	#[test]
+	fn max_gap() {
+		assert_eq!(Weekdays::new(0b00000_00).max_gap(), 7);
+		assert_eq!(Weekdays::new(0b00010_00).max_gap(), 6);
+		assert_eq!(Weekdays::new(0b10010_00).max_gap(), 3);
+		assert_eq!(Weekdays::new(0b01000_01).max_gap(), 4);
+		assert_eq!(Weekdays::new(0b11111_11).max_gap(), 0);
+	}
+	
+	//		nearest																
+	#[test]
+	fn nearest__hamming() {
+		let target     = Weekdays::new(0b11100_00);
+		let candidates = [
+			Weekdays::new(0b00000_11),
+			Weekdays::new(0b01110_00),
+			Weekdays::new(0b11100_00),
+			Weekdays::new(0b11000_00),
+		];
+		assert_eq!(target.nearest(&candidates, 3, Similarity::Hamming), vec![
+			(2, candidates[2]),
+			(3, candidates[3]),
+			(1, candidates[1]),
+		]);
+	}
+	#[test]
+	fn nearest__jaccard() {
+		let target     = Weekdays::new(0b11100_00);
+		let candidates = [
+			Weekdays::new(0b11111_11),
+			Weekdays::new(0b01110_00),
+			Weekdays::new(0b11000_00),
+			Weekdays::new(0b00000_11),
+		];
+		assert_eq!(target.nearest(&candidates, 4, Similarity::Jaccard), vec![
+			(2, candidates[2]),
+			(1, candidates[1]),
+			(0, candidates[0]),
+			(3, candidates[3]),
+		]);
+	}
+	#[test]
+	fn nearest__overlap() {
+		let target     = Weekdays::new(0b11100_00);
+		let candidates = [
+			Weekdays::new(0b00000_11),
+			Weekdays::new(0b11111_11),
+			Weekdays::new(0b01100_00),
+			Weekdays::new(0b11100_00),
+		];
+		assert_eq!(target.nearest(&candidates, 2, Similarity::Overlap), vec![
+			(1, candidates[1]),
+			(3, candidates[3]),
+		]);
+	}
+	#[test]
+	fn nearest__stable_ties() {
+		let target     = Weekdays::new(0b10000_00);
+		let candidates = [
+			Weekdays::new(0b01000_00),
+			Weekdays::new(0b00100_00),
+			Weekdays::new(0b00010_00),
+		];
+		assert_eq!(target.nearest(&candidates, 3, Similarity::Hamming), vec![
+			(0, candidates[0]),
+			(1, candidates[1]),
+			(2, candidates[2]),
+		]);
+		assert_eq!(target.nearest(&candidates, 3, Similarity::Jaccard), vec![
+			(0, candidates[0]),
+			(1, candidates[1]),
+			(2, candidates[2]),
+		]);
+	}
+	#[test]
+	fn nearest__bounds() {
+		let target     = Weekdays::new(0b10000_00);
+		let candidates = [Weekdays::new(0b01000_00)];
+		assert_eq!(target.nearest(&candidates, 5, Similarity::Overlap), vec![(0, candidates[0])]);
+		assert_eq!(target.nearest(&candidates, 0, Similarity::Overlap), vec![]);
+		assert_eq!(target.nearest(&[],          3, Similarity::Overlap), vec![]);
+	}
+	
+	//		overlap																
+	#[test]
+	fn overlap() {
+		assert_eq!(Weekdays::new(0b00000_00).overlap(Weekdays::new(0b00000_00)), 0);
+		assert_eq!(Weekdays::new(0b11111_11).overlap(Weekdays::new(0b11111_11)), 7);
+		assert_eq!(Weekdays::new(0b11111_00).overlap(Weekdays::new(0b00000_11)), 0);
+		assert_eq!(Weekdays::new(0b11100_00).overlap(Weekdays::new(0b01110_00)), 2);
+	}
+	
+	//		runs																
+	#[test]
+	fn runs() {
+		assert_eq!(Weekdays::new(0b00000_00).runs().collect::<Vec<_>>(), vec![]);
+		assert_eq!(Weekdays::new(0b11111_11).runs().collect::<Vec<_>>(), vec![(Weekdays::MONDAY, 7)]);
+		assert_eq!(Weekdays::new(0b11111_00).runs().collect::<Vec<_>>(), vec![(Weekdays::MONDAY, 5)]);
+		assert_eq!(Weekdays::new(0b00000_01).runs().collect::<Vec<_>>(), vec![(Weekdays::SUNDAY, 1)]);
+		assert_eq!(Weekdays::new(0b11011_11).runs().collect::<Vec<_>>(), vec![(Weekdays::THURSDAY, 6)]);
+		assert_eq!(Weekdays::new(0b10011_01).runs().collect::<Vec<_>>(), vec![
+			(Weekdays::THURSDAY, 2),
+			(Weekdays::SUNDAY,   2),
+		]);
+		assert_eq!(Weekdays::new(0b10101_00).runs().collect::<Vec<_>>(), vec![
+			(Weekdays::MONDAY,    1),
+			(Weekdays::WEDNESDAY, 1),
+			(Weekdays::FRIDAY,    1),
+		]);
+	}
+	
 	//		to_chrono_vec														
 	#[cfg(feature = "chrono")]
 	#[test]
@@ -132,6 +404,30 @@ mod public_methods {
 		]);
 	}
 	
+	//		to_layout															
+	#[test]
+	fn to_layout() {
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::Native),           0b100_0000);
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::ReversedIso),      0b000_0001);
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::JavaOrdinal),      0b000_0001);
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::JavaScript),       0b000_0010);
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::MySql),            0b000_0100);
+		assert_eq!(Weekdays::MONDAY.to_layout(BitLayout::WindowsSunday1),   0b000_0010);
+		assert_eq!(Weekdays::SUNDAY.to_layout(BitLayout::Native),           0b000_0001);
+		assert_eq!(Weekdays::SUNDAY.to_layout(BitLayout::ReversedIso),      0b100_0000);
+		assert_eq!(Weekdays::SUNDAY.to_layout(BitLayout::JavaScript),       0b000_0001);
+		assert_eq!(Weekdays::SUNDAY.to_layout(BitLayout::MySql),            0b000_0010);
+		assert_eq!(Weekdays::SATURDAY.to_layout(BitLayout::MySql),          0b1000_0000);
+		assert_eq!(Weekdays::WEEKDAYS.to_layout(BitLayout::WindowsSunday1), 0b011_1110);
+		assert_eq!(Weekdays::NONE.to_layout(BitLayout::WindowsSunday1),     0);
+	}
+	#[test]
+	fn to_layout__native() {
+		for bits in 0..=0b111_1111 {
+			assert_eq!(Weekdays::new(bits).to_layout(BitLayout::Native), u32::from(bits));
+		}
+	}
+	
 	//		to_vec																
 	#[test]
 	fn to_vec() {
@@ -149,6 +445,16 @@ mod public_methods {
 			Weekdays::SUNDAY,
 		]);
 	}
+	
+	//		union																
+	#[test]
+	fn union() {
+		assert_eq!(Weekdays::new(0b00000_00).union(Weekdays::new(0b00000_00)), Weekdays::new(0b00000_00));
+		assert_eq!(Weekdays::new(0b00000_01).union(Weekdays::new(0b00000_00)), Weekdays::new(0b00000_01));
+		assert_eq!(Weekdays::new(0b00000_00).union(Weekdays::new(0b00000_01)), Weekdays::new(0b00000_01));
+		assert_eq!(Weekdays::new(0b00000_01).union(Weekdays::new(0b00000_01)), Weekdays::new(0b00000_01));
+		assert_eq!(Weekdays::new(0b00000_01).union(Weekdays::new(0b00000_10)), Weekdays::new(0b00000_11));
+	}
 }
 
 mod derived_traits {
@@ -411,6 +717,32 @@ mod traits {
 	}
 }
 
+mod functions {
+	use super::*;
+	
+	//		format_clock														
+	#[test]
+	fn format_clock() {
+		assert_eq!(super::format_clock(0),    "00:00");
+		assert_eq!(super::format_clock(510),  "08:30");
+		assert_eq!(super::format_clock(1440), "24:00");
+		assert_eq!(super::format_clock(1500), "25:00");
+		assert_eq!(super::format_clock(2880), "48:00");
+	}
+	
+	//		format_runs															
+	#[test]
+	fn format_runs() {
+		let names = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+		assert_eq!(super::format_runs(Weekdays::WEEKDAYS,                     names, ".."), "Mo..Fr");
+		assert_eq!(super::format_runs(Weekdays::ALL_DAYS,                     names, "-"),  "Mo-Su");
+		assert_eq!(super::format_runs(Weekdays::parse_const("mon-tue, thu"),  names, "-"),  "Mo,Tu,Th");
+		assert_eq!(super::format_runs(Weekdays::parse_const("fri-tue"),       names, "-"),  "Mo,Tu,Fr-Su");
+		assert_eq!(super::format_runs(Weekdays::parse_const("mon, sat-sun"),  names, "-"),  "Mo,Sa,Su");
+		assert_eq!(super::format_runs(Weekdays::NONE,                         names, "-"),  "");
+	}
+}
+
 #[cfg(feature = "chrono")]
 mod conversions__chrono {
 	use super::*;
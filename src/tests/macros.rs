@@ -0,0 +1,45 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use crate::{Weekdays, weekdays};
+
+
+
+//		Tests
+
+//		weekdays!																
+#[test]
+fn weekdays__single_days() {
+	assert_eq!(weekdays!(Mon), Weekdays::MONDAY);
+	assert_eq!(weekdays!(Tue), Weekdays::TUESDAY);
+	assert_eq!(weekdays!(Wed), Weekdays::WEDNESDAY);
+	assert_eq!(weekdays!(Thu), Weekdays::THURSDAY);
+	assert_eq!(weekdays!(Fri), Weekdays::FRIDAY);
+	assert_eq!(weekdays!(Sat), Weekdays::SATURDAY);
+	assert_eq!(weekdays!(Sun), Weekdays::SUNDAY);
+	assert_eq!(weekdays!(Wednesday), Weekdays::WEDNESDAY);
+}
+#[test]
+fn weekdays__lists() {
+	assert_eq!(weekdays!(),                                  Weekdays::NONE);
+	assert_eq!(weekdays!(Sat, Sun),                          Weekdays::WEEKENDS);
+	assert_eq!(weekdays!(Sat, Sun,),                         Weekdays::WEEKENDS);
+	assert_eq!(weekdays!(Mon, Tue, Wed, Thu, Fri),           Weekdays::WEEKDAYS);
+	assert_eq!(weekdays!(Mon, Tue, Wed, Thu, Fri, Sat, Sun), Weekdays::ALL_DAYS);
+	assert_eq!(weekdays!(Mon, Mon),                          Weekdays::MONDAY);
+}
+#[test]
+fn weekdays__ranges() {
+	assert_eq!(weekdays!(Mon..=Fri),           Weekdays::WEEKDAYS);
+	assert_eq!(weekdays!(Sat..=Sun),           Weekdays::WEEKENDS);
+	assert_eq!(weekdays!(Mon..=Sun),           Weekdays::ALL_DAYS);
+	assert_eq!(weekdays!(Thu..=Thu),           Weekdays::THURSDAY);
+	assert_eq!(weekdays!(Sat..=Tue),           Weekdays::new(0b11000_11));
+	assert_eq!(weekdays!(Mon, Wed..=Fri, Sun), Weekdays::new(0b10111_01));
+}
+#[test]
+fn weekdays__const() {
+	const DAYS: Weekdays = weekdays!(Tue, Thu..=Sat);
+	assert_eq!(DAYS, Weekdays::new(0b01011_10));
+}
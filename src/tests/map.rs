@@ -0,0 +1,220 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let map = WeekdaysMap::<u8>::new();
+		assert!(map.is_empty());
+		assert_eq!(map.len(),  0);
+		assert_eq!(map.keys(), Weekdays::NONE);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		clear																
+	#[test]
+	fn clear() {
+		let mut map = WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::SUNDAY, 7)]);
+		map.clear();
+		assert!(map.is_empty());
+	}
+	
+	//		filter_by															
+	#[test]
+	fn filter_by() {
+		let map = WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::FRIDAY, 5), (Weekdays::SUNDAY, 7)]);
+		assert_eq!(map.clone().filter_by(Weekdays::WEEKDAYS).keys(), Weekdays::new(0b10001_00));
+		assert_eq!(map.clone().filter_by(Weekdays::WEEKENDS).keys(), Weekdays::SUNDAY);
+		assert_eq!(map.clone().filter_by(Weekdays::NONE).keys(),     Weekdays::NONE);
+		assert_eq!(map.clone().filter_by(Weekdays::ALL_DAYS),        map);
+	}
+	
+	//		get																	
+	#[test]
+	fn get() {
+		let map = WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::SUNDAY, 7)]);
+		assert_eq!(map.get(Weekdays::MONDAY),   Some(&1));
+		assert_eq!(map.get(Weekdays::SUNDAY),   Some(&7));
+		assert_eq!(map.get(Weekdays::TUESDAY),  None);
+		assert_eq!(map.get(Weekdays::NONE),     None);
+		assert_eq!(map.get(Weekdays::ALL_DAYS), Some(&1));
+	}
+	
+	//		get_mut																
+	#[test]
+	fn get_mut() {
+		let mut map = WeekdaysMap::from([(Weekdays::MONDAY, 1)]);
+		if let Some(value) = map.get_mut(Weekdays::MONDAY) {
+			*value = 10;
+		}
+		assert_eq!(map.get(Weekdays::MONDAY),      Some(&10));
+		assert_eq!(map.get_mut(Weekdays::TUESDAY), None);
+	}
+	
+	//		groups																
+	#[test]
+	fn groups() {
+		let mut map = WeekdaysMap::new();
+		map.insert_all(Weekdays::WEEKDAYS, "9-5");
+		map.insert_all(Weekdays::WEEKENDS, "10-2");
+		_ = map.insert(Weekdays::WEDNESDAY, "9-1");
+		assert_eq!(map.groups(), vec![
+			(Weekdays::new(0b11011_00), &"9-5"),
+			(Weekdays::WEDNESDAY,       &"9-1"),
+			(Weekdays::WEEKENDS,        &"10-2"),
+		]);
+		assert_eq!(WeekdaysMap::<u8>::new().groups(), vec![]);
+	}
+	
+	//		insert																
+	#[test]
+	fn insert() {
+		let mut map = WeekdaysMap::new();
+		assert_eq!(map.insert(Weekdays::TUESDAY,  2), Ok(None));
+		assert_eq!(map.insert(Weekdays::TUESDAY,  3), Ok(Some(2)));
+		assert_eq!(map.insert(Weekdays::SUNDAY,   6), Ok(None));
+		assert_eq!(map.insert(Weekdays::WEEKENDS, 7), Err(MapError::NotSingleDay(Weekdays::WEEKENDS)));
+		assert_eq!(map.insert(Weekdays::NONE,     0), Err(MapError::NotSingleDay(Weekdays::NONE)));
+		assert_eq!(map.iter().collect::<Vec<_>>(), vec![(Weekdays::TUESDAY, &3), (Weekdays::SUNDAY, &6)]);
+	}
+	
+	//		insert_all															
+	#[test]
+	fn insert_all() {
+		let mut map = WeekdaysMap::new();
+		map.insert_all(Weekdays::WEEKENDS, 1);
+		map.insert_all(Weekdays::new(0b00001_10), 2);
+		assert_eq!(map.iter().collect::<Vec<_>>(), vec![
+			(Weekdays::FRIDAY,   &2),
+			(Weekdays::SATURDAY, &2),
+			(Weekdays::SUNDAY,   &1),
+		]);
+	}
+	
+	//		iter																
+	#[test]
+	fn iter() {
+		let map = WeekdaysMap::from([(Weekdays::SUNDAY, 7), (Weekdays::MONDAY, 1), (Weekdays::THURSDAY, 4)]);
+		assert_eq!(map.iter().collect::<Vec<_>>(), vec![
+			(Weekdays::MONDAY,   &1),
+			(Weekdays::THURSDAY, &4),
+			(Weekdays::SUNDAY,   &7),
+		]);
+	}
+	
+	//		iter_mut															
+	#[test]
+	fn iter_mut() {
+		let mut map = WeekdaysMap::from([(Weekdays::SUNDAY, 7), (Weekdays::MONDAY, 1)]);
+		for (_, value) in map.iter_mut() {
+			*value += 10;
+		}
+		assert_eq!(map, WeekdaysMap::from([(Weekdays::MONDAY, 11), (Weekdays::SUNDAY, 17)]));
+	}
+	
+	//		map																	
+	#[test]
+	fn map() {
+		let map = WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::SUNDAY, 7)]);
+		assert_eq!(map.map(|value| value.to_string()), WeekdaysMap::from([
+			(Weekdays::MONDAY, "1".to_owned()),
+			(Weekdays::SUNDAY, "7".to_owned()),
+		]));
+	}
+	
+	//		remove																
+	#[test]
+	fn remove() {
+		let mut map = WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::SUNDAY, 7)]);
+		assert_eq!(map.remove(Weekdays::MONDAY), Some(1));
+		assert_eq!(map.remove(Weekdays::MONDAY), None);
+		assert_eq!(map.keys(), Weekdays::SUNDAY);
+		assert_eq!(map.len(),  1);
+	}
+	
+	//		values																
+	#[test]
+	fn values() {
+		let map = WeekdaysMap::from([(Weekdays::SUNDAY, 7), (Weekdays::MONDAY, 1)]);
+		assert_eq!(map.values().collect::<Vec<_>>(), vec![&1, &7]);
+	}
+}
+
+mod traits {
+	use super::*;
+	#[cfg(feature = "serde")]
+	use claims::{assert_err, assert_ok_eq};
+	
+	//		Default																
+	#[test]
+	fn default() {
+		assert_eq!(WeekdaysMap::<u8>::default(), WeekdaysMap::new());
+	}
+	
+	//		Deserialize															
+	#[cfg(feature = "serde")]
+	#[test]
+	fn deserialize() {
+		assert_ok_eq!(
+			serde_json::from_str::<WeekdaysMap<u8>>(r#"{"monday":1,"Sun":7,"FRIDAY":5}"#),
+			WeekdaysMap::from([(Weekdays::MONDAY, 1), (Weekdays::FRIDAY, 5), (Weekdays::SUNDAY, 7)]),
+		);
+		assert_ok_eq!(serde_json::from_str::<WeekdaysMap<u8>>("{}"), WeekdaysMap::new());
+	}
+	#[cfg(feature = "serde")]
+	#[test]
+	fn deserialize__invalid() {
+		let invalid   = serde_json::from_str::<WeekdaysMap<u8>>(r#"{"funday":1}"#);
+		let range     = serde_json::from_str::<WeekdaysMap<u8>>(r#"{"mon-fri":1}"#);
+		let single    = serde_json::from_str::<WeekdaysMap<u8>>(r#"{"mon-mon":1}"#);
+		let padded    = serde_json::from_str::<WeekdaysMap<u8>>(r#"{" Monday ":1}"#);
+		let duplicate = serde_json::from_str::<WeekdaysMap<u8>>(r#"{"mon":1,"monday":2}"#);
+		assert_err!(&range);
+		assert_err!(&single);
+		assert_eq!(assert_err!(padded).to_string(),    "invalid day name:  Monday  at line 1 column 11");
+		assert_eq!(assert_err!(invalid).to_string(),   "invalid day name: funday at line 1 column 9");
+		assert_eq!(assert_err!(duplicate).to_string(), "duplicate day: monday at line 1 column 20");
+	}
+	
+	//		FromIterator														
+	#[test]
+	fn from_iter() {
+		let map = vec![(Weekdays::SUNDAY, 7), (Weekdays::SUNDAY, 8)].into_iter().collect::<WeekdaysMap<_>>();
+		assert_eq!(map.get(Weekdays::SUNDAY), Some(&8));
+		assert_eq!(map.len(),                 1);
+	}
+	#[test]
+	fn from_iter__several_days() {
+		let map = vec![(Weekdays::WEEKENDS, 1), (Weekdays::NONE, 2), (Weekdays::SUNDAY, 3)].into_iter().collect::<WeekdaysMap<_>>();
+		assert_eq!(map.iter().collect::<Vec<_>>(), vec![(Weekdays::SATURDAY, &1), (Weekdays::SUNDAY, &3)]);
+	}
+	
+	//		MapError Display													
+	#[test]
+	fn map_error__display() {
+		assert_eq!(MapError::NotSingleDay(Weekdays::WEEKENDS).to_string(), "Expected a single day, found 2 days");
+		assert_eq!(MapError::NotSingleDay(Weekdays::NONE).to_string(),     "Expected a single day, found 0 days");
+	}
+	
+	//		Serialize															
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serialize() {
+		let map = WeekdaysMap::from([(Weekdays::SUNDAY, 7), (Weekdays::MONDAY, 1)]);
+		assert_eq!(serde_json::to_string(&map).unwrap(),                      r#"{"monday":1,"sunday":7}"#);
+		assert_eq!(serde_json::to_string(&WeekdaysMap::<u8>::new()).unwrap(), "{}");
+	}
+}
@@ -0,0 +1,182 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+use crate::date;
+
+/// Creates a fortnightly pattern anchored on Monday 1 January 2024.
+fn fortnightly() -> MultiWeekPattern {
+	MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::new(0b10100_00), Weekdays::new(0b00001_01)]).unwrap()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let pattern = MultiWeekPattern::new(date(2024, 1, 4), vec![Weekdays::MONDAY, Weekdays::NONE]).unwrap();
+		assert_eq!(pattern.anchor(),    date(2024, 1, 1));
+		assert_eq!(pattern.cycle_len(), 2);
+		assert_eq!(pattern.weeks(),     &[Weekdays::MONDAY, Weekdays::NONE]);
+	}
+	#[test]
+	fn new__empty() {
+		assert_eq!(MultiWeekPattern::new(date(2024, 1, 1), vec![]), None);
+	}
+	
+	//		from_u16															
+	#[test]
+	fn from_u16() {
+		let pattern = MultiWeekPattern::from_u16(date(2024, 1, 1), 2, 0b10100_00_00001_01).unwrap();
+		assert_eq!(pattern, fortnightly());
+		assert_eq!(MultiWeekPattern::from_u16(date(2024, 1, 1), 1, 0b11111_00).unwrap().weeks(), &[Weekdays::WEEKDAYS]);
+		assert_eq!(MultiWeekPattern::from_u16(date(2024, 1, 1), 0, 0),           None);
+		assert_eq!(MultiWeekPattern::from_u16(date(2024, 1, 1), 3, 0),           None);
+		assert_eq!(MultiWeekPattern::from_u16(date(2024, 1, 1), 1, 0b1_00000_00), None);
+	}
+	
+	//		from_u32															
+	#[test]
+	fn from_u32() {
+		let pattern = MultiWeekPattern::from_u32(date(2024, 1, 1), 4, 0b10000_00_00000_00_00000_01_00000_10).unwrap();
+		assert_eq!(pattern.weeks(), &[Weekdays::MONDAY, Weekdays::NONE, Weekdays::SUNDAY, Weekdays::SATURDAY]);
+		assert_eq!(MultiWeekPattern::from_u32(date(2024, 1, 1), 5, 0), None);
+	}
+	
+	//		from_u64															
+	#[test]
+	fn from_u64() {
+		let pattern = MultiWeekPattern::from_u64(date(2024, 1, 1), 9, 1 << 62).unwrap();
+		assert_eq!(pattern.weeks()[0], Weekdays::MONDAY);
+		assert_eq!(pattern.weeks()[8], Weekdays::NONE);
+		assert_eq!(MultiWeekPattern::from_u64(date(2024, 1, 1), 9,  u64::MAX), None);
+		assert_eq!(MultiWeekPattern::from_u64(date(2024, 1, 1), 10, 0),        None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		contains_date														
+	#[test]
+	fn contains_date() {
+		let pattern = fortnightly();
+		assert!( pattern.contains_date(date(2024, 1,  1)));
+		assert!( pattern.contains_date(date(2024, 1,  3)));
+		assert!(!pattern.contains_date(date(2024, 1,  2)));
+		assert!( pattern.contains_date(date(2024, 1, 12)));
+		assert!( pattern.contains_date(date(2024, 1, 14)));
+		assert!(!pattern.contains_date(date(2024, 1,  8)));
+		assert!( pattern.contains_date(date(2024, 1, 15)));
+	}
+	#[test]
+	fn contains_date__before_anchor() {
+		let pattern = fortnightly();
+		assert!( pattern.contains_date(date(2023, 12, 29)));
+		assert!(!pattern.contains_date(date(2023, 12, 25)));
+		assert!( pattern.contains_date(date(2023, 12, 18)));
+	}
+	
+	//		count_occurrences													
+	#[test]
+	fn count_occurrences() {
+		let pattern = fortnightly();
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 1), date(2024, 1,  1)), 0);
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 1), date(2024, 1,  2)), 1);
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 1), date(2024, 1, 15)), 4);
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 2), date(2024, 1, 16)), 4);
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 1), date(2024, 2, 12)), 12);
+		assert_eq!(pattern.count_occurrences(date(2024, 1, 9), date(2024, 1,  1)), 0);
+	}
+	#[test]
+	fn count_occurrences__matches_iteration() {
+		let pattern = MultiWeekPattern::new(date(2024, 3, 6), vec![
+			Weekdays::new(0b11000_01),
+			Weekdays::NONE,
+			Weekdays::new(0b00110_10),
+		]).unwrap();
+		let start = date(2023, 11, 17);
+		let end   = date(2024, 8, 3);
+		let count = start.iter_days().take_while(|&day| day < end).filter(|&day| pattern.contains_date(day)).count();
+		assert_eq!(pattern.count_occurrences(start, end), count as u64);
+	}
+	
+	//		next_occurrence														
+	#[test]
+	fn next_occurrence() {
+		let pattern = fortnightly();
+		assert_eq!(pattern.next_occurrence(date(2024, 1,  1)), Some(date(2024, 1,  1)));
+		assert_eq!(pattern.next_occurrence(date(2024, 1,  2)), Some(date(2024, 1,  3)));
+		assert_eq!(pattern.next_occurrence(date(2024, 1,  4)), Some(date(2024, 1, 12)));
+		assert_eq!(pattern.next_occurrence(date(2024, 1, 13)), Some(date(2024, 1, 14)));
+		assert_eq!(pattern.next_occurrence(date(2023, 12, 30)), Some(date(2023, 12, 31)));
+	}
+	#[test]
+	fn next_occurrence__empty() {
+		let pattern = MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::NONE; 3]).unwrap();
+		assert_eq!(pattern.next_occurrence(date(2024, 1, 1)), None);
+	}
+	
+	//		to_u16																
+	#[test]
+	fn to_u16() {
+		assert_eq!(fortnightly().to_u16(), Some(0b10100_00_00001_01));
+		assert_eq!(MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::NONE; 3]).unwrap().to_u16(), None);
+	}
+	
+	//		to_u32																
+	#[test]
+	fn to_u32() {
+		assert_eq!(fortnightly().to_u32(), Some(0b10100_00_00001_01));
+		let pattern = MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::ALL_DAYS; 4]).unwrap();
+		assert_eq!(pattern.to_u32(), Some((1 << 28) - 1));
+		assert_eq!(MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::NONE; 5]).unwrap().to_u32(), None);
+	}
+	
+	//		to_u64																
+	#[test]
+	fn to_u64() {
+		let pattern = MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::ALL_DAYS; 9]).unwrap();
+		assert_eq!(pattern.to_u64(), Some((1 << 63) - 1));
+		assert_eq!(MultiWeekPattern::from_u64(date(2024, 1, 1), 9, (1 << 63) - 1).unwrap(), pattern);
+		assert_eq!(MultiWeekPattern::new(date(2024, 1, 1), vec![Weekdays::NONE; 10]).unwrap().to_u64(), None);
+	}
+	
+	//		week_of																
+	#[test]
+	fn week_of() {
+		let pattern = fortnightly();
+		assert_eq!(pattern.week_of(date(2024, 1,  7)), Weekdays::new(0b10100_00));
+		assert_eq!(pattern.week_of(date(2024, 1,  8)), Weekdays::new(0b00001_01));
+		assert_eq!(pattern.week_of(date(2023, 12, 31)), Weekdays::new(0b00001_01));
+	}
+}
+
+#[cfg(feature = "serde")]
+mod traits {
+	use super::*;
+	use claims::{assert_err, assert_ok_eq};
+	
+	//		Deserialize															
+	#[test]
+	fn deserialize() {
+		assert_ok_eq!(
+			serde_json::from_str::<MultiWeekPattern>(r#"{"anchor":"2024-01-03","weeks":[80,5]}"#),
+			fortnightly(),
+		);
+		assert_err!(serde_json::from_str::<MultiWeekPattern>(r#"{"anchor":"2024-01-01","weeks":[]}"#));
+		assert_err!(serde_json::from_str::<MultiWeekPattern>(r#"{"weeks":[1]}"#));
+	}
+	
+	//		Serialize															
+	#[test]
+	fn serialize() {
+		assert_eq!(serde_json::to_string(&fortnightly()).unwrap(), r#"{"anchor":"2024-01-01","weeks":[80,5]}"#);
+	}
+}
@@ -0,0 +1,224 @@
+#![allow(clippy::single_range_in_vec_init, reason = "Lists of time ranges often have only one")]
+
+//		Packages
+
+use super::*;
+use crate::parse;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+/// Creates a date and time in the first week of 2024, where the 1st is a
+/// Monday.
+#[cfg(feature = "chrono")]
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(2024, 1, day).unwrap().and_hms_opt(hour, minute, 0).unwrap()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let hours = OpeningHours::new();
+		assert_eq!(hours.open_days(), Weekdays::NONE);
+		assert_eq!(hours.holidays(),  None);
+		assert_eq!(hours,             OpeningHours::default());
+	}
+	
+	//		with_holidays														
+	#[test]
+	#[expect(clippy::reversed_empty_ranges, reason = "Testing reversed ranges")]
+	fn with_holidays() {
+		let hours = OpeningHours::new().with_holidays(&[600..720]).unwrap();
+		assert_eq!(hours.holidays(), Some(&[600..720][..]));
+		assert_eq!(OpeningHours::new().with_holidays(&[720..600]), None);
+	}
+	
+	//		with_hours															
+	#[test]
+	fn with_hours() {
+		let hours = OpeningHours::new()
+			.with_hours(Weekdays::WEEKDAYS, &[780..1020, 540..720, 700..750]).unwrap()
+			.with_hours(Weekdays::WEDNESDAY, &[]).unwrap();
+		assert_eq!(hours.hours(Weekdays::MONDAY),    [540..750, 780..1020]);
+		assert_eq!(hours.hours(Weekdays::WEDNESDAY), []);
+		assert_eq!(hours.open_days(),                Weekdays::parse_const("mon-tue, thu-fri"));
+	}
+	#[test]
+	fn with_hours__invalid() {
+		assert_eq!(OpeningHours::new().with_hours(Weekdays::MONDAY, &[600..600]),   None);
+		assert_eq!(OpeningHours::new().with_hours(Weekdays::MONDAY, &[1440..1500]), None);
+		assert_eq!(OpeningHours::new().with_hours(Weekdays::MONDAY, &[1380..2881]), None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		hours																
+	#[test]
+	fn hours() {
+		let hours: OpeningHours = parse("Mo-Fr 08:00-18:00; Sa 22:00-02:00");
+		assert_eq!(hours.hours(Weekdays::WEEKDAYS), [480..1080]);
+		assert_eq!(hours.hours(Weekdays::SATURDAY), [1320..1560]);
+		assert_eq!(hours.hours(Weekdays::NONE),     []);
+	}
+	
+	//		is_open_at															
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn is_open_at() {
+		let hours: OpeningHours = parse("Mo-Fr 08:00-12:00,13:00-18:00; Su 22:00-02:00");
+		assert!( hours.is_open_at(at(1,  8,  0)));
+		assert!( hours.is_open_at(at(1, 11, 59)));
+		assert!(!hours.is_open_at(at(1, 12, 30)));
+		assert!(!hours.is_open_at(at(1, 18,  0)));
+		assert!(!hours.is_open_at(at(6, 10,  0)));
+		assert!( hours.is_open_at(at(7, 23,  0)));
+		assert!( hours.is_open_at(at(8,  1, 59)));
+		assert!(!hours.is_open_at(at(8,  2,  0)));
+	}
+	
+	//		next_change															
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn next_change() {
+		let hours: OpeningHours = parse("Mo-Fr 08:00-12:00,13:00-18:00; Su 22:00-02:00");
+		assert_eq!(hours.next_change(at(1,  7,  0)), Some(at(1,  8, 0)));
+		assert_eq!(hours.next_change(at(1,  8,  0)), Some(at(1, 12, 0)));
+		assert_eq!(hours.next_change(at(5, 18,  0)), Some(at(7, 22, 0)));
+		assert_eq!(hours.next_change(at(7, 23, 30)), Some(at(8,  2, 0)));
+		assert_eq!(hours.next_change(at(8,  2,  0)), Some(at(8,  8, 0)));
+	}
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn next_change__continuous() {
+		let overnight: OpeningHours = parse("Mo-Su 18:00-06:00");
+		assert_eq!(overnight.next_change(at(1, 20, 0)), Some(at(2, 6, 0)));
+		assert_eq!(parse::<OpeningHours>("24/7").next_change(at(1, 12, 0)),   None);
+		assert_eq!(parse::<OpeningHours>("off").next_change(at(1, 12, 0)),    None);
+		assert_eq!(parse::<OpeningHours>("Mo-Su 00:00-24:00").next_change(at(1, 0, 0)), None);
+	}
+	
+	//		rules																
+	#[test]
+	fn rules() {
+		let hours: OpeningHours = parse("Mo,We 09:00-17:00; Tu 10:00-16:00; Sa 09:00-17:00");
+		assert_eq!(hours.rules(), vec![
+			(Weekdays::parse_const("mon, wed, sat"), &[540..1020][..]),
+			(Weekdays::TUESDAY,                      &[600..960][..]),
+		]);
+	}
+}
+
+mod functions {
+	//		format_ranges														
+	#[test]
+	fn format_ranges() {
+		assert_eq!(super::format_ranges(&[]),                    "off");
+		assert_eq!(super::format_ranges(&[480..720, 780..1080]), "08:00-12:00,13:00-18:00");
+		assert_eq!(super::format_ranges(&[1320..1560]),          "22:00-02:00");
+		assert_eq!(super::format_ranges(&[600..2040]),           "10:00-10:00");
+		assert_eq!(super::format_ranges(&[600..2100]),           "10:00-35:00");
+		assert_eq!(super::format_ranges(&[600..2880]),           "10:00-48:00");
+	}
+	
+	//		normalise															
+	#[test]
+	#[expect(clippy::reversed_empty_ranges, reason = "Testing reversed ranges")]
+	fn normalise() {
+		assert_eq!(super::normalise(&[600..700, 500..600, 650..800, 900..1000]), Some(vec![500..800, 900..1000]));
+		assert_eq!(super::normalise(&[]),                                      Some(vec![]));
+		assert_eq!(super::normalise(&[600..500]),                              None);
+	}
+	
+	//		parse_range															
+	#[test]
+	fn parse_range() {
+		assert_eq!(super::parse_range("08:00-18:00"), Some(480..1080));
+		assert_eq!(super::parse_range("8:00-18:00"),  Some(480..1080));
+		assert_eq!(super::parse_range("22:00-02:00"), Some(1320..1560));
+		assert_eq!(super::parse_range("22:00-26:00"), Some(1320..1560));
+		assert_eq!(super::parse_range("00:00-24:00"), Some(0..1440));
+		assert_eq!(super::parse_range("10:00-10:00"), Some(600..2040));
+		assert_eq!(super::parse_range("24:00-25:00"), None);
+		assert_eq!(super::parse_range("08:60-09:00"), None);
+		assert_eq!(super::parse_range("08:00"),       None);
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		Display																
+	#[test]
+	fn display() {
+		assert_eq!(parse::<OpeningHours>("Mo-Fr 08:00-18:00; We 10:00-12:00").to_string(), "Mo,Tu,Th,Fr 08:00-18:00; We 10:00-12:00");
+		assert_eq!(parse::<OpeningHours>("Sa,Su 10:00-16:00").to_string(),                 "Sa,Su 10:00-16:00");
+		assert_eq!(parse::<OpeningHours>("Mo-Su 00:00-24:00").to_string(),                 "24/7");
+		assert_eq!(parse::<OpeningHours>("24/7; PH off").to_string(),                      "24/7; PH off");
+		assert_eq!(parse::<OpeningHours>("Sa-Mo 10:00-16:00").to_string(),                 "Mo,Sa,Su 10:00-16:00");
+		assert_eq!(parse::<OpeningHours>("PH 10:00-12:00").to_string(),                    "PH 10:00-12:00");
+		assert_eq!(OpeningHours::new().to_string(),                        "off");
+	}
+	#[test]
+	fn display__round_trip() {
+		for text in [
+			"Mo-Fr 08:00-12:00,13:00-18:00; Sa 09:00-13:00; PH off",
+			"Mo,We 09:00-17:00; Tu 10:00-16:00",
+			"Fr,Sa 18:00-03:00; Su 12:00-24:00",
+			"Mo 10:00-48:00; Tu 10:00-35:00; We 10:00-10:00",
+			"24/7",
+			"off",
+		] {
+			let hours: OpeningHours = parse(text);
+			assert_eq!(hours.to_string(),         text);
+			assert_eq!(parse::<OpeningHours>(&hours.to_string()), hours);
+		}
+	}
+	
+	//		FromStr																
+	#[test]
+	fn from_str() {
+		let hours: OpeningHours = parse("Mo-Fr 08:00-18:00; Sa 09:00-13:00; PH off");
+		assert_eq!(hours.hours(Weekdays::FRIDAY),   [480..1080]);
+		assert_eq!(hours.hours(Weekdays::SATURDAY), [540..780]);
+		assert_eq!(hours.holidays(),                Some(&[][..]));
+		assert_eq!(parse::<OpeningHours>("08:00-18:00").open_days(),                                    Weekdays::ALL_DAYS);
+		assert_eq!(parse::<OpeningHours>("Mo-Fr").hours(Weekdays::MONDAY),                              [0..1440]);
+		assert_eq!(parse::<OpeningHours>("Mo-Fr open").hours(Weekdays::MONDAY),                         [0..1440]);
+		assert_eq!(parse::<OpeningHours>("Mo-Fr 08:00 - 12:00, 13:00 - 18:00").hours(Weekdays::MONDAY), [480..720, 780..1080]);
+		assert_eq!(parse::<OpeningHours>("Mo-Fr 08:00-18:00; We closed").open_days(),                   Weekdays::parse_const("mon-tue, thu-fri"));
+		assert_eq!(parse::<OpeningHours>("Sa,PH 10:00-12:00").holidays(),                               Some(&[600..720][..]));
+	}
+	#[test]
+	fn from_str__errors() {
+		let parse_err = |text: &str| text.parse::<OpeningHours>().unwrap_err();
+		assert_eq!(parse_err(""),                               OpeningHoursError::Empty);
+		assert_eq!(parse_err("Mo 08:00-12:00;"),                OpeningHoursError::Empty);
+		assert_eq!(parse_err("Mo,,Tu 08:00-12:00"),             OpeningHoursError::Empty);
+		assert_eq!(parse_err("Mon 08:00-12:00"),                OpeningHoursError::InvalidDay("Mon".to_owned()));
+		assert_eq!(parse_err("Mo 08:00-12"),                    OpeningHoursError::InvalidTime("08:00-12".to_owned()));
+		assert_eq!(parse_err("Mo 24:00-25:00"),                 OpeningHoursError::InvalidTime("24:00-25:00".to_owned()));
+		assert_eq!(parse_err("Dec 25 off"),                     OpeningHoursError::UnsupportedSyntax("Dec".to_owned()));
+		assert_eq!(parse_err("SH off"),                         OpeningHoursError::UnsupportedSyntax("SH".to_owned()));
+		assert_eq!(parse_err("Mo[1] 10:00-12:00"),              OpeningHoursError::UnsupportedSyntax("Mo[1]".to_owned()));
+		assert_eq!(parse_err("Mo 10:00+"),                      OpeningHoursError::UnsupportedSyntax("10:00+".to_owned()));
+		assert_eq!(parse_err("Mo sunrise-sunset"),              OpeningHoursError::UnsupportedSyntax("sunrise-sunset".to_owned()));
+		assert_eq!(parse_err("Mo 10:00-12:00, We 14:00-16:00"), OpeningHoursError::UnsupportedSyntax("10:00-12:00, We 14:00-16:00".to_owned()));
+	}
+	
+	//		OpeningHoursError Display											
+	#[test]
+	fn opening_hours_error__display() {
+		assert_eq!(OpeningHoursError::Empty.to_string(),                              "Empty opening hours entry");
+		assert_eq!(OpeningHoursError::InvalidTime("8-9".to_owned()).to_string(),      "Invalid time range: 8-9");
+		assert_eq!(OpeningHoursError::UnsupportedSyntax("SH".to_owned()).to_string(), "Unsupported opening hours syntax: SH");
+	}
+}
@@ -0,0 +1,133 @@
+//		Packages
+
+use super::*;
+
+/// Parses a phrase and returns its days, panicking if it is invalid.
+fn days(phrase: &str) -> Weekdays {
+	parse_phrase(phrase).unwrap().days()
+}
+
+
+
+//		Tests
+
+mod public_methods {
+	use super::*;
+	
+	//		PhraseMatch confidence												
+	#[test]
+	fn phrase_match__confidence() {
+		assert!((parse_phrase("weekdays except wednesday").unwrap().confidence() - 1.0).abs() < f64::EPSILON);
+		let single = parse_phrase("thurday").unwrap().confidence();
+		let double = parse_phrase("thurday and fridya").unwrap().confidence();
+		assert!(single < 1.0);
+		assert!(double < single);
+		assert!(double > 0.0);
+	}
+	
+	//		PhraseMatch corrections												
+	#[test]
+	fn phrase_match__corrections() {
+		let result = parse_phrase("Wendesdays except wekends").unwrap();
+		assert_eq!(result.days(), Weekdays::WEDNESDAY);
+		let corrections: Vec<_> = result.corrections().iter().map(|correction| {
+			(correction.original(), correction.corrected(), correction.distance())
+		}).collect();
+		assert_eq!(corrections, vec![("wendesdays", "wednesday", 1), ("wekends", "weekend", 1)]);
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		parse_phrase														
+	#[test]
+	fn parse_phrase__named_sets() {
+		assert_eq!(days("weekdays"),       Weekdays::WEEKDAYS);
+		assert_eq!(days("Weekends"),       Weekdays::WEEKENDS);
+		assert_eq!(days("daily"),          Weekdays::ALL_DAYS);
+		assert_eq!(days("every day"),      Weekdays::ALL_DAYS);
+		assert_eq!(days("everyday"),       Weekdays::ALL_DAYS);
+		assert_eq!(days("all days"),       Weekdays::ALL_DAYS);
+		assert_eq!(days("workdays"),       Weekdays::WEEKDAYS);
+		assert_eq!(days("never"),          Weekdays::NONE);
+		assert_eq!(days("none"),           Weekdays::NONE);
+		assert_eq!(days("no days"),        Weekdays::NONE);
+	}
+	#[test]
+	fn parse_phrase__lists() {
+		assert_eq!(days("weekends and Mondays"),         Weekdays::parse_const("mon, sat-sun"));
+		assert_eq!(days("Mon, Wed & Fri"),               Weekdays::parse_const("mon, wed, fri"));
+		assert_eq!(days("on Tuesdays or Thursdays"),     Weekdays::parse_const("tue, thu"));
+		assert_eq!(days("Monday's and Friday's"),        Weekdays::parse_const("mon, fri"));
+		assert_eq!(days("every Mon. and Thurs."),        Weekdays::parse_const("mon, thu"));
+		assert_eq!(days("weekly on Sunday plus Monday"), Weekdays::parse_const("mon, sun"));
+	}
+	#[test]
+	fn parse_phrase__ranges() {
+		assert_eq!(days("Tue thru Thu"),                Weekdays::parse_const("tue-thu"));
+		assert_eq!(days("from Monday to Friday"),       Weekdays::WEEKDAYS);
+		assert_eq!(days("Mondays through Wednesdays"),  Weekdays::parse_const("mon-wed"));
+		assert_eq!(days("mon-fri"),                     Weekdays::WEEKDAYS);
+		assert_eq!(days("Fri\u{2013}Mon"),              Weekdays::parse_const("fri-mon"));
+		assert_eq!(days("Mon-Wed, Fri until Sat"),      Weekdays::parse_const("mon-wed, fri-sat"));
+	}
+	#[test]
+	fn parse_phrase__exceptions() {
+		assert_eq!(days("every weekday except Wednesday"),           Weekdays::parse_const("mon-tue, thu-fri"));
+		assert_eq!(days("every day but Sunday"),                     Weekdays::parse_const("mon-sat"));
+		assert_eq!(days("daily excluding Tue and Thu"),              Weekdays::parse_const("mon, wed, fri-sun"));
+		assert_eq!(days("weekdays but not Friday"),                  Weekdays::parse_const("mon-thu"));
+		assert_eq!(days("except Mondays"),                           Weekdays::parse_const("tue-sun"));
+		assert_eq!(days("not Sundays"),                              Weekdays::parse_const("mon-sat"));
+		assert_eq!(days("never on weekends"),                        Weekdays::WEEKDAYS);
+		assert_eq!(days("weekdays except Mon-Wed"),                  Weekdays::parse_const("thu-fri"));
+		assert_eq!(days("weekdays except Monday, also Saturdays"),   Weekdays::parse_const("tue-sat"));
+	}
+	#[test]
+	fn parse_phrase__typos() {
+		assert_eq!(days("Wendesday"),             Weekdays::WEDNESDAY);
+		assert_eq!(days("thurday"),               Weekdays::THURSDAY);
+		assert_eq!(days("Satruday and Sundy"),    Weekdays::WEEKENDS);
+		assert_eq!(days("weekdys except fridya"), Weekdays::parse_const("mon-thu"));
+		assert_eq!(days("every tusday"),          Weekdays::TUESDAY);
+	}
+	#[test]
+	fn parse_phrase__errors() {
+		assert_eq!(parse_phrase(""),                      Err(PhraseError::Empty));
+		assert_eq!(parse_phrase("every"),                 Err(PhraseError::Empty));
+		assert_eq!(parse_phrase("weekdays except"),       Err(PhraseError::IncompletePhrase("except".to_owned())));
+		assert_eq!(parse_phrase("Monday to"),             Err(PhraseError::IncompletePhrase("to".to_owned())));
+		assert_eq!(parse_phrase("to Friday"),             Err(PhraseError::InvalidRange("to".to_owned())));
+		assert_eq!(parse_phrase("weekdays to Sunday"),    Err(PhraseError::InvalidRange("to".to_owned())));
+		assert_eq!(parse_phrase("Monday to weekends"),    Err(PhraseError::InvalidRange("monday to weekends".to_owned())));
+		assert_eq!(parse_phrase("Mondays and sometimes"), Err(PhraseError::UnknownWord("sometimes".to_owned())));
+		assert_eq!(parse_phrase("mnd"),                   Err(PhraseError::UnknownWord("mnd".to_owned())));
+		assert_eq!(parse_phrase("every other Monday"),    Err(PhraseError::UnsupportedWord("other".to_owned())));
+		assert_eq!(parse_phrase("monthly"),               Err(PhraseError::UnsupportedWord("monthly".to_owned())));
+	}
+	#[test]
+	fn parse_phrase__ambiguous_typo() {
+		//	"thes" is one edit from both "tues" and "the"
+		assert_eq!(parse_phrase("thes"),                  Err(PhraseError::UnknownWord("thes".to_owned())));
+	}
+	#[test]
+	fn parse_phrase__operator_typo() {
+		//	Only days and named sets of days are corrected
+		assert_eq!(parse_phrase("note Monday"),            Err(PhraseError::UnknownWord("note".to_owned())));
+		assert_eq!(parse_phrase("weekdays excpet Friday"), Err(PhraseError::UnknownWord("excpet".to_owned())));
+		assert_eq!(parse_phrase("evrey Tuesday"),          Err(PhraseError::UnknownWord("evrey".to_owned())));
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		PhraseError Display													
+	#[test]
+	fn phrase_error__display() {
+		assert_eq!(PhraseError::Empty.to_string(),                                "No days given");
+		assert_eq!(PhraseError::UnknownWord("sometimes".to_owned()).to_string(),  "Unknown word: sometimes");
+		assert_eq!(PhraseError::UnsupportedWord("other".to_owned()).to_string(),  "Unsupported schedule: other");
+	}
+}
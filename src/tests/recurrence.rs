@@ -0,0 +1,185 @@
+//		Packages
+
+use super::*;
+use crate::parse;
+use chrono::NaiveDate;
+use claims::assert_err;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		GoogleRecurrence new												
+	#[test]
+	fn google_recurrence__new() {
+		let recurrence = GoogleRecurrence::new(parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=TU"))
+			.with_extra_lines(vec!["EXDATE;VALUE=DATE:20240611".to_owned()]);
+		assert_eq!(recurrence.rule(),        parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=TU"));
+		assert_eq!(recurrence.extra_lines(), ["EXDATE;VALUE=DATE:20240611"]);
+	}
+	
+	//		GraphRecurrencePattern new											
+	#[test]
+	fn graph_recurrence_pattern__new() {
+		let pattern = GraphRecurrencePattern::new(Weekdays::WEEKDAYS, 2).unwrap();
+		assert_eq!(pattern.days(),              Weekdays::WEEKDAYS);
+		assert_eq!(pattern.interval(),          2);
+		assert_eq!(pattern.first_day_of_week(), Weekday::Sun);
+		assert_eq!(pattern.with_first_day_of_week(Weekday::Mon).first_day_of_week(), Weekday::Mon);
+	}
+	#[test]
+	fn graph_recurrence_pattern__new__invalid() {
+		assert_eq!(GraphRecurrencePattern::new(Weekdays::NONE,   1), None);
+		assert_eq!(GraphRecurrencePattern::new(Weekdays::MONDAY, 0), None);
+	}
+	
+	//		GraphRecurrencePattern from_rule									
+	#[test]
+	fn graph_recurrence_pattern__from_rule() {
+		let pattern = GraphRecurrencePattern::from_rule(parse::<WeeklyRule>("FREQ=WEEKLY;INTERVAL=3;BYDAY=MO,FR;WKST=SU")).unwrap();
+		assert_eq!(pattern.days(),              Weekdays::parse_const("mon, fri"));
+		assert_eq!(pattern.interval(),          3);
+		assert_eq!(pattern.first_day_of_week(), Weekday::Sun);
+	}
+	#[test]
+	fn graph_recurrence_pattern__from_rule__invalid() {
+		assert_eq!(GraphRecurrencePattern::from_rule(parse::<WeeklyRule>("FREQ=WEEKLY")),                  None);
+		assert_eq!(GraphRecurrencePattern::from_rule(parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=MO;COUNT=5")), None);
+		assert_eq!(GraphRecurrencePattern::from_rule(parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=MO;UNTIL=20241231")), None);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		GraphRecurrencePattern to_rule										
+	#[test]
+	fn graph_recurrence_pattern__to_rule() {
+		let pattern = GraphRecurrencePattern::new(Weekdays::WEEKENDS, 2).unwrap();
+		assert_eq!(pattern.to_rule(), parse::<WeeklyRule>("FREQ=WEEKLY;INTERVAL=2;BYDAY=SA,SU;WKST=SU"));
+	}
+	#[test]
+	fn graph_recurrence_pattern__to_rule__round_trip() {
+		for bits in 1..=0b111_1111 {
+			for first in [Weekday::Mon, Weekday::Wed, Weekday::Sun] {
+				let pattern = GraphRecurrencePattern::new(Weekdays::new(bits), 4).unwrap().with_first_day_of_week(first);
+				assert_eq!(GraphRecurrencePattern::from_rule(pattern.to_rule()), Some(pattern));
+			}
+		}
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		GoogleRecurrence Deserialize										
+	#[test]
+	fn google_recurrence__deserialize() {
+		//	Sample from the Google Calendar events reference
+		let simple: GoogleRecurrence = serde_json::from_str(r#"["RRULE:FREQ=WEEKLY;UNTIL=20110701T170000Z"]"#).unwrap();
+		assert_eq!(simple.rule().until(), NaiveDate::from_ymd_opt(2011, 7, 1));
+		assert_eq!(simple.rule().days(),  Weekdays::NONE);
+		assert!(simple.extra_lines().is_empty());
+		//	Sample from the Google Calendar recurring events guide, made weekly
+		let recurrence: GoogleRecurrence = serde_json::from_str(r#"[
+			"EXDATE;VALUE=DATE:20150610",
+			"RDATE;VALUE=DATE:20150609,20150611",
+			"RRULE:FREQ=WEEKLY;BYDAY=TU,TH;INTERVAL=2;WKST=SU"
+		]"#).unwrap();
+		assert_eq!(recurrence.rule().days(),       Weekdays::parse_const("tue, thu"));
+		assert_eq!(recurrence.rule().interval(),   2);
+		assert_eq!(recurrence.rule().week_start(), Weekday::Sun);
+		assert_eq!(recurrence.extra_lines(),       ["EXDATE;VALUE=DATE:20150610", "RDATE;VALUE=DATE:20150609,20150611"]);
+	}
+	#[test]
+	fn google_recurrence__deserialize__invalid() {
+		assert_err!(serde_json::from_str::<GoogleRecurrence>("[]"));
+		assert_err!(serde_json::from_str::<GoogleRecurrence>(r#"["EXDATE;VALUE=DATE:20150610"]"#));
+		assert_err!(serde_json::from_str::<GoogleRecurrence>(r#"["RRULE:FREQ=DAILY;UNTIL=20150628;INTERVAL=3"]"#));
+		assert_err!(serde_json::from_str::<GoogleRecurrence>(r#"["RRULE:FREQ=WEEKLY;BYDAY=MO", "RRULE:FREQ=WEEKLY;BYDAY=TU"]"#));
+		assert_err!(serde_json::from_str::<GoogleRecurrence>(r#""RRULE:FREQ=WEEKLY""#));
+	}
+	
+	//		GoogleRecurrence Serialize											
+	#[test]
+	fn google_recurrence__serialize() {
+		let recurrence = GoogleRecurrence::from(parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=MO,FR;COUNT=10"))
+			.with_extra_lines(vec!["EXDATE;VALUE=DATE:20240610".to_owned()]);
+		assert_eq!(
+			serde_json::to_string(&recurrence).unwrap(),
+			r#"["RRULE:FREQ=WEEKLY;BYDAY=MO,FR;COUNT=10","EXDATE;VALUE=DATE:20240610"]"#,
+		);
+		assert_eq!(serde_json::from_str::<GoogleRecurrence>(&serde_json::to_string(&recurrence).unwrap()).unwrap(), recurrence);
+	}
+	
+	//		GraphRecurrencePattern Deserialize									
+	#[test]
+	fn graph_recurrence_pattern__deserialize() {
+		//	Request sample from the Microsoft Graph documentation
+		let request: GraphRecurrencePattern = serde_json::from_str(r#"{
+			"type": "weekly",
+			"interval": 1,
+			"daysOfWeek": [ "Monday" ]
+		}"#).unwrap();
+		assert_eq!(request.days(),              Weekdays::MONDAY);
+		assert_eq!(request.interval(),          1);
+		assert_eq!(request.first_day_of_week(), Weekday::Sun);
+		//	Response sample from the Microsoft Graph documentation
+		let response: GraphRecurrencePattern = serde_json::from_str(r#"{
+			"type": "weekly",
+			"interval": 1,
+			"month": 0,
+			"dayOfMonth": 0,
+			"daysOfWeek": [ "monday" ],
+			"firstDayOfWeek": "sunday",
+			"index": "first"
+		}"#).unwrap();
+		assert_eq!(response, request);
+		let monday: GraphRecurrencePattern = serde_json::from_str(
+			r#"{"type":"weekly","interval":2,"daysOfWeek":["saturday","SUNDAY"],"firstDayOfWeek":"Monday"}"#,
+		).unwrap();
+		assert_eq!(monday.days(),              Weekdays::WEEKENDS);
+		assert_eq!(monday.interval(),          2);
+		assert_eq!(monday.first_day_of_week(), Weekday::Mon);
+	}
+	#[test]
+	fn graph_recurrence_pattern__deserialize__invalid() {
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"daily","interval":1}"#));
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"weekly","interval":1,"daysOfWeek":[]}"#));
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"weekly","interval":0,"daysOfWeek":["monday"]}"#));
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"weekly","interval":1,"daysOfWeek":["mon"]}"#));
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"weekly","interval":1,"daysOfWeek":["monday"],"firstDayOfWeek":"someday"}"#));
+		assert_err!(serde_json::from_str::<GraphRecurrencePattern>(r#"{"type":"weekly","interval":1,"daysOfWeek":["monday"],"month":3}"#));
+	}
+	
+	//		GraphRecurrencePattern Serialize									
+	#[test]
+	fn graph_recurrence_pattern__serialize() {
+		let pattern = GraphRecurrencePattern::new(Weekdays::parse_const("mon, wed, fri"), 1).unwrap()
+			.with_first_day_of_week(Weekday::Mon);
+		assert_eq!(serde_json::to_string(&pattern).unwrap(), concat!(
+			r#"{"type":"weekly","interval":1,"#,
+			r#""daysOfWeek":["monday","wednesday","friday"],"firstDayOfWeek":"monday"}"#,
+		));
+	}
+	#[test]
+	fn graph_recurrence_pattern__serialize__round_trip() {
+		for bits in 1..=0b111_1111 {
+			let pattern = GraphRecurrencePattern::new(Weekdays::new(bits), 3).unwrap().with_first_day_of_week(Weekday::Thu);
+			let json    = serde_json::to_string(&pattern).unwrap();
+			assert_eq!(serde_json::from_str::<GraphRecurrencePattern>(&json).unwrap(), pattern);
+		}
+	}
+	
+	//		GraphRecurrencePattern TryFrom										
+	#[test]
+	fn graph_recurrence_pattern__try_from() {
+		let weekly  = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=WE");
+		let limited = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=WE;COUNT=3");
+		assert_eq!(GraphRecurrencePattern::try_from(weekly),  Ok(GraphRecurrencePattern::from_rule(weekly).unwrap()));
+		assert_eq!(GraphRecurrencePattern::try_from(limited), Err(limited));
+	}
+}
@@ -0,0 +1,195 @@
+//		Packages
+
+use super::*;
+
+/// Checks that a rota meets the solver's requirements, panicking if not.
+fn check(solver: &RotaSolver, rota: &Rota) {
+	for (day, required) in Weekdays::ALL_DAYS.iter().map(|day| (day, solver.headcount(day))) {
+		assert_eq!(rota.staff(day).len(), required);
+	}
+	for (person, days) in solver.people().iter().zip(rota.assignments()) {
+		assert!(person.availability().contains(*days));
+		assert!(days.days() <= person.max_days());
+		//	Runs are carried from one week to the next, not wrapped round
+		let longest = Weekdays::ALL_DAYS.iter()
+			.scan(0_u8, |run, day| {
+				*run = if days.contains(day) { *run + 1 } else { 0 };
+				Some(*run)
+			})
+			.max()
+			.unwrap_or(0);
+		assert!(longest <= person.max_consecutive());
+	}
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		Person new															
+	#[test]
+	fn person__new() {
+		let person = Person::new(Weekdays::WEEKDAYS);
+		assert_eq!(person.availability(),    Weekdays::WEEKDAYS);
+		assert_eq!(person.max_days(),        7);
+		assert_eq!(person.max_consecutive(), 7);
+	}
+	
+	//		Person with_max_consecutive											
+	#[test]
+	fn person__with_max_consecutive() {
+		assert_eq!(Person::new(Weekdays::ALL_DAYS).with_max_consecutive(3).max_consecutive(), 3);
+	}
+	
+	//		Person with_max_days												
+	#[test]
+	fn person__with_max_days() {
+		assert_eq!(Person::new(Weekdays::ALL_DAYS).with_max_days(4).max_days(), 4);
+	}
+	
+	//		RotaSolver with_headcount											
+	#[test]
+	fn rota_solver__with_headcount() {
+		let solver = RotaSolver::new(Vec::new()).with_headcount(Weekdays::WEEKDAYS, 2).with_headcount(Weekdays::FRIDAY, 3);
+		assert_eq!(solver.headcount(Weekdays::MONDAY), 2);
+		assert_eq!(solver.headcount(Weekdays::FRIDAY), 3);
+		assert_eq!(solver.headcount(Weekdays::SUNDAY), 0);
+		assert_eq!(solver.headcount(Weekdays::NONE),   0);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	//		Rota staff															
+	#[test]
+	fn rota__staff() {
+		let rota = Rota { assignments: vec![Weekdays::parse_const("mon-tue"), Weekdays::MONDAY] };
+		assert_eq!(rota.staff(Weekdays::MONDAY),                  vec![0, 1]);
+		assert_eq!(rota.staff(Weekdays::parse_const("mon-tue")),  vec![0]);
+		assert_eq!(rota.staff(Weekdays::NONE),                    Vec::<usize>::new());
+		assert_eq!(rota.days_for(1),                              Some(Weekdays::MONDAY));
+		assert_eq!(rota.days_for(2),                              None);
+	}
+	
+	//		RotaSolver solve													
+	#[test]
+	fn rota_solver__solve() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::ALL_DAYS).with_max_days(5).with_max_consecutive(3),
+			Person::new(Weekdays::ALL_DAYS).with_max_days(5).with_max_consecutive(3),
+			Person::new(Weekdays::WEEKENDS),
+			Person::new(Weekdays::parse_const("mon, wed, fri")),
+		]).with_headcount(Weekdays::ALL_DAYS, 2);
+		let rota   = solver.solve().unwrap();
+		check(&solver, &rota);
+	}
+	#[test]
+	fn rota_solver__solve__backtracking() {
+		//	Choosing the fairest person for Monday leaves nobody for Tuesday
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::parse_const("mon-tue")).with_max_days(1),
+			Person::new(Weekdays::MONDAY),
+		]).with_headcount(Weekdays::parse_const("mon-tue"), 1);
+		let rota   = solver.solve().unwrap();
+		assert_eq!(rota.assignments(), &[Weekdays::TUESDAY, Weekdays::MONDAY]);
+	}
+	#[test]
+	fn rota_solver__solve__consecutive() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::ALL_DAYS).with_max_consecutive(2),
+			Person::new(Weekdays::ALL_DAYS).with_max_consecutive(2),
+		]).with_headcount(Weekdays::ALL_DAYS, 1);
+		let rota   = solver.solve().unwrap();
+		check(&solver, &rota);
+		let single = RotaSolver::new(vec![Person::new(Weekdays::ALL_DAYS).with_max_consecutive(2)]).with_headcount(Weekdays::parse_const("mon-wed"), 1);
+		assert_eq!(single.solve(), Err(RotaError::LimitsExceeded { day: Weekdays::WEDNESDAY, required: 1, week: 1 }));
+	}
+	#[test]
+	fn rota_solver__solve__consecutive_large() {
+		//	Any four days in a row need more days than anyone can work
+		let person   = Person::new(Weekdays::ALL_DAYS).with_max_consecutive(3);
+		let large    = RotaSolver::new(vec![person; 30]).with_headcount(Weekdays::ALL_DAYS, 25);
+		assert_eq!(large.solve(), Err(RotaError::LimitsExceeded { day: Weekdays::THURSDAY, required: 25, week: 1 }));
+		let small    = RotaSolver::new(vec![person; 14]).with_headcount(Weekdays::ALL_DAYS, 12);
+		assert_eq!(small.solve(), Err(RotaError::LimitsExceeded { day: Weekdays::THURSDAY, required: 12, week: 1 }));
+		let feasible = RotaSolver::new(vec![person; 30]).with_headcount(Weekdays::ALL_DAYS, 22);
+		for rota in &feasible.solve_weeks(2).unwrap() {
+			check(&feasible, rota);
+		}
+	}
+	#[test]
+	fn rota_solver__solve__empty() {
+		let rota = RotaSolver::new(vec![Person::new(Weekdays::ALL_DAYS)]).solve().unwrap();
+		assert_eq!(rota.assignments(), &[Weekdays::NONE]);
+		assert_eq!(RotaSolver::new(Vec::new()).solve().unwrap().assignments(), &[]);
+	}
+	#[test]
+	fn rota_solver__solve__not_enough_people() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::WEEKDAYS),
+			Person::new(Weekdays::parse_const("mon-wed")),
+			Person::new(Weekdays::ALL_DAYS).with_max_days(0),
+		]).with_headcount(Weekdays::WEEKDAYS, 2);
+		assert_eq!(solver.solve(), Err(RotaError::NotEnoughPeople { day: Weekdays::THURSDAY, required: 2, available: 1 }));
+	}
+	#[test]
+	fn rota_solver__solve__limits_exceeded() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::WEEKDAYS).with_max_days(2),
+			Person::new(Weekdays::WEEKDAYS).with_max_days(2),
+		]).with_headcount(Weekdays::WEEKDAYS, 1);
+		assert_eq!(solver.solve(), Err(RotaError::LimitsExceeded { day: Weekdays::FRIDAY, required: 1, week: 1 }));
+	}
+	
+	//		RotaSolver solve_weeks												
+	#[test]
+	fn rota_solver__solve_weeks() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::ALL_DAYS),
+			Person::new(Weekdays::ALL_DAYS),
+			Person::new(Weekdays::ALL_DAYS),
+		]).with_headcount(Weekdays::parse_const("mon, wed"), 1);
+		let rotas  = solver.solve_weeks(3).unwrap();
+		assert_eq!(rotas.len(), 3);
+		for rota in &rotas {
+			check(&solver, rota);
+		}
+		let totals: Vec<u8> = (0..3).map(|person| rotas.iter().map(|rota| rota.days_for(person).unwrap().days()).sum()).collect();
+		assert_eq!(totals, vec![2, 2, 2]);
+	}
+	#[test]
+	fn rota_solver__solve_weeks__runs_carry_over() {
+		let solver = RotaSolver::new(vec![
+			Person::new(Weekdays::parse_const("mon, sun")).with_max_consecutive(1),
+			Person::new(Weekdays::MONDAY),
+		]).with_headcount(Weekdays::parse_const("mon, sun"), 1);
+		let rotas  = solver.solve_weeks(2).unwrap();
+		//	The first person works Sunday, so cannot work the following Monday
+		assert_eq!(rotas.last().unwrap().staff(Weekdays::MONDAY), vec![1]);
+		let strict = RotaSolver::new(vec![
+			Person::new(Weekdays::parse_const("mon, sun")).with_max_consecutive(1),
+		]).with_headcount(Weekdays::parse_const("mon, sun"), 1);
+		assert_eq!(strict.solve_weeks(2), Err(RotaError::LimitsExceeded { day: Weekdays::MONDAY, required: 1, week: 2 }));
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		RotaError Display													
+	#[test]
+	fn rota_error__display() {
+		assert_eq!(
+			RotaError::NotEnoughPeople { day: Weekdays::THURSDAY, required: 2, available: 1 }.to_string(),
+			"Cannot staff Thursday: 2 required but only 1 available",
+		);
+		assert_eq!(
+			RotaError::LimitsExceeded { day: Weekdays::FRIDAY, required: 1, week: 2 }.to_string(),
+			"Cannot staff Friday in week 2: 1 required without exceeding the limits on days worked",
+		);
+	}
+}
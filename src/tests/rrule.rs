@@ -0,0 +1,193 @@
+//		Packages
+
+use super::*;
+#[cfg(feature = "chrono")]
+use crate::{date, parse};
+
+
+
+//		Tests
+
+mod functions {
+	use super::*;
+	
+	//		parse_byday															
+	#[test]
+	fn parse_byday() {
+		assert_eq!(super::parse_byday("MO,WE,FR"), Ok(Weekdays::parse_const("mon, wed, fri")));
+		assert_eq!(super::parse_byday("su,Sa"),    Ok(Weekdays::WEEKENDS));
+		assert_eq!(super::parse_byday("MO,MO"),    Ok(Weekdays::MONDAY));
+	}
+	#[test]
+	fn parse_byday__invalid() {
+		assert_eq!(super::parse_byday(""),       Err(RruleError::InvalidDay(String::new())));
+		assert_eq!(super::parse_byday("MO,XX"),  Err(RruleError::InvalidDay("XX".to_owned())));
+		assert_eq!(super::parse_byday("1MO"),    Err(RruleError::InvalidDay("1MO".to_owned())));
+		assert_eq!(super::parse_byday("MO, TU"), Err(RruleError::InvalidDay(" TU".to_owned())));
+	}
+	
+	//		to_byday															
+	#[test]
+	fn to_byday() {
+		assert_eq!(super::to_byday(Weekdays::parse_const("fri, mon, wed")), "MO,WE,FR");
+		assert_eq!(super::to_byday(Weekdays::ALL_DAYS),                     "MO,TU,WE,TH,FR,SA,SU");
+		assert_eq!(super::to_byday(Weekdays::NONE),                         "");
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let rule = WeeklyRule::new(Weekdays::MONDAY, 2).unwrap();
+		assert_eq!(rule.days(),       Weekdays::MONDAY);
+		assert_eq!(rule.interval(),   2);
+		assert_eq!(rule.week_start(), Weekday::Mon);
+		assert_eq!(rule.count(),      None);
+		assert_eq!(rule.until(),      None);
+		assert_eq!(WeeklyRule::new(Weekdays::MONDAY, 0), None);
+	}
+	
+	//		with_count															
+	#[test]
+	fn with_count() {
+		let rule = WeeklyRule::new(Weekdays::MONDAY, 1).unwrap().with_until(date(2024, 1, 1)).with_count(5);
+		assert_eq!(rule.count(), Some(5));
+		assert_eq!(rule.until(), None);
+	}
+	
+	//		with_until															
+	#[test]
+	fn with_until() {
+		let rule = WeeklyRule::new(Weekdays::MONDAY, 1).unwrap().with_count(5).with_until(date(2024, 1, 1));
+		assert_eq!(rule.count(), None);
+		assert_eq!(rule.until(), Some(date(2024, 1, 1)));
+	}
+	
+	//		with_week_start														
+	#[test]
+	fn with_week_start() {
+		let rule = WeeklyRule::new(Weekdays::MONDAY, 1).unwrap().with_week_start(Weekday::Sun);
+		assert_eq!(rule.week_start(), Weekday::Sun);
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod public_methods {
+	use super::*;
+	
+	//		occurrences															
+	#[test]
+	fn occurrences() {
+		let dates = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=TU,TH;COUNT=5").occurrences(date(2024, 1, 4)).collect::<Vec<_>>();
+		assert_eq!(dates, vec![
+			date(2024, 1,  4),
+			date(2024, 1,  9),
+			date(2024, 1, 11),
+			date(2024, 1, 16),
+			date(2024, 1, 18),
+		]);
+	}
+	#[test]
+	fn occurrences__until() {
+		let dates = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=MO;UNTIL=20240122T090000Z").occurrences(date(2024, 1, 1)).collect::<Vec<_>>();
+		assert_eq!(dates, vec![date(2024, 1, 1), date(2024, 1, 8), date(2024, 1, 15), date(2024, 1, 22)]);
+		assert_eq!(parse::<WeeklyRule>("FREQ=WEEKLY;UNTIL=20231231").occurrences(date(2024, 1, 1)).count(), 0);
+	}
+	#[test]
+	fn occurrences__interval() {
+		let dates = parse::<WeeklyRule>("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,SU;COUNT=4").occurrences(date(2024, 1, 1)).collect::<Vec<_>>();
+		assert_eq!(dates, vec![date(2024, 1, 1), date(2024, 1, 7), date(2024, 1, 15), date(2024, 1, 21)]);
+	}
+	#[test]
+	fn occurrences__week_start() {
+		//	Example from RFC 5545, where WKST changes the result
+		let monday = parse::<WeeklyRule>("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO").occurrences(date(1997, 8, 5));
+		assert_eq!(monday.collect::<Vec<_>>(), vec![
+			date(1997, 8,  5),
+			date(1997, 8, 10),
+			date(1997, 8, 19),
+			date(1997, 8, 24),
+		]);
+		let sunday = parse::<WeeklyRule>("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU").occurrences(date(1997, 8, 5));
+		assert_eq!(sunday.collect::<Vec<_>>(), vec![
+			date(1997, 8,  5),
+			date(1997, 8, 17),
+			date(1997, 8, 19),
+			date(1997, 8, 31),
+		]);
+	}
+	#[test]
+	fn occurrences__start_not_on_days() {
+		//	The start date is always the first occurrence, and is counted
+		let dates = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=TU,TH;COUNT=3").occurrences(date(2024, 1, 1)).collect::<Vec<_>>();
+		assert_eq!(dates, vec![date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 4)]);
+		let until = parse::<WeeklyRule>("FREQ=WEEKLY;BYDAY=TU;UNTIL=20240109").occurrences(date(2024, 1, 6)).collect::<Vec<_>>();
+		assert_eq!(until, vec![date(2024, 1, 6), date(2024, 1, 9)]);
+	}
+	#[test]
+	fn occurrences__no_days() {
+		let dates = parse::<WeeklyRule>("FREQ=WEEKLY;COUNT=3").occurrences(date(2024, 1, 3)).collect::<Vec<_>>();
+		assert_eq!(dates, vec![date(2024, 1, 3), date(2024, 1, 10), date(2024, 1, 17)]);
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod traits {
+	use super::*;
+	
+	//		Display																
+	#[test]
+	fn display() {
+		assert_eq!(WeeklyRule::new(Weekdays::NONE, 1).unwrap().to_string(), "FREQ=WEEKLY");
+		let full = WeeklyRule::new(Weekdays::WEEKENDS, 3).unwrap().with_week_start(Weekday::Sun).with_until(date(2024, 12, 31));
+		assert_eq!(full.to_string(), "FREQ=WEEKLY;INTERVAL=3;BYDAY=SA,SU;WKST=SU;UNTIL=20241231");
+		assert_eq!(parse::<WeeklyRule>(&full.to_string()), full);
+	}
+	
+	//		FromStr																
+	#[test]
+	fn from_str() {
+		let parsed: WeeklyRule = parse("RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;INTERVAL=2;WKST=SU");
+		assert_eq!(parsed.days(),       Weekdays::parse_const("mon, wed, fri"));
+		assert_eq!(parsed.interval(),   2);
+		assert_eq!(parsed.week_start(), Weekday::Sun);
+		assert_eq!(parse::<WeeklyRule>("freq=weekly;byday=mo").days(), Weekdays::MONDAY);
+	}
+	#[test]
+	fn from_str__errors() {
+		let cases = [
+			("FREQ=WEEKLY;COUNT=2;UNTIL=20240101", RruleError::CountAndUntil),
+			("FREQ=WEEKLY;FREQ=WEEKLY",            RruleError::DuplicatePart("FREQ".to_owned())),
+			("FREQ=WEEKLY;BYDAY=MO,2TU",           RruleError::InvalidDay("2TU".to_owned())),
+			("FREQ=WEEKLY;BYDAY",                  RruleError::InvalidPart("BYDAY".to_owned())),
+			("FREQ=WEEKLY;",                       RruleError::InvalidPart(String::new())),
+			("FREQ=WEEKLY;INTERVAL=0",             RruleError::InvalidValue { name: "INTERVAL".to_owned(), value: "0".to_owned() }),
+			("FREQ=WEEKLY;COUNT=x",                RruleError::InvalidValue { name: "COUNT".to_owned(),    value: "x".to_owned() }),
+			("FREQ=WEEKLY;UNTIL=2024-01-01",       RruleError::InvalidValue { name: "UNTIL".to_owned(),    value: "2024-01-01".to_owned() }),
+			("FREQ=WEEKLY;WKST=XX",                RruleError::InvalidValue { name: "WKST".to_owned(),     value: "XX".to_owned() }),
+			("BYDAY=MO",                           RruleError::MissingFrequency),
+			("FREQ=WEEKLY;X-FOO=1",                RruleError::UnknownPart("X-FOO".to_owned())),
+			("FREQ=DAILY",                         RruleError::UnsupportedFrequency("DAILY".to_owned())),
+			("FREQ=WEEKLY;BYMONTH=1",              RruleError::UnsupportedPart("BYMONTH".to_owned())),
+		];
+		for (input, err) in cases {
+			assert_eq!(input.parse::<WeeklyRule>(), Err(err), "{input}");
+		}
+	}
+	
+	//		RruleError Display													
+	#[test]
+	fn rrule_error__display() {
+		assert_eq!(RruleError::CountAndUntil.to_string(),                      "COUNT and UNTIL cannot both be given");
+		assert_eq!(RruleError::InvalidDay("1MO".to_owned()).to_string(),        "Invalid BYDAY entry: 1MO");
+		assert_eq!(RruleError::UnsupportedPart("BYHOUR".to_owned()).to_string(), "Unsupported rule part: BYHOUR");
+		assert_eq!(
+			RruleError::InvalidValue { name: "COUNT".to_owned(), value: "x".to_owned() }.to_string(),
+			"Invalid COUNT value: x",
+		);
+	}
+}
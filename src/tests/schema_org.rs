@@ -0,0 +1,213 @@
+//		Packages
+
+use super::*;
+use crate::parse;
+use claims::{assert_err, assert_ok_eq};
+
+/// Parses a specification from JSON, panicking if it is invalid.
+fn from_json(json: &str) -> OpeningHoursSpecification {
+	serde_json::from_str(json).unwrap()
+}
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let spec = OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(540..1020)).unwrap();
+		assert_eq!(spec.days(),            Weekdays::WEEKDAYS);
+		assert_eq!(spec.hours(),           Some(540..1020));
+		assert!(!spec.public_holidays());
+		assert!(spec.with_public_holidays().public_holidays());
+	}
+	#[test]
+	fn new__invalid() {
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::NONE,   Some(540..1020)),  None);
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::MONDAY, Some(540..540)),   None);
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::MONDAY, Some(1440..1500)), None);
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::MONDAY, Some(1380..2881)), None);
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::MONDAY, Some(600..2100)),  None);
+		assert_eq!(OpeningHoursSpecification::new(Weekdays::MONDAY, Some(0..2880)),    None);
+	}
+	
+	//		for_public_holidays													
+	#[test]
+	fn for_public_holidays() {
+		let spec = OpeningHoursSpecification::for_public_holidays(None).unwrap();
+		assert_eq!(spec.days(),  Weekdays::NONE);
+		assert_eq!(spec.hours(), None);
+		assert!(spec.public_holidays());
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		from_opening_hours													
+	#[test]
+	fn from_opening_hours() {
+		let hours: OpeningHours = parse("Mo-Fr 09:00-12:00,13:00-17:00; Sa 10:00-14:00; PH off");
+		let specs               = super::from_opening_hours(&hours).unwrap();
+		assert_eq!(specs, vec![
+			OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(540..720)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(780..1020)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::SATURDAY, Some(600..840)).unwrap(),
+			OpeningHoursSpecification::for_public_holidays(None).unwrap(),
+		]);
+	}
+	#[test]
+	fn from_opening_hours__shared_holidays() {
+		let hours: OpeningHours = parse("Mo,We 09:00-17:00; Su,PH 10:00-16:00");
+		assert_ok_eq!(super::from_opening_hours(&hours), vec![
+			OpeningHoursSpecification::new(Weekdays::parse_const("mon, wed"), Some(540..1020)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::SUNDAY, Some(600..960)).unwrap().with_public_holidays(),
+		]);
+		let separate: OpeningHours = parse("Mo 09:00-17:00; PH 10:00-12:00");
+		assert_eq!(super::from_opening_hours(&separate).unwrap().last(), OpeningHoursSpecification::for_public_holidays(Some(600..720)).as_ref());
+	}
+	#[test]
+	fn from_opening_hours__too_long() {
+		//	Ranges longer than a day cannot be given as opening and closing times
+		let days: OpeningHours     = parse("Mo 10:00-35:00");
+		let holidays: OpeningHours = parse("Mo 10:00-12:00; PH 10:00-35:00");
+		assert_eq!(super::from_opening_hours(&days),     Err(SchemaOrgError::InvalidHours(600..2100)));
+		assert_eq!(super::from_opening_hours(&holidays), Err(SchemaOrgError::InvalidHours(600..2100)));
+	}
+	
+	//		to_opening_hours													
+	#[test]
+	fn to_opening_hours() {
+		let specs = [
+			OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(780..1020)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(540..720)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::FRIDAY,   Some(1200..1560)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::SUNDAY,   None).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::SATURDAY, Some(600..840)).unwrap().with_public_holidays(),
+		];
+		assert_eq!(
+			super::to_opening_hours(&specs).unwrap().to_string(),
+			"Mo-Th 09:00-12:00,13:00-17:00; Fr 09:00-12:00,13:00-17:00,20:00-02:00; Sa 10:00-14:00; PH 10:00-14:00",
+		);
+		assert_ok_eq!(super::to_opening_hours(&[]), OpeningHours::new());
+	}
+	#[test]
+	fn to_opening_hours__round_trip() {
+		for text in [
+			"Mo-Fr 09:00-12:00,13:00-17:00; Sa 10:00-14:00; PH off",
+			"Mo,We 09:00-17:00; Su 10:00-16:00; PH 10:00-16:00",
+			"Fr,Sa 18:00-03:00; PH 10:00-12:00",
+			"Mo 10:00-10:00; Tu 18:00-24:00",
+			"24/7",
+		] {
+			let hours: OpeningHours = parse(text);
+			assert_ok_eq!(super::to_opening_hours(&super::from_opening_hours(&hours).unwrap()), hours);
+		}
+	}
+	
+	//		parse_time															
+	#[test]
+	fn parse_time() {
+		assert_eq!(super::parse_time("08:30"),    Some(510));
+		assert_eq!(super::parse_time("08:30:45"), Some(510));
+		assert_eq!(super::parse_time("24:00"),    Some(1440));
+		assert_eq!(super::parse_time("24:01"),    None);
+		assert_eq!(super::parse_time("8:30"),     None);
+		assert_eq!(super::parse_time("08:60"),    None);
+		assert_eq!(super::parse_time("08"),       None);
+		assert_eq!(super::parse_time("08:30:00:00"), None);
+	}
+	
+	//		strip_prefix														
+	#[test]
+	fn strip_prefix() {
+		assert_eq!(super::strip_prefix("https://schema.org/Monday"), "Monday");
+		assert_eq!(super::strip_prefix("http://schema.org/Monday"),  "Monday");
+		assert_eq!(super::strip_prefix("schema:Monday"),             "Monday");
+		assert_eq!(super::strip_prefix("Monday"),                    "Monday");
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		Deserialize															
+	#[test]
+	fn deserialize() {
+		let weekends = || OpeningHoursSpecification::new(Weekdays::WEEKENDS, Some(600..960)).unwrap();
+		assert_ok_eq!(serde_json::from_str::<OpeningHoursSpecification>(
+			r#"{"@type":"OpeningHoursSpecification","dayOfWeek":["https://schema.org/Saturday","http://schema.org/Sunday"],"opens":"10:00","closes":"16:00"}"#,
+		), weekends());
+		assert_ok_eq!(serde_json::from_str::<OpeningHoursSpecification>(
+			r#"{"@type":"schema:OpeningHoursSpecification","dayOfWeek":["saturday","Sunday"],"opens":"10:00:00","closes":"16:00:00"}"#,
+		), weekends());
+		assert_eq!(from_json(r#"{"dayOfWeek":"Monday","opens":"09:00","closes":"17:00"}"#).days(), Weekdays::MONDAY);
+		assert!(from_json(r#"{"dayOfWeek":"https://schema.org/PublicHolidays","opens":"09:00","closes":"12:00"}"#).public_holidays());
+	}
+	#[test]
+	fn deserialize__hours() {
+		let hours = |opens: &str, closes: &str| {
+			from_json(&format!(r#"{{"dayOfWeek":"Friday","opens":"{opens}","closes":"{closes}"}}"#)).hours()
+		};
+		assert_eq!(hours("00:00", "00:00"), None);
+		assert_eq!(hours("00:00", "23:59"), Some(0..1440));
+		assert_eq!(hours("00:00", "24:00"), Some(0..1440));
+		assert_eq!(hours("22:00", "02:00"), Some(1320..1560));
+		assert_eq!(hours("18:00", "00:00"), Some(1080..1440));
+		assert_eq!(hours("09:00", "17:30"), Some(540..1050));
+	}
+	#[test]
+	fn deserialize__errors() {
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"@type":"Place","dayOfWeek":"Monday","opens":"09:00","closes":"17:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"dayOfWeek":"Mon","opens":"09:00","closes":"17:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"dayOfWeek":[],"opens":"09:00","closes":"17:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"dayOfWeek":"Monday","opens":"9am","closes":"17:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"dayOfWeek":"Monday","opens":"24:00","closes":"02:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(r#"{"dayOfWeek":"Monday","opens":"09:00"}"#));
+		assert_err!(serde_json::from_str::<OpeningHoursSpecification>(
+			r#"{"dayOfWeek":"Monday","opens":"09:00","closes":"17:00","validFrom":"2024-12-24","validThrough":"2024-12-26"}"#,
+		));
+	}
+	
+	//		Serialize															
+	#[test]
+	fn serialize() {
+		let closed  = OpeningHoursSpecification::for_public_holidays(None).unwrap();
+		let always  = OpeningHoursSpecification::new(Weekdays::SUNDAY, Some(0..1440)).unwrap();
+		let late    = OpeningHoursSpecification::new(Weekdays::FRIDAY, Some(1320..1560)).unwrap().with_public_holidays();
+		assert_eq!(
+			serde_json::to_string(&closed).unwrap(),
+			r#"{"@type":"OpeningHoursSpecification","dayOfWeek":["https://schema.org/PublicHolidays"],"opens":"00:00","closes":"00:00"}"#,
+		);
+		assert_eq!(
+			serde_json::to_string(&always).unwrap(),
+			r#"{"@type":"OpeningHoursSpecification","dayOfWeek":["https://schema.org/Sunday"],"opens":"00:00","closes":"23:59"}"#,
+		);
+		assert_eq!(
+			serde_json::to_string(&late).unwrap(),
+			r#"{"@type":"OpeningHoursSpecification","dayOfWeek":["https://schema.org/Friday","https://schema.org/PublicHolidays"],"opens":"22:00","closes":"02:00"}"#,
+		);
+	}
+	#[test]
+	fn serialize__round_trip() {
+		for spec in [
+			OpeningHoursSpecification::for_public_holidays(None).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::ALL_DAYS, Some(0..1440)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::WEEKDAYS, Some(1080..1440)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::MONDAY,   Some(600..2040)).unwrap(),
+			OpeningHoursSpecification::new(Weekdays::WEEKENDS, Some(1320..1560)).unwrap().with_public_holidays(),
+		] {
+			assert_eq!(from_json(&serde_json::to_string(&spec).unwrap()), spec);
+		}
+	}
+	
+	//		SchemaOrgError Display												
+	#[test]
+	fn schema_org_error__display() {
+		assert_eq!(SchemaOrgError::InvalidHours(600..2100).to_string(), "Invalid time range for opening hours: 10:00-35:00");
+	}
+}
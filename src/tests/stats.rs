@@ -0,0 +1,157 @@
+#![allow(clippy::unusual_byte_groupings, reason = "Clearer to show weekdays vs weekends")]
+
+//		Packages
+
+use super::*;
+
+
+
+//		Tests
+
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let stats = WeekdaysStats::new();
+		assert_eq!(stats.total(),       0);
+		assert_eq!(stats.day_counts(),  [0; 7]);
+		assert_eq!(stats.mean_days(),   None);
+		assert_eq!(stats.top_patterns(5), vec![]);
+	}
+}
+
+mod public_methods {
+	use super::*;
+	
+	/// Creates statistics from the given bit-mapped sets of days.
+	fn stats(days: &[u8]) -> WeekdaysStats {
+		let mut stats = WeekdaysStats::new();
+		stats.add_slice(&days.iter().copied().map(Weekdays::new).collect::<Vec<_>>());
+		stats
+	}
+	
+	//		add																	
+	#[test]
+	fn add() {
+		let mut stats = WeekdaysStats::new();
+		stats.add(Weekdays::new(0b11111_00));
+		stats.add(Weekdays::new(0b11111_00));
+		stats.add(Weekdays::new(0b00000_11));
+		assert_eq!(stats.total(),                           3);
+		assert_eq!(stats.count(Weekdays::new(0b11111_00)), 2);
+		assert_eq!(stats.count(Weekdays::new(0b00000_11)), 1);
+	}
+	
+	//		add_slice															
+	#[test]
+	fn add_slice() {
+		let stats = stats(&[0b11111_00, 0b11111_00, 0b00000_11, 0b00000_00]);
+		assert_eq!(stats.total(),                           4);
+		assert_eq!(stats.count(Weekdays::new(0b11111_00)), 2);
+		assert_eq!(stats.count(Weekdays::new(0b00000_11)), 1);
+		assert_eq!(stats.count(Weekdays::new(0b00000_00)), 1);
+		assert_eq!(stats.count(Weekdays::new(0b10000_00)), 0);
+	}
+	
+	//		day_count															
+	#[test]
+	fn day_count() {
+		let stats = stats(&[0b11111_00, 0b10000_01, 0b00000_11]);
+		assert_eq!(stats.day_count(Weekdays::MONDAY),   2);
+		assert_eq!(stats.day_count(Weekdays::FRIDAY),   1);
+		assert_eq!(stats.day_count(Weekdays::SUNDAY),   2);
+		assert_eq!(stats.day_count(Weekdays::WEEKENDS), 1);
+		assert_eq!(stats.day_count(Weekdays::NONE),     3);
+	}
+	
+	//		day_counts															
+	#[test]
+	fn day_counts() {
+		let stats = stats(&[0b11111_00, 0b10000_01, 0b00000_11]);
+		assert_eq!(stats.day_counts(), [2, 1, 1, 1, 1, 1, 2]);
+	}
+	
+	//		day_frequencies														
+	#[test]
+	fn day_frequencies() {
+		let frequencies = stats(&[0b11111_00, 0b10000_01, 0b00000_11, 0b00000_00]).day_frequencies();
+		assert!((frequencies[0] - 0.5 ).abs() < f64::EPSILON);
+		assert!((frequencies[1] - 0.25).abs() < f64::EPSILON);
+		assert!((frequencies[6] - 0.5 ).abs() < f64::EPSILON);
+		assert_eq!(WeekdaysStats::new().day_frequencies().map(f64::to_bits), [0; 7]);
+	}
+	
+	//		length_distribution													
+	#[test]
+	fn length_distribution() {
+		assert_eq!(stats(&[]).length_distribution(), [0; 8]);
+		assert_eq!(
+			stats(&[0b11111_00, 0b10000_01, 0b00000_11, 0b00000_00, 0b11111_11]).length_distribution(),
+			[1, 0, 2, 0, 0, 1, 0, 1],
+		);
+	}
+	
+	//		mean_days															
+	#[test]
+	fn mean_days() {
+		assert_eq!(stats(&[]).mean_days(),                       None);
+		assert_eq!(stats(&[0b11111_00, 0b00000_11]).mean_days(), Some(3.5));
+		assert_eq!(stats(&[0b00000_00]).mean_days(),             Some(0.0));
+	}
+	
+	//		merge																
+	#[test]
+	fn merge() {
+		let mut first = stats(&[0b11111_00, 0b00000_11]);
+		let second    = stats(&[0b11111_00, 0b10000_00]);
+		first.merge(&second);
+		assert_eq!(first, stats(&[0b11111_00, 0b00000_11, 0b11111_00, 0b10000_00]));
+		assert_eq!(first.total(), 4);
+	}
+	
+	//		top_patterns														
+	#[test]
+	fn top_patterns() {
+		let stats = stats(&[0b00000_11, 0b11111_00, 0b11111_00, 0b10000_00, 0b00000_11, 0b01000_00]);
+		assert_eq!(stats.top_patterns(0), vec![]);
+		assert_eq!(stats.top_patterns(2), vec![
+			(Weekdays::new(0b00000_11), 2),
+			(Weekdays::new(0b11111_00), 2),
+		]);
+		assert_eq!(stats.top_patterns(10), vec![
+			(Weekdays::new(0b00000_11), 2),
+			(Weekdays::new(0b11111_00), 2),
+			(Weekdays::new(0b01000_00), 1),
+			(Weekdays::new(0b10000_00), 1),
+		]);
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		Default																
+	#[test]
+	fn default() {
+		assert_eq!(WeekdaysStats::default(), WeekdaysStats::new());
+	}
+	
+	//		Extend																
+	#[test]
+	fn extend() {
+		let mut stats = WeekdaysStats::new();
+		stats.extend([Weekdays::MONDAY, Weekdays::MONDAY, Weekdays::SUNDAY]);
+		assert_eq!(stats.total(),                  3);
+		assert_eq!(stats.count(Weekdays::MONDAY),  2);
+	}
+	
+	//		FromIterator														
+	#[test]
+	fn from_iter() {
+		let stats = [Weekdays::MONDAY, Weekdays::SUNDAY].into_iter().collect::<WeekdaysStats>();
+		assert_eq!(stats.total(),                 2);
+		assert_eq!(stats.count(Weekdays::SUNDAY), 1);
+	}
+}
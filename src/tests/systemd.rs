@@ -0,0 +1,157 @@
+//		Packages
+
+use super::*;
+
+/// Creates a time from the given hour, minute, and second.
+#[cfg(feature = "chrono")]
+fn time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+	NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+}
+
+
+
+//		Tests
+
+#[cfg(feature = "chrono")]
+mod constructors {
+	use super::*;
+	
+	//		new																	
+	#[test]
+	fn new() {
+		let calendar = OnCalendar::new(Weekdays::WEEKENDS, vec![time(17, 30, 0), time(9, 0, 0), time(17, 30, 0)]).unwrap();
+		assert_eq!(calendar.days(),  Weekdays::WEEKENDS);
+		assert_eq!(calendar.times(), [time(9, 0, 0), time(17, 30, 0)]);
+	}
+	#[test]
+	fn new__fractions() {
+		let precise  = NaiveTime::from_hms_milli_opt(9, 0, 0, 500).unwrap();
+		let calendar = OnCalendar::new(Weekdays::MONDAY, vec![precise, time(9, 0, 0)]).unwrap();
+		assert_eq!(calendar.times(), [time(9, 0, 0)]);
+	}
+	#[test]
+	fn new__invalid() {
+		assert_eq!(OnCalendar::new(Weekdays::NONE,   vec![time(9, 0, 0)]), None);
+		assert_eq!(OnCalendar::new(Weekdays::MONDAY, vec![]),              None);
+	}
+	
+	//		from_specs															
+	#[test]
+	fn from_specs() {
+		let calendar = OnCalendar::from_specs(["Mon..Fri *-*-* 09,17:00:00", "Mon..Fri *-*-* 12:30:00"]).unwrap();
+		assert_eq!(calendar.days(),  Weekdays::WEEKDAYS);
+		assert_eq!(calendar.times(), [time(9, 0, 0), time(12, 30, 0), time(17, 0, 0)]);
+	}
+	#[test]
+	fn from_specs__errors() {
+		assert_eq!(OnCalendar::from_specs([]),                          Err(SystemdError::Empty));
+		assert_eq!(OnCalendar::from_specs(["Mon 09:00", "Tue 10:00"]),  Err(SystemdError::InconsistentDays));
+		assert_eq!(OnCalendar::from_specs(["Mon 09:00", "Mon 25:00"]),  Err(SystemdError::InvalidTime("25:00".to_owned())));
+	}
+}
+
+#[cfg(feature = "chrono")]
+mod public_methods {
+	use super::*;
+	
+	//		to_specs															
+	#[test]
+	fn to_specs() {
+		let calendar = OnCalendar::new(Weekdays::WEEKDAYS, vec![time(9, 0, 0), time(12, 30, 0), time(17, 0, 0)]).unwrap();
+		assert_eq!(calendar.to_specs(), vec!["Mon..Fri *-*-* 09,17:00:00", "Mon..Fri *-*-* 12:30:00"]);
+	}
+	#[test]
+	fn to_specs__every_day() {
+		let calendar = OnCalendar::new(Weekdays::ALL_DAYS, vec![time(6, 15, 30)]).unwrap();
+		assert_eq!(calendar.to_specs(), vec!["*-*-* 06:15:30"]);
+	}
+	#[test]
+	fn to_specs__round_trip() {
+		let times = vec![time(0, 0, 0), time(8, 45, 0), time(9, 0, 0), time(13, 45, 0), time(23, 59, 59)];
+		for bits in 1..=0b111_1111 {
+			let calendar = OnCalendar::new(Weekdays::new(bits), times.clone()).unwrap();
+			let specs    = calendar.to_specs();
+			assert_eq!(OnCalendar::from_specs(specs.iter().map(String::as_str)), Ok(calendar));
+		}
+	}
+}
+
+mod functions {
+	use super::*;
+	
+	//		parse_day_spec														
+	#[test]
+	fn parse_day_spec() {
+		assert_eq!(super::parse_day_spec("Mon"),                Ok(Weekdays::MONDAY));
+		assert_eq!(super::parse_day_spec("Mon..Fri"),           Ok(Weekdays::WEEKDAYS));
+		assert_eq!(super::parse_day_spec("Mon-Fri"),            Ok(Weekdays::WEEKDAYS));
+		assert_eq!(super::parse_day_spec("Sat,Sun"),            Ok(Weekdays::WEEKENDS));
+		assert_eq!(super::parse_day_spec("monday,WED..thu"),    Ok(Weekdays::parse_const("mon, wed-thu")));
+		assert_eq!(super::parse_day_spec("Mon..Sun"),           Ok(Weekdays::ALL_DAYS));
+		assert_eq!(super::parse_day_spec("Tue,Tue..Wed"),       Ok(Weekdays::parse_const("tue-wed")));
+	}
+	#[test]
+	fn parse_day_spec__errors() {
+		assert_eq!(super::parse_day_spec(""),             Err(SystemdError::Empty));
+		assert_eq!(super::parse_day_spec("Mon,,Tue"),     Err(SystemdError::Empty));
+		assert_eq!(super::parse_day_spec("Mon.."),        Err(SystemdError::Empty));
+		assert_eq!(super::parse_day_spec("Foo"),          Err(SystemdError::InvalidDay("Foo".to_owned())));
+		assert_eq!(super::parse_day_spec("Mon, Tue"),     Err(SystemdError::InvalidDay(" Tue".to_owned())));
+		assert_eq!(super::parse_day_spec("Mond"),         Err(SystemdError::InvalidDay("Mond".to_owned())));
+		assert_eq!(super::parse_day_spec("Mon-Mon..Tue"), Err(SystemdError::InvalidDay("Mon-Mon".to_owned())));
+		assert_eq!(super::parse_day_spec("Fri..Mon"),     Err(SystemdError::InvalidRange("Fri..Mon".to_owned())));
+	}
+	
+	//		to_day_spec															
+	#[test]
+	fn to_day_spec() {
+		assert_eq!(super::to_day_spec(Weekdays::WEEKDAYS),                     "Mon..Fri");
+		assert_eq!(super::to_day_spec(Weekdays::WEEKENDS),                     "Sat,Sun");
+		assert_eq!(super::to_day_spec(Weekdays::ALL_DAYS),                     "Mon..Sun");
+		assert_eq!(super::to_day_spec(Weekdays::parse_const("mon-wed, fri")),  "Mon..Wed,Fri");
+		assert_eq!(super::to_day_spec(Weekdays::parse_const("mon, sat, sun")), "Mon,Sat,Sun");
+		assert_eq!(super::to_day_spec(Weekdays::NONE),                         "");
+	}
+	#[test]
+	fn to_day_spec__round_trip() {
+		for bits in 1..=0b111_1111 {
+			let days = Weekdays::new(bits);
+			assert_eq!(super::parse_day_spec(&super::to_day_spec(days)), Ok(days));
+		}
+	}
+}
+
+mod traits {
+	use super::*;
+	
+	//		OnCalendar FromStr													
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn on_calendar__from_str() {
+		let weekdays = OnCalendar::new(Weekdays::WEEKDAYS, vec![time(9, 0, 0), time(17, 0, 0)]).unwrap();
+		assert_eq!("Mon..Fri *-*-* 09,17:00:00".parse(), Ok(weekdays.clone()));
+		assert_eq!("Mon..Fri 09,17:00".parse(),          Ok(weekdays));
+		let midnight = OnCalendar::new(Weekdays::SATURDAY, vec![time(0, 0, 0)]).unwrap();
+		assert_eq!("Sat *-*-*".parse(),                  Ok(midnight));
+		let daily    = OnCalendar::new(Weekdays::ALL_DAYS, vec![time(6, 0, 0), time(6, 30, 0)]).unwrap();
+		assert_eq!("*-*-* 06:00,30".parse(),             Ok(daily));
+	}
+	#[cfg(feature = "chrono")]
+	#[test]
+	fn on_calendar__from_str__errors() {
+		assert_eq!("".parse::<OnCalendar>(),                     Err(SystemdError::Empty));
+		assert_eq!("Mon 9".parse::<OnCalendar>(),                Err(SystemdError::InvalidTime("9".to_owned())));
+		assert_eq!("Mon 09:xx".parse::<OnCalendar>(),            Err(SystemdError::InvalidTime("09:xx".to_owned())));
+		assert_eq!("Mon *-*-* 09:00/15".parse::<OnCalendar>(),   Err(SystemdError::UnsupportedSyntax("09:00/15".to_owned())));
+		assert_eq!("Mon 2024-01-01 09:00".parse::<OnCalendar>(), Err(SystemdError::UnsupportedSyntax("2024-01-01".to_owned())));
+		assert_eq!("Mon 09:00 UTC".parse::<OnCalendar>(),        Err(SystemdError::UnsupportedSyntax("UTC".to_owned())));
+	}
+	
+	//		SystemdError Display												
+	#[test]
+	fn systemd_error__display() {
+		assert_eq!(SystemdError::Empty.to_string(),                              "Empty calendar specification entry");
+		assert_eq!(SystemdError::InvalidDay("Foo".to_owned()).to_string(),       "Invalid day of the week: Foo");
+		assert_eq!(SystemdError::UnsupportedSyntax("UTC".to_owned()).to_string(), "Unsupported calendar syntax: UTC");
+	}
+}